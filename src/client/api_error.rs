@@ -0,0 +1,27 @@
+use crate::error::Error as GoogleMapsError;
+
+// -----------------------------------------------------------------------------
+//
+/// Implemented by the error type of each Google Maps Platform API. Allows the
+/// shared `get` method to wrap HTTP client, and JSON parsing errors in the
+/// appropriate API error type.
+
+pub(crate) trait ApiError:
+    From<reqwest::Error>
+    + From<serde_json::error::Error>
+    + Into<GoogleMapsError>
+    + std::fmt::Display
+{
+
+    /// The HTTP request was unsuccessful. The argument contains the HTTP
+    /// status code returned by the server.
+    fn http_unsuccessful(status: String) -> Self;
+
+    /// The HTTP client generated an error that could not be passed normally,
+    /// so a `String` representation is passed instead.
+    fn reqwest_message(message: String) -> Self;
+
+    /// The HTTP request timed out. Timeouts are eligible for retries.
+    fn timeout(error: reqwest::Error) -> Self;
+
+} // trait
@@ -29,6 +29,10 @@ impl GoogleMapsClient {
     pub fn build(&self) -> GoogleMapsClient {
         GoogleMapsClient {
             key: self.key.clone(),
+            #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places", feature = "time_zone"))]
+            default_language: self.default_language.clone(),
+            #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
+            default_region: self.default_region.clone(),
         } // GoogleMapsClient
     } // fn
 
@@ -0,0 +1,410 @@
+//! Contains the `CircuitBreaker` struct, which stops the Google Maps client
+//! from sending requests to an API that is failing.
+
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::api::Api;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// -----------------------------------------------------------------------------
+//
+/// The state of a circuit breaker for an API.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CircuitState {
+    /// Requests are sent normally, and their outcomes are recorded.
+    Closed,
+    /// Too many requests have failed. Requests fail immediately with
+    /// `Error::CircuitOpen` until the cooldown has elapsed.
+    Open,
+    /// The cooldown has elapsed. A single probe request is allowed through. If
+    /// it succeeds the circuit closes, otherwise the circuit opens again.
+    HalfOpen,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for CircuitState {
+    /// Formats a `CircuitState` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CircuitState::Closed => write!(f, "Closed"),
+            CircuitState::Open => write!(f, "Open"),
+            CircuitState::HalfOpen => write!(f, "Half Open"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A circuit breaker that stops the client from hammering a degraded Google
+/// Maps Platform API. Each API (Directions, Geocoding, etc.) has its own
+/// circuit, so a failing API does not affect the others.
+///
+/// The outcome of the most recent `window_size` attempts to each API is
+/// recorded. An attempt that fails in a way that would normally be retried
+/// (for example, a connection error, a timeout, or an HTTP `503 Service
+/// Unavailable` response) counts as a failure. Any other outcome counts as a
+/// success.
+///
+/// * **Closed** ‧ Once `window_size` attempts have been recorded, and the
+/// proportion of failures reaches `failure_rate`, the circuit opens. The
+/// request in progress stops retrying.
+///
+/// * **Open** ‧ Requests fail immediately with `Error::CircuitOpen` until
+/// `cooldown` has elapsed.
+///
+/// * **Half Open** ‧ A single probe request is allowed through. If it succeeds
+/// the circuit closes, otherwise it opens again for another `cooldown`.
+///
+/// The circuit breaker's state is shared by every clone of the client. Set
+/// using the `GoogleMapsClient::with_circuit_breaker` or
+/// `GoogleMapsClient::with_circuit_breaker_rate` methods.
+
+#[derive(Clone, Debug)]
+pub struct CircuitBreaker {
+
+    /// The proportion of failed attempts, from `0.0` to `1.0`, that will open
+    /// the circuit.
+    failure_rate: f64,
+
+    /// The number of most recent attempts that the failure rate is calculated
+    /// from.
+    window_size: usize,
+
+    /// How long requests are rejected for once the circuit has opened.
+    cooldown: Duration,
+
+    /// The state of each API's circuit. This is shared between clones of the
+    /// client.
+    circuits: Arc<Mutex<HashMap<Api, Circuit>>>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+/// The mutable state of a single API's circuit.
+
+#[derive(Debug)]
+struct Circuit {
+
+    /// The state of the circuit.
+    state: CircuitState,
+
+    /// The outcomes of the most recent attempts. `true` means the attempt
+    /// failed.
+    outcomes: VecDeque<bool>,
+
+    /// When the circuit last opened.
+    opened_at: Instant,
+
+    /// Whether a probe request is in progress while the circuit is half open.
+    probing: bool,
+
+    /// Identifies the most recent probe request, so that a `ProbeGuard` for an
+    /// earlier probe does not release a later one.
+    probe_id: u64,
+
+} // struct
+
+impl std::default::Default for Circuit {
+    /// Returns a closed circuit with no recorded outcomes.
+    fn default() -> Self {
+        Circuit {
+            state: CircuitState::Closed,
+            outcomes: VecDeque::new(),
+            opened_at: Instant::now(),
+            probing: false,
+            probe_id: 0,
+        } // Circuit
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl CircuitBreaker {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Creates a new circuit breaker.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `failure_rate` ‧ The proportion of failed attempts, from `0.0` to
+    /// `1.0`, that will open the circuit. Values outside of this range are
+    /// clamped.
+    ///
+    /// * `window_size` ‧ The number of most recent attempts that the failure
+    /// rate is calculated from. A window size of `0` is treated as `1`.
+    ///
+    /// * `cooldown` ‧ How long requests are rejected for once the circuit has
+    /// opened.
+
+    pub fn new(
+        failure_rate: f64,
+        window_size: u32,
+        cooldown: Duration,
+    ) -> CircuitBreaker {
+        CircuitBreaker {
+            failure_rate: failure_rate.clamp(0.0, 1.0),
+            window_size: window_size.max(1) as usize,
+            cooldown,
+            circuits: Arc::new(Mutex::new(HashMap::new())),
+        } // CircuitBreaker
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the current state of the specified API's circuit.
+
+    pub fn state(&self, api: &Api) -> CircuitState {
+        let circuits = self.circuits.lock().unwrap_or_else(|error| error.into_inner());
+        match circuits.get(api) {
+            // An open circuit whose cooldown has elapsed will let the next
+            // request through as a probe:
+            Some(circuit) if circuit.state == CircuitState::Open
+                && circuit.opened_at.elapsed() >= self.cooldown => CircuitState::HalfOpen,
+            Some(circuit) => circuit.state,
+            None => CircuitState::Closed,
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns an error if the specified API's circuit is open. If the circuit
+    /// is open but the cooldown has elapsed, the circuit becomes half open and
+    /// this request is allowed through as the probe.
+    ///
+    /// The returned guard must be held until the request's outcome has been
+    /// recorded. If the request is the probe, and it is abandoned before its
+    /// outcome is recorded (for example, because its future was dropped),
+    /// dropping the guard lets another request through as the probe.
+
+    pub(crate) fn check(&self, api: &Api) -> Result<ProbeGuard<'_>, GoogleMapsError> {
+        let mut circuits = self.circuits.lock().unwrap_or_else(|error| error.into_inner());
+        let circuit = circuits.entry(api.clone()).or_default();
+        let probe_id = match circuit.state {
+            CircuitState::Closed => None,
+            CircuitState::Open if circuit.opened_at.elapsed() >= self.cooldown => {
+                tracing::info!("Circuit breaker for the {api} API is half open. Sending a probe request");
+                circuit.state = CircuitState::HalfOpen;
+                Some(circuit.start_probe())
+            }, // Open
+            CircuitState::HalfOpen if !circuit.probing => Some(circuit.start_probe()),
+            CircuitState::Open | CircuitState::HalfOpen =>
+                return Err(GoogleMapsError::CircuitOpen { api: api.clone() }),
+        }; // match
+        Ok(ProbeGuard { circuit_breaker: self, api: api.clone(), probe_id })
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Records the outcome of an attempt to the specified API. Returns `true`
+    /// if the circuit is open after this attempt, meaning that the caller
+    /// should stop retrying.
+
+    pub(crate) fn record(&self, api: &Api, failed: bool) -> bool {
+        let mut circuits = self.circuits.lock().unwrap_or_else(|error| error.into_inner());
+        let circuit = circuits.entry(api.clone()).or_default();
+        match circuit.state {
+            CircuitState::HalfOpen => {
+                circuit.probing = false;
+                if failed {
+                    tracing::warn!("Circuit breaker probe for the {api} API failed. Circuit is open");
+                    circuit.state = CircuitState::Open;
+                    circuit.opened_at = Instant::now();
+                } else {
+                    tracing::info!("Circuit breaker probe for the {api} API succeeded. Circuit is closed");
+                    *circuit = Circuit::default();
+                } // if
+            }, // HalfOpen
+            CircuitState::Closed => {
+                circuit.outcomes.push_back(failed);
+                while circuit.outcomes.len() > self.window_size {
+                    circuit.outcomes.pop_front();
+                } // while
+                let failures = circuit.outcomes.iter().filter(|failed| **failed).count();
+                let rate = failures as f64 / self.window_size as f64;
+                if failed && circuit.outcomes.len() == self.window_size && rate >= self.failure_rate {
+                    tracing::error!(
+                        "{failures} of the last {} requests to the {api} API have failed. \
+                        Circuit is open. Rejecting requests for {:?}",
+                        self.window_size,
+                        self.cooldown,
+                    ); // error!
+                    circuit.state = CircuitState::Open;
+                    circuit.opened_at = Instant::now();
+                    circuit.outcomes.clear();
+                } // if
+            }, // Closed
+            // Requests that were already in progress when the circuit opened
+            // do not affect it:
+            CircuitState::Open => (),
+        } // match
+        circuit.state == CircuitState::Open
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Circuit {
+
+    /// Marks a probe request as in progress, and returns its ID.
+
+    fn start_probe(&mut self) -> u64 {
+        self.probing = true;
+        self.probe_id = self.probe_id.wrapping_add(1);
+        self.probe_id
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returned by `CircuitBreaker::check` for a request that is allowed through.
+/// If the request is a half-open circuit's probe, and the probe is still in
+/// progress when the guard is dropped, the probe is released so that the next
+/// request may probe the API instead. Otherwise, dropping the guard does
+/// nothing.
+
+#[derive(Debug)]
+pub(crate) struct ProbeGuard<'a> {
+
+    /// The circuit breaker that allowed the request through.
+    circuit_breaker: &'a CircuitBreaker,
+
+    /// The API that the request is for.
+    api: Api,
+
+    /// The ID of the probe, if the request is a probe.
+    probe_id: Option<u64>,
+
+} // struct
+
+impl std::ops::Drop for ProbeGuard<'_> {
+    /// Releases the probe if its outcome was never recorded.
+    fn drop(&mut self) {
+        if let Some(probe_id) = self.probe_id {
+            let mut circuits = self.circuit_breaker.circuits.lock().unwrap_or_else(|error| error.into_inner());
+            if let Some(circuit) = circuits.get_mut(&self.api) {
+                if circuit.state == CircuitState::HalfOpen
+                    && circuit.probing
+                    && circuit.probe_id == probe_id {
+                    tracing::warn!("Circuit breaker probe for the {} API was abandoned", self.api);
+                    circuit.probing = false;
+                } // if
+            } // if
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    /// Moves the specified API's circuit back in time, so that its cooldown
+    /// has elapsed.
+    fn expire_cooldown(circuit_breaker: &CircuitBreaker, api: &Api) {
+        let mut circuits = circuit_breaker.circuits.lock().unwrap();
+        let circuit = circuits.get_mut(api).unwrap();
+        circuit.opened_at = Instant::now()
+            .checked_sub(circuit_breaker.cooldown)
+            .unwrap();
+    } // fn
+
+    #[test]
+    fn closed_open_half_open_closed() {
+        let api = Api::Geocoding;
+        let circuit_breaker = CircuitBreaker::new(0.5, 2, Duration::from_secs(60));
+        assert_eq!(circuit_breaker.state(&api), CircuitState::Closed);
+
+        // The first failure does not fill the window:
+        drop(circuit_breaker.check(&api).unwrap());
+        assert!(!circuit_breaker.record(&api, true));
+        assert_eq!(circuit_breaker.state(&api), CircuitState::Closed);
+
+        // The second failure opens the circuit:
+        drop(circuit_breaker.check(&api).unwrap());
+        assert!(circuit_breaker.record(&api, true));
+        assert_eq!(circuit_breaker.state(&api), CircuitState::Open);
+        assert!(matches!(
+            circuit_breaker.check(&api),
+            Err(GoogleMapsError::CircuitOpen { api: Api::Geocoding })
+        ));
+
+        // Other APIs are unaffected:
+        assert_eq!(circuit_breaker.state(&Api::Directions), CircuitState::Closed);
+        assert!(circuit_breaker.check(&Api::Directions).is_ok());
+
+        // Once the cooldown has elapsed, a single probe is let through:
+        expire_cooldown(&circuit_breaker, &api);
+        assert_eq!(circuit_breaker.state(&api), CircuitState::HalfOpen);
+        let probe = circuit_breaker.check(&api).unwrap();
+        assert!(circuit_breaker.check(&api).is_err());
+
+        // A successful probe closes the circuit:
+        assert!(!circuit_breaker.record(&api, false));
+        drop(probe);
+        assert_eq!(circuit_breaker.state(&api), CircuitState::Closed);
+        assert!(circuit_breaker.check(&api).is_ok());
+    } // fn
+
+    #[test]
+    fn failed_probe_reopens_circuit() {
+        let api = Api::Directions;
+        let circuit_breaker = CircuitBreaker::new(1.0, 1, Duration::from_secs(60));
+        assert!(circuit_breaker.record(&api, true));
+        expire_cooldown(&circuit_breaker, &api);
+
+        let probe = circuit_breaker.check(&api).unwrap();
+        assert!(circuit_breaker.record(&api, true));
+        drop(probe);
+        assert_eq!(circuit_breaker.state(&api), CircuitState::Open);
+        assert!(circuit_breaker.check(&api).is_err());
+    } // fn
+
+    #[test]
+    fn abandoned_probe_is_released() {
+        let api = Api::TimeZone;
+        let circuit_breaker = CircuitBreaker::new(1.0, 1, Duration::from_secs(60));
+        assert!(circuit_breaker.record(&api, true));
+        expire_cooldown(&circuit_breaker, &api);
+
+        // The probe is abandoned before its outcome is recorded:
+        let probe = circuit_breaker.check(&api).unwrap();
+        assert!(circuit_breaker.check(&api).is_err());
+        drop(probe);
+
+        // The circuit is still half open, and the next request probes it:
+        assert_eq!(circuit_breaker.state(&api), CircuitState::HalfOpen);
+        let probe = circuit_breaker.check(&api).unwrap();
+        assert!(!circuit_breaker.record(&api, false));
+        drop(probe);
+        assert_eq!(circuit_breaker.state(&api), CircuitState::Closed);
+    } // fn
+
+    #[test]
+    fn stale_guard_does_not_release_later_probe() {
+        let api = Api::Elevation;
+        let circuit_breaker = CircuitBreaker::new(1.0, 1, Duration::from_secs(60));
+        assert!(circuit_breaker.record(&api, true));
+        expire_cooldown(&circuit_breaker, &api);
+
+        // The first probe fails, but its guard outlives a second probe:
+        let first_probe = circuit_breaker.check(&api).unwrap();
+        assert!(circuit_breaker.record(&api, true));
+        expire_cooldown(&circuit_breaker, &api);
+        let _second_probe = circuit_breaker.check(&api).unwrap();
+        drop(first_probe);
+
+        // The second probe is still in progress:
+        assert!(circuit_breaker.check(&api).is_err());
+    } // fn
+
+} // mod
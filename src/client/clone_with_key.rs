@@ -0,0 +1,96 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a copy of this client that uses a different API key.
+    ///
+    /// ## Description:
+    ///
+    /// Every other setting is copied. Clones share the `reqwest` connection
+    /// pool, the response cache, and the rate limiters, so creating a client
+    /// for each key is cheap. This may be used to spread requests across
+    /// several API keys, each with its own quota, for example by picking a
+    /// client in round-robin order for each request. To rotate keys within a
+    /// single client, see `with_key_source`. A key source, if one is set,
+    /// takes precedence over the key.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `key` ‧ The API key that the new client sends with its requests.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let clients: Vec<GoogleMapsClient> = keys
+    ///     .iter()
+    ///     .map(|key| google_maps_client.clone_with_key(key))
+    ///     .collect();
+    /// let client = &clients[request_number % clients.len()];
+    /// ```
+
+    pub fn clone_with_key(&self, key: impl Into<String>) -> GoogleMapsClient {
+        GoogleMapsClient {
+            key: key.into(),
+            ..self.clone()
+        } // GoogleMapsClient
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "geocoding"))]
+mod tests {
+
+    use crate::client::GoogleMapsClient;
+
+    /// Returns the value of the `key` query parameter in a URL.
+    fn key(url: &str) -> &str {
+        url.split(['?', '&'])
+            .find_map(|parameter| parameter.strip_prefix("key="))
+            .unwrap_or_else(|| panic!("`{url}` has no key"))
+    } // fn
+
+    #[test]
+    fn rotated_key_appears_in_the_built_url() {
+        let client = GoogleMapsClient::new("FIRST_API_KEY");
+        let rotated = client.clone_with_key("SECOND_API_KEY");
+
+        let url = rotated.geocoding().with_address("Ottawa").query_url().unwrap();
+        assert_eq!(key(&url), "SECOND_API_KEY");
+
+        // The original client is unchanged:
+        let url = client.geocoding().with_address("Ottawa").query_url().unwrap();
+        assert_eq!(key(&url), "FIRST_API_KEY");
+    } // fn
+
+    #[cfg(feature = "enable-reqwest")]
+    #[test]
+    fn rotated_key_is_sent() {
+        use crate::client::test_server::{block_on, TestServer};
+        use crate::client::RetryPolicy;
+        use crate::request_rate::api::Api;
+
+        let body = r#"{"results":[],"status":"ZERO_RESULTS"}"#;
+        let server = TestServer::start(200, &[], body);
+        let mut client = GoogleMapsClient::new("FIRST_API_KEY");
+        client
+            .with_endpoint_override(Api::Geocoding, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none());
+        let client = client.build();
+
+        let clients = [client.clone_with_key("SECOND_API_KEY"), client];
+        for client in &clients {
+            let _ = block_on(client.geocoding().with_address("Ottawa").execute());
+        } // for
+
+        let targets = server.targets();
+        let keys: Vec<&str> = targets.iter().map(|target| key(target)).collect();
+        assert_eq!(keys, ["SECOND_API_KEY", "FIRST_API_KEY"]);
+    } // fn
+
+} // mod
@@ -0,0 +1,160 @@
+use chrono::{FixedOffset, Utc};
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+use crate::time_zone::{
+    error::Error as TimeZoneError,
+    response::{status::Status as TimeZoneStatus, Response as TimeZoneResponse},
+}; // crate::time_zone
+use crate::types::LatLng;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the current UTC offset at a location.
+    ///
+    /// ## Description:
+    ///
+    /// The Time Zone API is queried for the location at the current time, and
+    /// the raw offset and daylight savings offset are combined into a single
+    /// `FixedOffset`. The offset is only valid for the current time. Across a
+    /// daylight savings transition it changes.
+    ///
+    /// If Google did not return a time zone for the location, for example
+    /// because it is at sea,
+    /// `google_maps::time_zone::error::Error::TimeZoneNotFound` is returned.
+    /// Any other error from the Time Zone API is returned as-is.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `location` ‧ Latitude & longitude of the location to look up.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let offset = google_maps_client.current_offset(
+    ///     // St. Vitus Cathedral in Prague, Czechia
+    ///     LatLng::try_from_dec(dec!(50.090_903), dec!(14.400_512))?,
+    /// ).await?;
+    ///
+    /// let local_time = chrono::Utc::now().with_timezone(&offset);
+    /// ```
+
+    pub async fn current_offset(
+        &self,
+        location: LatLng,
+    ) -> Result<FixedOffset, GoogleMapsError> {
+
+        let response = self
+            .time_zone(location.clone(), Utc::now())
+            .execute()
+            .await;
+
+        to_offset(response, location)
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Converts the result of a Time Zone API request into the location's UTC
+/// offset. A `ZERO_RESULTS` status, or a response without offsets, becomes
+/// `TimeZoneNotFound`. Any other error is returned as-is.
+
+fn to_offset(
+    response: Result<TimeZoneResponse, GoogleMapsError>,
+    location: LatLng,
+) -> Result<FixedOffset, GoogleMapsError> {
+
+    let response = match response {
+        Ok(response) => response,
+        // Zero results means that there is no time zone at the location:
+        Err(GoogleMapsError::TimeZone(TimeZoneError::GoogleMapsService(TimeZoneStatus::ZeroResults, _))) =>
+            return Err(TimeZoneError::TimeZoneNotFound(location))?,
+        Err(error) => return Err(error),
+    }; // match
+
+    response
+        .total_offset()
+        .ok_or_else(|| TimeZoneError::TimeZoneNotFound(location).into())
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::client::test_server::{block_on, TestServer};
+    use crate::client::RetryPolicy;
+    use crate::request_rate::api::Api;
+    use rust_decimal_macros::dec;
+
+    const PRAGUE_SUMMER: &str = r#"{
+        "dstOffset": 3600,
+        "rawOffset": 3600,
+        "status": "OK",
+        "timeZoneId": "Europe/Prague",
+        "timeZoneName": "Central European Summer Time"
+    }"#;
+
+    fn location() -> LatLng {
+        LatLng::try_from_dec(dec!(50.090_903), dec!(14.400_512)).unwrap()
+    } // fn
+
+    fn is_time_zone_not_found(result: &Result<FixedOffset, GoogleMapsError>) -> bool {
+        matches!(result, Err(GoogleMapsError::TimeZone(TimeZoneError::TimeZoneNotFound(_))))
+    } // fn
+
+    #[test]
+    fn offsets_are_combined() {
+        let response: TimeZoneResponse = PRAGUE_SUMMER.parse().unwrap();
+        assert_eq!(
+            to_offset(Ok(response), location()).unwrap(),
+            FixedOffset::east_opt(2 * 3600).unwrap(),
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn zero_results_is_time_zone_not_found() {
+        let error = TimeZoneError::GoogleMapsService(TimeZoneStatus::ZeroResults, None);
+        assert!(is_time_zone_not_found(&to_offset(Err(error.into()), location())));
+    } // fn
+
+    #[test]
+    fn missing_offsets_are_time_zone_not_found() {
+        let response: TimeZoneResponse = r#"{"status":"OK"}"#.parse().unwrap();
+        assert!(is_time_zone_not_found(&to_offset(Ok(response), location())));
+    } // fn
+
+    #[test]
+    fn other_errors_are_returned_as_is() {
+        let error = TimeZoneError::GoogleMapsService(TimeZoneStatus::RequestDenied, None);
+        assert!(matches!(
+            to_offset(Err(error.into()), location()),
+            Err(GoogleMapsError::TimeZone(TimeZoneError::GoogleMapsService(TimeZoneStatus::RequestDenied, _))),
+        ));
+    } // fn
+
+    /// Queries a server that answers with the specified body.
+    fn current_offset(body: &str) -> Result<FixedOffset, GoogleMapsError> {
+        let server = TestServer::start(200, &[], body);
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_endpoint_override(Api::TimeZone, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none());
+        let client = client.build();
+        block_on(client.current_offset(location()))
+    } // fn
+
+    #[test]
+    fn current_offset_from_mock() {
+        assert_eq!(current_offset(PRAGUE_SUMMER).unwrap(), FixedOffset::east_opt(7_200).unwrap());
+        assert!(is_time_zone_not_found(&current_offset(r#"{"status":"ZERO_RESULTS"}"#)));
+    } // fn
+
+} // mod
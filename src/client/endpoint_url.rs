@@ -0,0 +1,80 @@
+use crate::client::GoogleMapsClient;
+use crate::request_rate::api::Api;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Applies any endpoint override for the specified API to a request URL.
+    /// The URL's scheme and host are replaced by the API's base URL or, if it
+    /// has none, by the `Api::All` base URL. Otherwise, the URL is returned
+    /// unchanged. See `with_endpoint_override` for more information.
+
+    pub(crate) fn endpoint_url(&self, api: &Api, url: &str) -> String {
+
+        let Some(base_url) = self.endpoint_overrides
+            .get(api)
+            .or_else(|| self.endpoint_overrides.get(&Api::All)) else {
+            return url.to_string();
+        }; // let
+
+        // The path starts at the first slash after the `scheme://` prefix:
+        let path_start = url
+            .find("://")
+            .and_then(|scheme_end| url[scheme_end + 3..].find('/').map(|host_end| scheme_end + 3 + host_end))
+            .unwrap_or(url.len());
+
+        format!("{base_url}{}", &url[path_start..])
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const GEOCODING_URL: &str = "https://maps.googleapis.com/maps/api/geocode/json?address=Ottawa&key=KEY";
+    const DIRECTIONS_URL: &str = "https://maps.googleapis.com/maps/api/directions/json?origin=A&destination=B&key=KEY";
+
+    #[test]
+    fn override_applies_to_one_api() {
+        let mut client = GoogleMapsClient::new("KEY");
+        client.with_endpoint_override(Api::Geocoding, "https://geocoding-proxy.example.com/");
+        let client = client.build();
+        assert_eq!(
+            client.endpoint_url(&Api::Geocoding, GEOCODING_URL),
+            "https://geocoding-proxy.example.com/maps/api/geocode/json?address=Ottawa&key=KEY",
+        ); // assert_eq!
+        // Other APIs keep using Google's default endpoint:
+        assert_eq!(client.endpoint_url(&Api::Directions, DIRECTIONS_URL), DIRECTIONS_URL);
+    } // fn
+
+    #[test]
+    fn all_override_is_a_fallback() {
+        let mut client = GoogleMapsClient::new("KEY");
+        client
+            .with_endpoint_override(Api::All, "http://127.0.0.1:8080/google")
+            .with_endpoint_override(Api::Geocoding, "https://geocoding-proxy.example.com");
+        let client = client.build();
+        assert_eq!(
+            client.endpoint_url(&Api::Directions, DIRECTIONS_URL),
+            "http://127.0.0.1:8080/google/maps/api/directions/json?origin=A&destination=B&key=KEY",
+        ); // assert_eq!
+        assert!(client
+            .endpoint_url(&Api::Geocoding, GEOCODING_URL)
+            .starts_with("https://geocoding-proxy.example.com/maps/api/geocode/json?"));
+    } // fn
+
+    #[test]
+    fn no_overrides() {
+        let client = GoogleMapsClient::new("KEY").build();
+        assert_eq!(client.endpoint_url(&Api::Geocoding, GEOCODING_URL), GEOCODING_URL);
+    } // fn
+
+} // mod
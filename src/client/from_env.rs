@@ -0,0 +1,141 @@
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+
+// -----------------------------------------------------------------------------
+
+/// The name of the environment variable that `GoogleMapsClient::from_env`
+/// reads the Google Maps API key from.
+pub(crate) const DEFAULT_API_KEY_VAR: &str = "GOOGLE_MAPS_API_KEY";
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initialize the settings needed for a Google Cloud Maps API transaction,
+    /// reading the API key from the `GOOGLE_MAPS_API_KEY` environment
+    /// variable. This keeps the key out of your source code.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments. Use the methods of the resulting type.
+    ///
+    /// ## Errors:
+    ///
+    /// Returns `Error::MissingApiKey` if the environment variable is not set
+    /// or is empty.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::from_env()?;
+    /// ```
+    ///
+    /// The returned client may be configured further using the builder
+    /// pattern:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::from_env()?
+    ///     .with_retry_policy(RetryPolicy::none())
+    ///     .build();
+    /// ```
+
+    pub fn from_env() -> Result<GoogleMapsClient, GoogleMapsError> {
+        GoogleMapsClient::from_env_var(DEFAULT_API_KEY_VAR)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initialize the settings needed for a Google Cloud Maps API transaction,
+    /// reading the API key from the specified environment variable.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `name` ‧ The name of the environment variable that contains the
+    /// Google Maps API key.
+    ///
+    /// ## Errors:
+    ///
+    /// Returns `Error::MissingApiKey` if the environment variable is not set,
+    /// is empty, or is not valid unicode.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::from_env_var("MY_MAPS_KEY")?;
+    /// ```
+
+    pub fn from_env_var(name: &str) -> Result<GoogleMapsClient, GoogleMapsError> {
+        match std::env::var(name) {
+            Ok(key) if !key.trim().is_empty() => Ok(GoogleMapsClient::new(key.trim())),
+            _ => {
+                tracing::error!("The `{name}` environment variable does not contain a Google Maps API key");
+                Err(GoogleMapsError::MissingApiKey)
+            }, // _
+        } // match
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Serializes the tests that change the `GOOGLE_MAPS_API_KEY` environment
+    /// variable, since the environment is shared by every test thread.
+    static ENVIRONMENT: Mutex<()> = Mutex::new(());
+
+    /// Runs `test` with `GOOGLE_MAPS_API_KEY` set to `value` (or unset, if
+    /// `value` is `None`), then restores the variable's original value.
+    fn with_default_var<T>(value: Option<&str>, test: impl FnOnce() -> T) -> T {
+        let _guard = ENVIRONMENT.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let original = std::env::var_os(DEFAULT_API_KEY_VAR);
+        match value {
+            Some(value) => std::env::set_var(DEFAULT_API_KEY_VAR, value),
+            None => std::env::remove_var(DEFAULT_API_KEY_VAR),
+        } // match
+        let result = test();
+        match original {
+            Some(original) => std::env::set_var(DEFAULT_API_KEY_VAR, original),
+            None => std::env::remove_var(DEFAULT_API_KEY_VAR),
+        } // match
+        result
+    } // fn
+
+    #[test]
+    fn from_env_reads_set_var() {
+        let client = with_default_var(Some(" ENV_KEY \n"), GoogleMapsClient::from_env);
+        assert_eq!(client.unwrap().key, "ENV_KEY");
+    } // fn
+
+    #[test]
+    fn from_env_rejects_unset_var() {
+        let client = with_default_var(None, GoogleMapsClient::from_env);
+        assert!(matches!(client, Err(GoogleMapsError::MissingApiKey)));
+    } // fn
+
+    #[test]
+    fn from_env_rejects_blank_var() {
+        let client = with_default_var(Some("   "), GoogleMapsClient::from_env);
+        assert!(matches!(client, Err(GoogleMapsError::MissingApiKey)));
+    } // fn
+
+    #[test]
+    fn from_env_var_reads_named_var() {
+        let name = "GOOGLE_MAPS_FROM_ENV_VAR_TEST_KEY";
+        std::env::set_var(name, "NAMED_KEY");
+        let client = GoogleMapsClient::from_env_var(name);
+        std::env::remove_var(name);
+        assert_eq!(client.unwrap().key, "NAMED_KEY");
+        assert!(matches!(
+            GoogleMapsClient::from_env_var(name),
+            Err(GoogleMapsError::MissingApiKey),
+        )); // assert!
+    } // fn
+
+} // mod
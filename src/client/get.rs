@@ -0,0 +1,316 @@
+use backoff::Error::{Permanent, Transient};
+use backoff::future::Retry;
+use crate::client::{cache_key, replace_key, ApiError, GoogleMapsClient, HasStatus, RetryAfter, TimerSleeper};
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::api::Api;
+use serde::de::DeserializeOwned;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Performs an HTTP get request for the specified URL, and returns the
+    /// deserialized response, along with the raw response body, to the
+    /// caller. This method is shared by all of the Google Maps Platform APIs.
+    ///
+    /// ## Description:
+    ///
+    /// * If the client's API key is empty, `Error::MissingApiKey` is returned
+    /// without sending the request. If the client has a key source, it is
+    /// asked for the key instead, and the same applies to the key it returns.
+    ///
+    /// * If the client has a cache, and it holds an unexpired response for the
+    /// URL, the cached response is returned without sending the request.
+    ///
+    /// * If an endpoint override has been set for the API being called, the
+    /// request is sent to the overridden base URL.
+    ///
+    /// * If the circuit breaker for the API being called is open,
+    /// `Error::CircuitOpen` is returned without sending the request.
+    ///
+    /// * Any rate limiting for the specified APIs is observed before the
+    /// request is executed.
+    ///
+    /// * The request is retried until it is successful, an error ineligible for
+    /// retries is returned, or the client's `RetryPolicy` gives up.
+    ///
+    /// * Connection errors, timeouts, HTTP `429 Too Many Requests`, and HTTP
+    /// `5xx Server Error` responses are retried. If the server sends a
+    /// `Retry-After` header, it is honoured, capped at the `RetryPolicy`'s
+    /// limits. The header never causes a retry that the policy would not
+    /// allow.
+    ///
+    /// * Errors reported by Google in the response payload are only retried if
+    /// the response's `HasStatus::is_retryable` method says so.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `url` ‧ The full URL, including the query string, of the request.
+    ///
+    /// * `apis` ‧ The APIs whose rate limits should be observed before
+    /// executing the request. This is typically `Api::All` and the API being
+    /// called.
+
+    pub(crate) async fn get_with_raw<T>(
+        &self,
+        url: &str,
+        apis: Vec<&Api>,
+    ) -> Result<(T, String), GoogleMapsError>
+    where
+        T: DeserializeOwned + HasStatus,
+    {
+
+        // Without an API key, Google will deny every request. Return an error
+        // immediately rather than spending a network round trip:
+        if self.key_source.is_none() && self.key.trim().is_empty() {
+            tracing::error!("{}", GoogleMapsError::MissingApiKey);
+            return Err(GoogleMapsError::MissingApiKey);
+        } // if
+
+        // If a successful response for this URL has been cached, return it
+        // without sending the request. The API key is not part of the cache
+        // key:
+        let cache_key = self.cache.as_ref().map(|_cache| cache_key(url));
+        if let (Some(cache), Some(cache_key)) = (&self.cache, &cache_key) {
+            if let Some(body) = cache.get(cache_key).await {
+                match serde_json::from_str::<T>(&body) {
+                    Ok(deserialized) => {
+                        tracing::debug!("Returning cached response for `{cache_key}`");
+                        return Ok((deserialized, body));
+                    }, // Ok
+                    Err(error) => tracing::warn!("Could not parse cached response: {error}"),
+                } // match
+            } // if
+        } // if
+
+        // The circuit breaker is keyed by the specific API being called, rather
+        // than by `Api::All`:
+        let api = apis
+            .iter()
+            .find(|api| ***api != Api::All)
+            .map_or(Api::All, |api| (*api).clone());
+
+        // Send the request to the API's overridden endpoint, if there is one:
+        let url = self.endpoint_url(&api, url);
+
+        // If the client has a key source, send the request with the key that
+        // it chooses for this API:
+        let url = &match &self.key_source {
+            Some(key_source) => {
+                let key = key_source.next_key(&api);
+                if key.trim().is_empty() {
+                    tracing::error!("{}", GoogleMapsError::MissingApiKey);
+                    return Err(GoogleMapsError::MissingApiKey);
+                } // if
+                replace_key(&url, &key)
+            }, // Some
+            None => url,
+        }; // match
+
+        // If the API's circuit is open, fail fast without sending the request.
+        // The guard is held until the request completes, so that a probe that
+        // is abandoned (for example, because this future was dropped) is
+        // released:
+        let _probe_guard = match &self.circuit_breaker {
+            Some(circuit_breaker) => Some(circuit_breaker.check(&api)?),
+            None => None,
+        }; // match
+
+        // Observe any rate limiting before executing request:
+        self.rate_limit.limit_apis(apis).await;
+
+        // If the client has been configured to send a request ID header,
+        // generate an ID for this request. The same ID is sent with every retry
+        // attempt and is recorded in the caller's tracing span:
+        let request_id = self.new_request_id(&tracing::Span::current());
+
+        // If the client has a tap, it is passed each attempt with the API key
+        // redacted from the URL:
+        let tap_url = self.tap_url(url);
+
+        // Retries the get request until successful, an error ineligible for
+        // retries is returned, or the client's retry policy gives up. Note:
+        // errors wrapped in `Transient()` will retried by the `backoff` crate
+        // while errors wrapped in `Permanent()` will exit the retry loop. The
+        // client's timer is used to wait between attempts.
+        let sleeper = TimerSleeper(self.timer.clone());
+        let notify = |_error, _duration| ();
+        let retry_after = RetryAfter::default();
+        let backoff = self.retry_policy.to_backoff(&retry_after);
+        let response = Retry::new(sleeper, backoff, notify, || async {
+
+            // Query the Google Cloud Maps Platform using using an HTTP get
+            // request, and return result to caller:
+            let response: Result<reqwest::Response, reqwest::Error> =
+                match self.get_request(url, request_id.as_deref()) {
+                    Ok(request) => self.reqwest_client.execute(request).await,
+                    Err(error) => Err(error),
+                }; // match
+
+            // Check response from the HTTP client:
+            let result = match response {
+                Ok(response) => {
+                    // HTTP client was successful getting a response from the
+                    // server. Check the HTTP status code:
+                    let status = response.status();
+                    if status.is_success() {
+                        // If the HTTP GET request was successful, get the
+                        // response text, and show it to the tap:
+                        let text = response.text().await;
+                        self.tap(tap_url.as_deref(), Some(status), text.as_deref().ok());
+                        match text {
+                            Ok(text) => match serde_json::from_str::<T>(&text) {
+                                Ok(deserialized) => {
+                                    // If the response JSON was successfully
+                                    // parsed, check the Google API status
+                                    // before returning it to the caller:
+                                    match deserialized.error() {
+                                        // If Google's response was "Ok" cache
+                                        // the response body, and return the
+                                        // struct deserialized from JSON:
+                                        None => {
+                                            if let (Some(cache), Some(cache_key)) = (&self.cache, &cache_key) {
+                                                cache.put(cache_key, text.clone(), self.cache_ttl).await;
+                                            } // if
+                                            Ok((deserialized, text))
+                                        }, // None
+                                        // Google API returned an error. This
+                                        // indicates an issue with the request.
+                                        // In most cases, retrying will not
+                                        // help:
+                                        Some(error) => if deserialized.is_retryable() {
+                                            tracing::warn!("{}", error);
+                                            Err(Transient { err: error, retry_after: None })
+                                        } else {
+                                            tracing::error!("{}", error);
+                                            Err(Permanent(error))
+                                        }, // Some
+                                    } // match
+                                }, // Ok(deserialized)
+                                Err(error) => {
+                                    tracing::error!("JSON parsing error: {}", error);
+                                    Err(Permanent(T::Error::from(error)))
+                                }, // Err
+                            }, // Ok(text)
+                            // Reading the response body timed out. Retry:
+                            Err(error) if error.is_timeout() => {
+                                tracing::warn!("HTTP client timed out: {}", error);
+                                Err(Transient { err: T::Error::timeout(error), retry_after: None })
+                            }, // Err
+                            Err(error) => {
+                                tracing::error!("HTTP client returned: {}", error);
+                                Err(Permanent(T::Error::reqwest_message(error.to_string())))
+                            }, // Err
+                        } // match
+                    // We got a response from the server but it was not OK.
+                    // Only HTTP "500 Server Errors", and HTTP "429 Too Many
+                    // Requests" are eligible for retries.
+                    } else if status.is_server_error() || status == 429 {
+                        tracing::warn!("HTTP client returned: {}", status);
+                        // If the server told us how long to wait before
+                        // retrying, pass it to the retry policy's back-off,
+                        // which honours it only if the policy allows another
+                        // attempt:
+                        if let Some(delay) = response
+                            .headers()
+                            .get(reqwest::header::RETRY_AFTER)
+                            .and_then(|value| value.to_str().ok())
+                            .and_then(|value| value.trim().parse::<u64>().ok())
+                            .map(std::time::Duration::from_secs) {
+                            retry_after.set(delay);
+                        } // if
+                        self.tap_error_response(tap_url.as_deref(), status, response).await;
+                        Err(Transient {
+                            err: T::Error::http_unsuccessful(status.to_string()),
+                            retry_after: None,
+                        }) // Transient
+                    // Not a 500 Server Error or "429 Too Many Requests" error.
+                    // The error is permanent, do not retry:
+                    } else {
+                        tracing::error!("HTTP client returned: {}", status);
+                        self.tap_error_response(tap_url.as_deref(), status, response).await;
+                        Err(Permanent(T::Error::http_unsuccessful(status.to_string())))
+                    } // if
+                } // case
+                // HTTP client timed out before getting a response from the
+                // server. Retry:
+                Err(error) if error.is_timeout() => {
+                    self.tap(tap_url.as_deref(), None, None);
+                    tracing::warn!("HTTP client timed out: {}", error);
+                    Err(Transient { err: T::Error::timeout(error), retry_after: None })
+                } // case
+                // HTTP client did not get a response from the server. Retry:
+                Err(error) => {
+                    self.tap(tap_url.as_deref(), None, None);
+                    tracing::warn!("HTTP client returned: {}", error);
+                    Err(Transient { err: T::Error::from(error), retry_after: None })
+                } // case
+            }; // match
+
+            // Record the attempt's outcome with the circuit breaker, if there
+            // is one. If the circuit is now open, stop retrying:
+            match (&self.circuit_breaker, result) {
+                (Some(circuit_breaker), Err(Transient { err, retry_after })) =>
+                    if circuit_breaker.record(&api, true) {
+                        Err(Permanent(err))
+                    } else {
+                        Err(Transient { err, retry_after })
+                    }, // Some
+                (Some(circuit_breaker), result) => {
+                    circuit_breaker.record(&api, false);
+                    result
+                }, // Some
+                (None, result) => result,
+            } // match
+
+        }).await.map_err(Into::into)?;
+
+        // Return response to caller:
+        Ok(response)
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "geocoding"))]
+mod tests {
+
+    use crate::client::test_server::{block_on, TestServer};
+    use crate::client::{GoogleMapsClient, RetryPolicy};
+    use crate::error::Error as GoogleMapsError;
+    use crate::request_rate::api::Api;
+
+    /// Sends a geocoding request with the specified API key to a test server.
+    /// Returns the result and the targets of the requests that the server
+    /// received.
+    fn geocode_with_key(key: &str) -> (Result<(), GoogleMapsError>, Vec<String>) {
+        let server = TestServer::start(200, &[], r#"{"results":[],"status":"ZERO_RESULTS"}"#);
+        let mut client = GoogleMapsClient::new(key);
+        client
+            .with_endpoint_override(Api::Geocoding, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none());
+        let client = client.build();
+        let result = block_on(client.geocoding().with_address("Ottawa").execute());
+        (result.map(|_response| ()), server.targets())
+    } // fn
+
+    #[test]
+    fn empty_key_fails_without_sending_request() {
+        for key in ["", "   "] {
+            let (result, targets) = geocode_with_key(key);
+            assert!(matches!(result, Err(GoogleMapsError::MissingApiKey)), "{result:?}");
+            assert!(targets.is_empty(), "{targets:?}");
+        } // for
+    } // fn
+
+    #[test]
+    fn non_empty_key_sends_request() {
+        let (_result, targets) = geocode_with_key("YOUR_API_KEY_HERE");
+        assert_eq!(targets.len(), 1);
+    } // fn
+
+} // mod
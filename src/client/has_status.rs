@@ -0,0 +1,24 @@
+use crate::client::ApiError;
+
+// -----------------------------------------------------------------------------
+//
+/// Implemented by the response of each Google Maps Platform API. It tells the
+/// shared `get` method whether Google reported an error in the response
+/// payload and, if so, whether the request is worth retrying.
+
+pub(crate) trait HasStatus {
+
+    /// The API's error type. Errors reported in the response payload, and
+    /// errors encountered while sending the request, are wrapped in this type.
+    type Error: ApiError;
+
+    /// Returns the error reported by Google in the response payload, or `None`
+    /// if Google reported that the request was successful.
+    fn error(&self) -> Option<Self::Error>;
+
+    /// Returns whether the error reported by Google in the response payload
+    /// is eligible for retries. For most APIs, only Google's "Unknown Error"
+    /// status is retryable.
+    fn is_retryable(&self) -> bool;
+
+} // trait
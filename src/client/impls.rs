@@ -1,5 +1,7 @@
 use crate::client::GoogleMapsClient;
 #[cfg(feature = "enable-reqwest")]
+use crate::client::RetryPolicy;
+#[cfg(feature = "enable-reqwest")]
 use crate::request_rate::RequestRate;
 #[cfg(feature = "directions")]
 use crate::directions::request::location::Location;
@@ -25,15 +27,37 @@ impl GoogleMapsClient {
     #[cfg(feature = "enable-reqwest")]
     pub fn new(key: &str) -> GoogleMapsClient {
 
-        let reqwest_client = reqwest::Client::builder()
-            .user_agent(format!("RustGoogleMaps/{version}", version=env!("CARGO_PKG_VERSION")))
-            .build()
-            .unwrap();
+        let http2_prior_knowledge = false;
+        let tcp_nodelay = true;
+        let connect_timeout = None;
 
         GoogleMapsClient {
             key: key.to_string(),
+            #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places", feature = "time_zone"))]
+            default_language: None,
+            #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
+            default_region: None,
+            key_source: None,
             rate_limit: RequestRate::default(),
-            reqwest_client,
+            reqwest_client: GoogleMapsClient::build_reqwest_client(
+                http2_prior_knowledge,
+                tcp_nodelay,
+                connect_timeout,
+            ), // build_reqwest_client
+            custom_reqwest_client: false,
+            headers: reqwest::header::HeaderMap::new(),
+            request_id_header: None,
+            retry_policy: RetryPolicy::default(),
+            timer: std::sync::Arc::new(crate::client::TokioTimer),
+            circuit_breaker: None,
+            cache: None,
+            cache_ttl: std::time::Duration::ZERO,
+            endpoint_overrides: std::collections::HashMap::new(),
+            http2_prior_knowledge,
+            tcp_nodelay,
+            timeout: None,
+            connect_timeout,
+            tap: None,
         } // GoogleMapsClient
 
     } // fn
@@ -50,6 +74,10 @@ impl GoogleMapsClient {
     pub fn new(key: &str) -> GoogleMapsClient {
         GoogleMapsClient {
             key: key.to_string(),
+            #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places", feature = "time_zone"))]
+            default_language: None,
+            #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
+            default_region: None,
         } // GoogleMapsClient
     } // fn
 
@@ -450,6 +478,7 @@ impl GoogleMapsClient {
     /// [Place Search](https://developers.google.com/maps/documentation/places/web-service/search).
     /// For more information about place IDs, see the
     /// [place ID overview](https://developers.google.com/maps/documentation/places/web-service/place-id).
+    /// Either a `String` or a validated `PlaceId` may be passed.
     ///
     /// ## Basic usage:
     ///
@@ -469,7 +498,7 @@ impl GoogleMapsClient {
     #[cfg(feature = "places")]
     pub fn place_details(
         &self,
-        place_id: String,
+        place_id: impl Into<String>,
     ) -> crate::places::place_details::request::Request {
         crate::places::place_details::request::Request::new(self, place_id)
     } // fn
@@ -482,6 +511,10 @@ impl GoogleMapsClient {
     /// Optionally, you can request that the points be interpolated, resulting
     /// in a path that smoothly follows the geometry of the road.
     ///
+    /// Paths longer than 100 points are automatically split into several
+    /// requests by `execute()`, and the snapped points are stitched back
+    /// together into a single response.
+    ///
     /// ## Arguments:
     ///
     /// * `path` ‧ The path to be snapped. Note: The snapping algorithm works
@@ -530,7 +563,7 @@ impl GoogleMapsClient {
     /// points and returns the closest road segment for each point. The points
     /// passed do not need to be part of a continuous path.
     ///
-    /// **If you are working with sequential GPS points, use Nearest Roads.**
+    /// **If you are working with sequential GPS points, use Snap To Roads.**
     ///
     /// ## Arguments:
     ///
@@ -557,8 +590,37 @@ impl GoogleMapsClient {
     pub fn nearest_roads(
         &self,
         points: Vec<LatLng>,
-    ) -> crate::roads::snap_to_roads::request::Request {
-        crate::roads::snap_to_roads::request::Request::new(self, points)
+    ) -> crate::roads::nearest_roads::request::Request {
+        crate::roads::nearest_roads::request::Request::new(self, points)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Geolocation API returns a location and accuracy radius based on
+    /// information about cell towers and WiFi nodes that the mobile client can
+    /// detect.
+    ///
+    /// Google's Geolocation API currently responds `404 Not Found` with an
+    /// empty body to every request, so `execute()` returns
+    /// `Error::ServiceUnavailable` until the service is restored.
+    ///
+    /// ## Basic usage:
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let mut request = google_maps_client.geolocation();
+    /// request.consider_ip = Some(false);
+    /// let location = request.execute().await?;
+    /// ```
+
+    #[cfg(feature = "geolocation")]
+    pub fn geolocation(
+        &self,
+    ) -> crate::geolocation::request::Request {
+        crate::geolocation::request::Request::new(self)
     } // fn
 
 } // impl
\ No newline at end of file
@@ -0,0 +1,312 @@
+//! Contains the `KeySource` trait, which lets the Google Maps client choose an
+//! API key for each request, and its `RoundRobinKeys` and `EnvKeys`
+//! implementations.
+
+use crate::request_rate::api::Api;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// -----------------------------------------------------------------------------
+//
+/// Supplies the API key for each request.
+///
+/// By default, every request is sent with the client's `key`. When a key
+/// source is set, it is asked for a key each time a request is sent, which
+/// makes it possible to spread requests across several keys (each with its
+/// own quota), or to use a different key for each API. The key is chosen once
+/// per request: retries of the request use the same key.
+///
+/// `RoundRobinKeys` and `EnvKeys` are provided. Set using the
+/// `GoogleMapsClient::with_key_source` method.
+///
+/// ## Example:
+///
+/// ```rust
+/// #[derive(Debug)]
+/// struct PerApiKeys { geocoding: String, other: String }
+///
+/// impl KeySource for PerApiKeys {
+///     fn next_key(&self, api: &Api) -> String {
+///         match api {
+///             Api::Geocoding => self.geocoding.clone(),
+///             _ => self.other.clone(),
+///         }
+///     }
+/// }
+/// ```
+
+pub trait KeySource: std::fmt::Debug + Send + Sync {
+
+    /// Returns the API key to send the next request for the specified API
+    /// with. Returning an empty key causes the request to fail with
+    /// `Error::MissingApiKey`.
+    fn next_key(&self, api: &Api) -> String;
+
+} // trait
+
+// -----------------------------------------------------------------------------
+//
+/// A `KeySource` that uses each of several API keys in turn, regardless of the
+/// API being called.
+
+pub struct RoundRobinKeys {
+
+    /// The API keys to rotate through.
+    keys: Vec<String>,
+
+    /// The index of the next key to use. This is shared between clones of the
+    /// client.
+    next: AtomicUsize,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl RoundRobinKeys {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Creates a key source that rotates through the specified API keys.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `keys` ‧ The API keys to rotate through, in order. If no keys are
+    /// specified, every request fails with `Error::MissingApiKey`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::new("")
+    ///     .with_key_source(RoundRobinKeys::new(["FIRST_API_KEY", "SECOND_API_KEY"]))
+    ///     .build();
+    /// ```
+
+    pub fn new(keys: impl IntoIterator<Item = impl Into<String>>) -> RoundRobinKeys {
+        RoundRobinKeys {
+            keys: keys.into_iter().map(Into::into).collect(),
+            next: AtomicUsize::new(0),
+        } // RoundRobinKeys
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl KeySource for RoundRobinKeys {
+    /// Returns the next key in turn.
+    fn next_key(&self, _api: &Api) -> String {
+        if self.keys.is_empty() {
+            return String::new();
+        } // if
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.keys.len();
+        self.keys[index].clone()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for RoundRobinKeys {
+    /// Formats the key source for debugging. The keys themselves are not shown
+    /// so that they are not leaked into logs.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RoundRobinKeys")
+            .field("keys", &self.keys.len())
+            .field("next", &self.next)
+            .finish()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A `KeySource` that reads a separate API key for each API from the
+/// environment.
+///
+/// For each request, the environment variable named after the prefix and the
+/// API is read, for example `GOOGLE_MAPS_API_KEY_GEOCODING` or
+/// `GOOGLE_MAPS_API_KEY_DISTANCE_MATRIX`. If it is not set, the prefix itself
+/// (for example `GOOGLE_MAPS_API_KEY`) is read instead. Because the
+/// environment is read for every request, keys may be changed without
+/// restarting the application.
+
+#[derive(Clone, Debug)]
+pub struct EnvKeys {
+
+    /// The name of the environment variable holding the default API key, and
+    /// the prefix of the per-API environment variables.
+    prefix: String,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl EnvKeys {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Creates a key source that reads the `GOOGLE_MAPS_API_KEY` environment
+    /// variable, or its per-API variants such as
+    /// `GOOGLE_MAPS_API_KEY_GEOCODING`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::new("")
+    ///     .with_key_source(EnvKeys::new())
+    ///     .build();
+    /// ```
+
+    pub fn new() -> EnvKeys {
+        EnvKeys::with_prefix(crate::client::from_env::DEFAULT_API_KEY_VAR)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Creates a key source that reads the specified environment variable, or
+    /// its per-API variants.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `prefix` ‧ The name of the environment variable holding the default
+    /// API key. Per-API keys are read from this name followed by an underscore
+    /// and the API's name, for example `MAPS_KEY_TIME_ZONE`.
+
+    pub fn with_prefix(prefix: impl Into<String>) -> EnvKeys {
+        EnvKeys { prefix: prefix.into() }
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for EnvKeys {
+    /// Returns a key source that reads the `GOOGLE_MAPS_API_KEY` environment
+    /// variable, or its per-API variants.
+    fn default() -> Self {
+        EnvKeys::new()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl KeySource for EnvKeys {
+    /// Returns the API's key from the environment, falling back to the default
+    /// key.
+    fn next_key(&self, api: &Api) -> String {
+        let suffix = String::from(api).to_ascii_uppercase().replace(' ', "_");
+        std::env::var(format!("{}_{suffix}", self.prefix))
+            .or_else(|_error| std::env::var(&self.prefix))
+            .unwrap_or_default()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Replaces the value of the `key` query parameter in a request URL.
+
+pub(crate) fn replace_key(url: &str, key: &str) -> String {
+    let key = utf8_percent_encode(key, NON_ALPHANUMERIC).to_string();
+    match url.split_once('?') {
+        Some((path, query)) => {
+            let query = query
+                .split('&')
+                .map(|parameter| if parameter.starts_with("key=") {
+                    format!("key={key}")
+                } else {
+                    parameter.to_string()
+                }) // map
+                .collect::<Vec<String>>()
+                .join("&");
+            format!("{path}?{query}")
+        }, // Some
+        None => url.to_string(),
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn round_robin_keys_rotate_in_order() {
+        let keys = RoundRobinKeys::new(["FIRST", "SECOND", "THIRD"]);
+        let chosen: Vec<String> = [Api::Geocoding, Api::Directions, Api::TimeZone]
+            .iter()
+            .cycle()
+            .take(7)
+            .map(|api| keys.next_key(api))
+            .collect();
+        assert_eq!(chosen, ["FIRST", "SECOND", "THIRD", "FIRST", "SECOND", "THIRD", "FIRST"]);
+    } // fn
+
+    #[test]
+    fn round_robin_keys_without_keys_return_an_empty_key() {
+        let keys = RoundRobinKeys::new(Vec::<String>::new());
+        assert_eq!(keys.next_key(&Api::Geocoding), "");
+    } // fn
+
+    #[test]
+    fn round_robin_keys_are_not_leaked_by_debug() {
+        let keys = RoundRobinKeys::new(["SECRET_API_KEY"]);
+        assert!(!format!("{keys:?}").contains("SECRET_API_KEY"));
+    } // fn
+
+    #[test]
+    fn env_keys_prefer_the_per_api_variable() {
+        // A prefix that no other test uses, so that setting the environment
+        // does not race with other tests:
+        let prefix = "GOOGLE_MAPS_KEY_SOURCE_TEST";
+        let keys = EnvKeys::with_prefix(prefix);
+        std::env::set_var(prefix, "DEFAULT_KEY");
+        std::env::set_var(format!("{prefix}_TIME_ZONE"), "TIME_ZONE_KEY");
+        assert_eq!(keys.next_key(&Api::TimeZone), "TIME_ZONE_KEY");
+        assert_eq!(keys.next_key(&Api::Geocoding), "DEFAULT_KEY");
+    } // fn
+
+    #[test]
+    fn replace_key_replaces_only_the_key_parameter() {
+        assert_eq!(
+            replace_key("https://example.com/json?address=key%3Dvalue&key=OLD&monkey=1", "NEW"),
+            "https://example.com/json?address=key%3Dvalue&key=NEW&monkey=1",
+        ); // assert_eq!
+        assert_eq!(
+            replace_key("https://example.com/json?key=OLD", "A&B=C"),
+            "https://example.com/json?key=A%26B%3DC",
+        ); // assert_eq!
+        assert_eq!(replace_key("https://example.com/json", "NEW"), "https://example.com/json");
+    } // fn
+
+    #[cfg(feature = "geocoding")]
+    #[test]
+    fn requests_are_distributed_round_robin() {
+        use crate::client::test_server::{block_on, TestServer};
+        use crate::client::{GoogleMapsClient, RetryPolicy};
+
+        let body = r#"{"results":[],"status":"ZERO_RESULTS"}"#;
+        let server = TestServer::start(200, &[], body);
+        let mut client = GoogleMapsClient::new("UNUSED_API_KEY");
+        client
+            .with_endpoint_override(Api::Geocoding, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none())
+            .with_key_source(RoundRobinKeys::new(["FIRST", "SECOND", "THIRD"]));
+        let client = client.build();
+
+        for address in ["Ottawa", "Toronto", "Montréal", "Calgary", "Halifax", "Regina"] {
+            let _ = block_on(client.geocoding().with_address(address).execute());
+        } // for
+
+        let keys: Vec<String> = server
+            .targets()
+            .iter()
+            .map(|target| target
+                .split(['?', '&'])
+                .find_map(|parameter| parameter.strip_prefix("key="))
+                .expect("request has no key")
+                .to_string())
+            .collect();
+        assert_eq!(keys, ["FIRST", "SECOND", "THIRD", "FIRST", "SECOND", "THIRD"]);
+    } // fn
+
+} // mod
@@ -0,0 +1,156 @@
+//! Contains the `MemoryCache` struct, an in-memory cache of successful
+//! responses from the Google Maps Platform.
+
+use crate::client::ResponseCache;
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// -----------------------------------------------------------------------------
+//
+/// A least-recently-used (LRU) cache of successful response bodies, with
+/// time-based expiry. This is the default `ResponseCache` backend.
+///
+/// Once the cache is full, the least recently used response is evicted to
+/// make room for a new one. A cached response expires once the `ttl` that it
+/// was stored with has elapsed.
+///
+/// The cache is shared by every clone of the client. Set using the
+/// `GoogleMapsClient::with_cache` method.
+
+#[derive(Clone, Debug)]
+pub struct MemoryCache {
+
+    /// The maximum number of responses that may be cached.
+    capacity: usize,
+
+    /// The cached responses. This is shared between clones of the client.
+    entries: Arc<Mutex<Entries>>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+/// The mutable state of the cache.
+
+#[derive(Debug, Default)]
+struct Entries {
+
+    /// The cached responses, keyed by request URL.
+    map: HashMap<String, Entry>,
+
+    /// Incremented every time the cache is used. Each entry records the value
+    /// it was last used at, so that the least recently used entry can be found.
+    clock: u64,
+
+} // struct
+
+/// A single cached response.
+
+#[derive(Debug)]
+struct Entry {
+
+    /// The response body, as returned by Google.
+    body: String,
+
+    /// When the response expires.
+    expires_at: Instant,
+
+    /// The value of the clock when the response was last used.
+    last_used: u64,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl MemoryCache {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Creates a new, empty cache.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `capacity` ‧ The maximum number of responses that may be cached. A
+    /// capacity of `0` disables caching.
+
+    pub fn new(capacity: usize) -> MemoryCache {
+        MemoryCache {
+            capacity,
+            entries: Arc::new(Mutex::new(Entries::default())),
+        } // MemoryCache
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes every response from the cache.
+
+    pub fn clear(&self) {
+        let mut entries = self.entries.lock().unwrap_or_else(|error| error.into_inner());
+        entries.map.clear();
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl ResponseCache for MemoryCache {
+
+    /// Returns the cached response body for the specified key, if it is
+    /// present and has not expired.
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>> {
+        Box::pin(async move {
+            let mut entries = self.entries.lock().unwrap_or_else(|error| error.into_inner());
+            entries.clock += 1;
+            let clock = entries.clock;
+            match entries.map.get_mut(key) {
+                Some(entry) if entry.expires_at > Instant::now() => {
+                    entry.last_used = clock;
+                    Some(entry.body.clone())
+                }, // Some
+                Some(_expired) => {
+                    entries.map.remove(key);
+                    None
+                }, // Some
+                None => None,
+            } // match
+        }) // Box::pin
+    } // fn
+
+    /// Stores a response body under the specified key. If the cache is full,
+    /// expired responses are removed and, if it is still full, the least
+    /// recently used response is evicted.
+    fn put<'a>(&'a self, key: &'a str, value: String, ttl: Duration) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            if self.capacity == 0 {
+                return;
+            } // if
+
+            let mut entries = self.entries.lock().unwrap_or_else(|error| error.into_inner());
+            entries.clock += 1;
+            let clock = entries.clock;
+
+            if !entries.map.contains_key(key) && entries.map.len() >= self.capacity {
+                let now = Instant::now();
+                entries.map.retain(|_key, entry| entry.expires_at > now);
+                if entries.map.len() >= self.capacity {
+                    let least_recently_used = entries.map
+                        .iter()
+                        .min_by_key(|(_key, entry)| entry.last_used)
+                        .map(|(key, _entry)| key.clone());
+                    if let Some(least_recently_used) = least_recently_used {
+                        entries.map.remove(&least_recently_used);
+                    } // if
+                } // if
+            } // if
+
+            entries.map.insert(key.to_string(), Entry {
+                body: value,
+                expires_at: Instant::now() + ttl,
+                last_used: clock,
+            }); // insert
+        }) // Box::pin
+    } // fn
+
+} // impl
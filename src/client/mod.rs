@@ -4,15 +4,104 @@
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "enable-reqwest")]
+mod api_error;
 mod build;
+mod clone_with_key;
+#[cfg(feature = "enable-reqwest")]
+mod circuit_breaker;
+#[cfg(all(feature = "time_zone", feature = "enable-reqwest"))]
+mod current_offset;
+mod debug;
+#[cfg(feature = "enable-reqwest")]
+mod endpoint_url;
+mod from_env;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+#[cfg(feature = "enable-reqwest")]
+mod has_status;
 mod impls;
 #[cfg(feature = "enable-reqwest")]
+mod key_source;
+#[cfg(feature = "enable-reqwest")]
+mod memory_cache;
+#[cfg(all(feature = "geocoding", feature = "enable-reqwest"))]
+mod postal_code_bounds;
+#[cfg(feature = "enable-reqwest")]
+mod rate_limit_status;
+#[cfg(feature = "enable-reqwest")]
+mod request_id;
+#[cfg(feature = "enable-reqwest")]
+mod reqwest_client;
+#[cfg(feature = "enable-reqwest")]
+mod response_cache;
+#[cfg(feature = "enable-reqwest")]
+mod retry_policy;
+#[cfg(feature = "enable-reqwest")]
+mod tap;
+#[cfg(all(test, feature = "enable-reqwest"))]
+pub(crate) mod test_server;
+#[cfg(feature = "enable-reqwest")]
+mod timer;
+#[cfg(feature = "enable-reqwest")]
+mod with_cache;
+#[cfg(feature = "enable-reqwest")]
+mod with_circuit_breaker;
+#[cfg(feature = "enable-reqwest")]
+mod with_connect_timeout;
+#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places", feature = "time_zone"))]
+mod with_default_language;
+#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
+mod with_default_region;
+#[cfg(feature = "enable-reqwest")]
+mod with_endpoint_override;
+#[cfg(feature = "enable-reqwest")]
+mod with_header;
+#[cfg(feature = "enable-reqwest")]
+mod with_http2_prior_knowledge;
+#[cfg(feature = "enable-reqwest")]
+mod with_key_source;
+#[cfg(feature = "enable-reqwest")]
 mod with_rate;
 #[cfg(feature = "enable-reqwest")]
+mod with_request_id_header;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
+#[cfg(feature = "enable-reqwest")]
+mod with_tap;
+#[cfg(feature = "enable-reqwest")]
+mod with_tcp_nodelay;
+#[cfg(feature = "enable-reqwest")]
+mod with_timer;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
+#[cfg(feature = "enable-reqwest")]
 mod with_reqwest_client;
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "enable-reqwest")]
+pub(crate) use crate::client::{
+    api_error::ApiError,
+    has_status::HasStatus,
+    key_source::replace_key,
+    response_cache::cache_key,
+    retry_policy::RetryAfter,
+    tap::Tap,
+    timer::TimerSleeper,
+}; // crate::client
+
+#[cfg(feature = "enable-reqwest")]
+pub use crate::client::{
+    circuit_breaker::{CircuitBreaker, CircuitState},
+    key_source::{EnvKeys, KeySource, RoundRobinKeys},
+    memory_cache::MemoryCache,
+    response_cache::ResponseCache,
+    retry_policy::RetryPolicy,
+    tap::TapEvent,
+    timer::{Timer, TokioTimer},
+}; // crate::client
+
 #[cfg(feature = "enable-reqwest")]
 use crate::request_rate::RequestRate;
 
@@ -35,8 +124,10 @@ use crate::request_rate::RequestRate;
 ///     .with_rate(Api::All, 1, std::time::Duration::from_secs(2))
 ///     .build();
 /// ```
+///
+/// The client's `Debug` output redacts the API key.
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct GoogleMapsClient {
 
     /// Your application's API key. This key identifies your application for
@@ -45,6 +136,24 @@ pub struct GoogleMapsClient {
     /// Contains the application's API key and other settings.
     pub key: String,
 
+    /// Supplies the API key for each request, instead of `key`. Set using the
+    /// `with_key_source` method. Defaults to `None`, meaning that every
+    /// request is sent with `key`.
+    #[cfg(feature = "enable-reqwest")]
+    pub key_source: Option<std::sync::Arc<dyn KeySource>>,
+
+    /// The language that every request made with this client is sent with,
+    /// unless the request sets its own language using `with_language`. Set
+    /// using the `with_default_language` method. Defaults to `None`.
+    #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places", feature = "time_zone"))]
+    pub default_language: Option<crate::types::Language>,
+
+    /// The region that every request made with this client is biased to,
+    /// unless the request sets its own region using `with_region`. Set using
+    /// the `with_default_region` method. Defaults to `None`.
+    #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
+    pub default_region: Option<crate::types::Region>,
+
     /// Rate limits for each of the Google Cloud Maps Platform APIs.
     #[cfg(feature = "enable-reqwest")]
     pub rate_limit: RequestRate,
@@ -54,4 +163,83 @@ pub struct GoogleMapsClient {
     #[cfg(feature = "enable-reqwest")]
     pub reqwest_client: reqwest::Client,
 
+    /// Whether `reqwest_client` was passed in using the `with_reqwest_client`
+    /// method, rather than built by the Google Maps client. A custom client is
+    /// never rebuilt by the connection setting methods.
+    #[cfg(feature = "enable-reqwest")]
+    pub(crate) custom_reqwest_client: bool,
+
+    /// HTTP headers that are sent with every request. Set using the
+    /// `with_header` method. Defaults to no additional headers.
+    #[cfg(feature = "enable-reqwest")]
+    pub headers: reqwest::header::HeaderMap,
+
+    /// The name of the HTTP header that carries a generated request ID. When
+    /// set, each outgoing request is sent with a new UUID in this header and
+    /// the same ID is recorded in the request's tracing span. Defaults to
+    /// `None`, meaning that no request ID header is sent.
+    #[cfg(feature = "enable-reqwest")]
+    pub request_id_header: Option<reqwest::header::HeaderName>,
+
+    /// How failed requests are retried. Set using the `with_retry_policy`
+    /// method. Defaults to the `backoff` crate's default exponential back-off.
+    #[cfg(feature = "enable-reqwest")]
+    pub retry_policy: RetryPolicy,
+
+    /// Waits between retry attempts, and between the pages of a streamed Text
+    /// Search. Set using the `with_timer` method. Defaults to `TokioTimer`.
+    #[cfg(feature = "enable-reqwest")]
+    pub timer: std::sync::Arc<dyn Timer>,
+
+    /// Stops sending requests to an API that is failing. Set using the
+    /// `with_circuit_breaker` or `with_circuit_breaker_rate` methods. Defaults
+    /// to `None`, meaning that there is no circuit breaker.
+    #[cfg(feature = "enable-reqwest")]
+    pub circuit_breaker: Option<CircuitBreaker>,
+
+    /// Caches successful responses. Set using the `with_cache` or
+    /// `with_cache_backend` methods. Defaults to `None`, meaning that
+    /// responses are not cached.
+    #[cfg(feature = "enable-reqwest")]
+    pub cache: Option<std::sync::Arc<dyn ResponseCache>>,
+
+    /// How long successful responses are cached for. Set using the
+    /// `with_cache` or `with_cache_backend` methods.
+    #[cfg(feature = "enable-reqwest")]
+    pub cache_ttl: std::time::Duration,
+
+    /// Base URLs that replace `https://maps.googleapis.com` (or
+    /// `https://roads.googleapis.com`) for specific APIs, for example to route
+    /// requests through a proxy. Set using the `with_endpoint_override`
+    /// method. Defaults to no overrides.
+    #[cfg(feature = "enable-reqwest")]
+    pub endpoint_overrides: std::collections::HashMap<crate::request_rate::api::Api, String>,
+
+    /// Whether the `reqwest` client only uses HTTP/2. Set using the
+    /// `with_http2_prior_knowledge` method. Defaults to `false`.
+    #[cfg(feature = "enable-reqwest")]
+    pub(crate) http2_prior_knowledge: bool,
+
+    /// Whether the `reqwest` client's sockets have `TCP_NODELAY` enabled. Set
+    /// using the `with_tcp_nodelay` method. Defaults to `true`.
+    #[cfg(feature = "enable-reqwest")]
+    pub(crate) tcp_nodelay: bool,
+
+    /// The total time allowed for each HTTP request attempt, from connecting
+    /// until the response body has been read. Set using the `with_timeout`
+    /// method. Defaults to `None`, meaning that there is no timeout.
+    #[cfg(feature = "enable-reqwest")]
+    pub(crate) timeout: Option<std::time::Duration>,
+
+    /// The time allowed for the `reqwest` client to connect to the server. Set
+    /// using the `with_connect_timeout` method. Defaults to `None`, meaning
+    /// that there is no connect timeout.
+    #[cfg(feature = "enable-reqwest")]
+    pub(crate) connect_timeout: Option<std::time::Duration>,
+
+    /// A callback that sees every HTTP request attempt and its raw response.
+    /// Set using the `with_tap` method. Defaults to `None`.
+    #[cfg(feature = "enable-reqwest")]
+    pub(crate) tap: Option<Tap>,
+
 } // struct
\ No newline at end of file
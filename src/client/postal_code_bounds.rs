@@ -0,0 +1,169 @@
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+use crate::geocoding::{
+    error::Error as GeocodingError,
+    forward::component::Component as GeocodingComponent,
+    response::status::Status as GeocodingStatus,
+}; // crate::geocoding
+use crate::types::{Bounds, Country};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the bounding box of a postal code, rather than just its centre
+    /// point. Useful for building shipping zones, or for other GIS work.
+    ///
+    /// ## Description:
+    ///
+    /// The postal code is geocoded using a components filter that restricts the
+    /// results to the specified postal code and country. The `bounds` of the
+    /// first result are returned. If Google did not return `bounds` for the
+    /// result, its recommended `viewport` is returned instead.
+    ///
+    /// If Google did not return a result for the postal code,
+    /// `google_maps::geocoding::error::Error::PostalCodeNotFound` is returned.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `postal_code` ‧ The postal code or ZIP code to look up. For example,
+    /// `T5J 0N3` or `90210`.
+    ///
+    /// * `country` ‧ The country that the postal code belongs to. Postal codes
+    /// are not unique across countries.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let bounds = google_maps_client.postal_code_bounds(
+    ///     "T5J 0N3",
+    ///     Country::Canada,
+    /// ).await?;
+    /// ```
+
+    pub async fn postal_code_bounds(
+        &self,
+        postal_code: &str,
+        country: Country,
+    ) -> Result<Bounds, GoogleMapsError> {
+
+        let mut request = self.geocoding();
+
+        let response = request
+            .with_component(GeocodingComponent::PostalCode(postal_code.to_string()))
+            .with_component(GeocodingComponent::Country(country))
+            .execute()
+            .await;
+
+        let response = match response {
+            Ok(response) => response,
+            // Zero results means that the postal code was not found:
+            Err(GoogleMapsError::Geocoding(GeocodingError::GoogleMapsService(GeocodingStatus::ZeroResults, _))) =>
+                return Err(GeocodingError::PostalCodeNotFound(postal_code.to_string()))?,
+            Err(error) => return Err(error),
+        }; // match
+
+        response.results
+            .into_iter()
+            .next()
+            .map(|result| result.geometry.bounds.unwrap_or(result.geometry.viewport))
+            .ok_or_else(|| GeocodingError::PostalCodeNotFound(postal_code.to_string()).into())
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::test_server::{block_on, TestServer};
+    use crate::client::{GoogleMapsClient, RetryPolicy};
+    use crate::error::Error as GoogleMapsError;
+    use crate::geocoding::error::Error as GeocodingError;
+    use crate::request_rate::api::Api;
+    use crate::types::{Bounds, Country};
+    use serde_json::{json, Value};
+
+    /// A bounding box around downtown Edmonton.
+    fn edmonton_bounds() -> Value {
+        json!({
+            "northeast": { "lat": 53.5468, "lng": -113.4869 },
+            "southwest": { "lat": 53.5401, "lng": -113.4993 },
+        }) // json!
+    } // fn
+
+    /// A slightly larger viewport around downtown Edmonton.
+    fn edmonton_viewport() -> Value {
+        json!({
+            "northeast": { "lat": 53.5480, "lng": -113.4850 },
+            "southwest": { "lat": 53.5390, "lng": -113.5010 },
+        }) // json!
+    } // fn
+
+    /// Looks up the bounds of a postal code from a test server that answers
+    /// with the specified geocoding response. Returns the result and the
+    /// targets of the requests that the server received.
+    fn postal_code_bounds(response: Value) -> (Result<Bounds, GoogleMapsError>, Vec<String>) {
+        let server = TestServer::start(200, &[], &response.to_string());
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_endpoint_override(Api::Geocoding, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none());
+        let client = client.build();
+        let result = block_on(client.postal_code_bounds("T5J 0N3", Country::Canada));
+        (result, server.targets())
+    } // fn
+
+    /// A geocoding response with one result that has the specified geometry.
+    fn response(geometry: Value) -> Value {
+        json!({
+            "results": [{
+                "address_components": [],
+                "formatted_address": "Edmonton, AB T5J 0N3, Canada",
+                "geometry": geometry,
+                "place_id": "ChIJ8Qm6O0kioFMRz2bW3Jb6yN8",
+                "types": ["postal_code"],
+            }],
+            "status": "OK",
+        }) // json!
+    } // fn
+
+    #[test]
+    fn returns_bounds() {
+        let (result, targets) = postal_code_bounds(response(json!({
+            "location": { "lat": 53.5434, "lng": -113.4931 },
+            "location_type": "APPROXIMATE",
+            "bounds": edmonton_bounds(),
+            "viewport": edmonton_viewport(),
+        }))); // postal_code_bounds
+        let expected: Bounds = serde_json::from_value(edmonton_bounds()).unwrap();
+        assert_eq!(result.unwrap(), expected);
+        assert_eq!(targets.len(), 1);
+        assert!(targets[0].contains("components="), "{}", targets[0]);
+    } // fn
+
+    #[test]
+    fn falls_back_to_viewport() {
+        let (result, _targets) = postal_code_bounds(response(json!({
+            "location": { "lat": 53.5434, "lng": -113.4931 },
+            "location_type": "APPROXIMATE",
+            "viewport": edmonton_viewport(),
+        }))); // postal_code_bounds
+        let expected: Bounds = serde_json::from_value(edmonton_viewport()).unwrap();
+        assert_eq!(result.unwrap(), expected);
+    } // fn
+
+    #[test]
+    fn zero_results_is_not_found() {
+        let (result, _targets) = postal_code_bounds(json!({ "results": [], "status": "ZERO_RESULTS" }));
+        assert!(matches!(
+            result,
+            Err(GoogleMapsError::Geocoding(GeocodingError::PostalCodeNotFound(ref code))) if code == "T5J 0N3",
+        ), "{result:?}"); // assert!
+    } // fn
+
+} // mod
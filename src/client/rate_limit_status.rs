@@ -0,0 +1,90 @@
+use crate::client::GoogleMapsClient;
+use crate::request_rate::{api::Api, rate_limit_status::RateLimitStatus};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a snapshot of the rate limiter for the specified API: the
+    /// configured rate, the number of requests sent within the current window,
+    /// and how long until the window has room for another request.
+    ///
+    /// ## Description:
+    ///
+    /// `None` is returned if no rate limit has been set for the API using
+    /// `with_rate`. The rate limiter's state is shared by every clone of this
+    /// client. Reading it does not affect rate limiting.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ The API to inspect. For example, `Api::Geocoding` or
+    /// `Api::All`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// if let Some(status) = google_maps_client.rate_limit_status(Api::Geocoding) {
+    ///     gauge.set(status.remaining() as f64);
+    /// }
+    /// ```
+
+    pub fn rate_limit_status(&self, api: Api) -> Option<RateLimitStatus> {
+        self.rate_limit.status(&api)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "directions", feature = "distance_matrix"))]
+mod tests {
+
+    use crate::client::test_server::{block_on, TestServer};
+    use crate::client::{GoogleMapsClient, RetryPolicy};
+    use crate::directions::request::{location::Location, waypoint::Waypoint};
+    use crate::request_rate::api::Api;
+    use std::time::Duration;
+
+    #[test]
+    fn directions_and_distance_matrix_have_separate_buckets() {
+        let directions_server = TestServer::start(200, &[], r#"{"routes":[],"status":"ZERO_RESULTS"}"#);
+        let distance_matrix_server = TestServer::start(
+            200,
+            &[],
+            r#"{"destination_addresses":[],"origin_addresses":[],"rows":[],"status":"OK"}"#,
+        ); // start
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_endpoint_override(Api::Directions, directions_server.url.as_str())
+            .with_endpoint_override(Api::DistanceMatrix, distance_matrix_server.url.as_str())
+            .with_retry_policy(RetryPolicy::none())
+            .with_rate(Api::All, 100, Duration::from_secs(60))
+            .with_rate(Api::Directions, 50, Duration::from_secs(60))
+            .with_rate(Api::DistanceMatrix, 10, Duration::from_secs(60));
+        let client = client.build();
+
+        for _ in 0..2 {
+            let _ = block_on(client
+                .directions(
+                    Location::Address(String::from("Toronto, ON")),
+                    Location::Address(String::from("Montréal, QC")),
+                ) // directions
+                .execute());
+        } // for
+        let _ = block_on(client
+            .distance_matrix(
+                vec![Waypoint::Address(String::from("Toronto, ON"))],
+                vec![Waypoint::Address(String::from("Montréal, QC"))],
+            ) // distance_matrix
+            .execute());
+
+        let count = |api: Api| client.rate_limit_status(api).unwrap().current_count;
+        assert_eq!(count(Api::Directions), 2);
+        assert_eq!(count(Api::DistanceMatrix), 1);
+        // Both are also limited by the shared bucket:
+        assert_eq!(count(Api::All), 3);
+    } // fn
+
+} // mod
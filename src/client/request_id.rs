@@ -0,0 +1,154 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Generates a new request ID if the client has been configured with
+    /// `with_request_id_header`. Otherwise, returns `None`.
+    ///
+    /// The ID is recorded in the `request_id` field of the specified `tracing`
+    /// span, which is normally the calling `get` method's span. The span must
+    /// declare that field.
+
+    pub(crate) fn new_request_id(&self, span: &tracing::Span) -> Option<String> {
+
+        self.request_id_header.as_ref()?;
+
+        // Build a version 4 (random) UUID. The version and variant bits are
+        // set as described in RFC 4122:
+        let mut bytes: [u8; 16] = rand::random();
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        let hex: String = bytes
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+
+        let request_id = format!(
+            "{}-{}-{}-{}-{}",
+            &hex[0..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..32],
+        ); // format!
+
+        span.record("request_id", request_id.as_str());
+
+        Some(request_id)
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds an HTTP GET request for the specified URL using the client's
+    /// `reqwest` client. Any headers added using `with_header`, and any timeout
+    /// set using `with_timeout`, are applied to the request. If a request ID is
+    /// provided, and the client has been configured with
+    /// `with_request_id_header`, the ID is added to the request's headers.
+
+    pub(crate) fn get_request(
+        &self,
+        url: &str,
+        request_id: Option<&str>,
+    ) -> Result<reqwest::Request, reqwest::Error> {
+
+        let mut request = self.reqwest_client
+            .get(url)
+            .headers(self.headers.clone());
+
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        } // if
+
+        if let (Some(header_name), Some(request_id)) = (&self.request_id_header, request_id) {
+            request = request.header(header_name, request_id);
+        } // if
+
+        request.build()
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::GoogleMapsClient;
+    use crate::client::test_server::Spans;
+    use reqwest::header::HeaderName;
+
+    /// Generates a request ID inside a span that declares a `request_id` field,
+    /// and builds a request with it. Returns the request and the span's
+    /// `request_id` field, if it was recorded.
+    fn build_request(client: &GoogleMapsClient) -> (reqwest::Request, Option<String>) {
+        let subscriber = Spans::default();
+        let spans = subscriber.0.clone();
+        let request = tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("get", request_id = tracing::field::Empty);
+            let request_id = client.new_request_id(&span);
+            client.get_request("https://example.com/json?key=KEY", request_id.as_deref()).unwrap()
+        }); // with_default
+        let span_field = spans.lock().unwrap()[0].get("request_id").cloned();
+        (request, span_field)
+    } // fn
+
+    #[test]
+    fn request_id_header_matches_the_span_field() {
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client.with_request_id_header(HeaderName::from_static("x-request-id"));
+        let client = client.build();
+
+        let (request, span_field) = build_request(&client);
+        let header = request.headers()["x-request-id"].to_str().unwrap();
+        assert_eq!(span_field.as_deref(), Some(header));
+
+        // The ID is a version 4 UUID, such as
+        // `0b6b5f44-6f4b-4a4e-9a5d-3c3f0e1e2d4c`:
+        let groups: Vec<&str> = header.split('-').collect();
+        assert_eq!(groups.iter().map(|group| group.len()).collect::<Vec<usize>>(), [8, 4, 4, 4, 12]);
+        assert!(header.chars().all(|character| character == '-' || character.is_ascii_hexdigit()));
+        assert!(groups[2].starts_with('4'), "{header} is not a version 4 UUID");
+        assert!(groups[3].starts_with(['8', '9', 'a', 'b']), "{header} has the wrong variant");
+
+        // Every request gets a new ID:
+        let (_request, second_span_field) = build_request(&client);
+        assert_ne!(span_field, second_span_field);
+    } // fn
+
+    #[test]
+    fn no_request_id_by_default() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE").build();
+        let (request, span_field) = build_request(&client);
+        assert!(request.headers().get("x-request-id").is_none());
+        assert_eq!(span_field, None);
+    } // fn
+
+    #[cfg(feature = "geocoding")]
+    #[test]
+    fn request_id_header_is_sent() {
+        use crate::client::test_server::{block_on, TestServer};
+        use crate::client::RetryPolicy;
+        use crate::request_rate::api::Api;
+
+        let server = TestServer::start(200, &[], r#"{"results":[],"status":"ZERO_RESULTS"}"#);
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_endpoint_override(Api::Geocoding, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none())
+            .with_request_id_header(HeaderName::from_static("x-request-id"));
+        let client = client.build();
+        let _ = block_on(client.geocoding().with_address("Ottawa").execute());
+
+        let headers = server.header("x-request-id");
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers[0].as_ref().map(String::len), Some(36), "{headers:?}");
+    } // fn
+
+} // mod
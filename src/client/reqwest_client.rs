@@ -0,0 +1,114 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the default `reqwest` client for the Google Maps client using the
+    /// specified connection settings.
+
+    pub(crate) fn build_reqwest_client(
+        http2_prior_knowledge: bool,
+        tcp_nodelay: bool,
+        connect_timeout: Option<std::time::Duration>,
+    ) -> reqwest::Client {
+
+        let mut builder = reqwest::Client::builder()
+            .user_agent(format!("RustGoogleMaps/{version}", version=env!("CARGO_PKG_VERSION")))
+            .tcp_nodelay(tcp_nodelay);
+
+        if http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        } // if
+
+        if let Some(connect_timeout) = connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        } // if
+
+        builder
+            .build()
+            .unwrap()
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Rebuilds the client's `reqwest` client after one of its connection
+    /// settings has changed. If a custom `reqwest` client has been passed using
+    /// `with_reqwest_client`, it is kept, and a warning is logged instead.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `setter` ‧ The name of the method that changed the setting, for the
+    /// warning.
+
+    pub(crate) fn rebuild_reqwest_client(&mut self, setter: &str) {
+        if self.custom_reqwest_client {
+            tracing::warn!(
+                "`{setter}` does not apply to the `reqwest` client passed using \
+                `with_reqwest_client`. Configure the setting on that client instead"
+            ); // warn!
+        } else {
+            self.reqwest_client = GoogleMapsClient::build_reqwest_client(
+                self.http2_prior_knowledge,
+                self.tcp_nodelay,
+                self.connect_timeout,
+            ); // build_reqwest_client
+        } // if
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::GoogleMapsClient;
+
+    /// Returns a custom `reqwest` client that can be recognized by its user
+    /// agent.
+    fn custom_reqwest_client() -> reqwest::Client {
+        reqwest::Client::builder()
+            .user_agent("custom-agent")
+            .build()
+            .unwrap()
+    } // fn
+
+    #[test]
+    fn connection_settings_keep_custom_client() {
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_reqwest_client(custom_reqwest_client())
+            .with_http2_prior_knowledge(true)
+            .with_tcp_nodelay(false)
+            .with_connect_timeout(std::time::Duration::from_secs(3));
+        assert!(format!("{:?}", client.reqwest_client).contains("custom-agent"));
+        assert!(client.http2_prior_knowledge);
+        assert!(!client.tcp_nodelay);
+        assert_eq!(client.connect_timeout, Some(std::time::Duration::from_secs(3)));
+    } // fn
+
+    #[test]
+    fn custom_client_replaces_built_client() {
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_connect_timeout(std::time::Duration::from_secs(3))
+            .with_reqwest_client(custom_reqwest_client());
+        assert!(format!("{:?}", client.reqwest_client).contains("custom-agent"));
+    } // fn
+
+    #[test]
+    fn connection_settings_rebuild_built_client() {
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_http2_prior_knowledge(true)
+            .with_tcp_nodelay(false)
+            .with_connect_timeout(std::time::Duration::from_secs(3));
+        assert!(!client.custom_reqwest_client);
+        assert!(format!("{:?}", client.reqwest_client).contains("RustGoogleMaps"));
+    } // fn
+
+} // mod
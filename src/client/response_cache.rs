@@ -0,0 +1,170 @@
+//! Contains the `ResponseCache` trait, which lets the Google Maps client cache
+//! successful responses in any storage backend.
+
+use futures::future::BoxFuture;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// A storage backend for caching successful responses from the Google Maps
+/// Platform.
+///
+/// The client stores the raw response body, exactly as returned by Google, so
+/// deserialization remains the responsibility of this crate. Keys are request
+/// URLs with the API key removed. Only responses with an `OK` status are
+/// stored. Errors are never cached.
+///
+/// `MemoryCache`, an in-memory LRU cache, is provided. Implement this trait to
+/// share a cache between several instances of your application, for example
+/// by using Redis. Errors from the backend should be logged and treated as a
+/// cache miss, so that a failing cache never causes a request to fail.
+///
+/// Set using the `GoogleMapsClient::with_cache_backend` method.
+///
+/// ## Example:
+///
+/// ```rust
+/// #[derive(Debug)]
+/// struct RedisCache { /* ... */ }
+///
+/// impl ResponseCache for RedisCache {
+///     fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>> {
+///         Box::pin(async move { /* GET key */ })
+///     }
+///     fn put<'a>(&'a self, key: &'a str, value: String, ttl: Duration) -> BoxFuture<'a, ()> {
+///         Box::pin(async move { /* SET key value EX ttl */ })
+///     }
+/// }
+/// ```
+
+pub trait ResponseCache: std::fmt::Debug + Send + Sync {
+
+    /// Returns the cached response body for the specified key, or `None` if
+    /// it is not cached or has expired.
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>>;
+
+    /// Stores a response body under the specified key. The response should
+    /// expire once `ttl` has elapsed.
+    fn put<'a>(&'a self, key: &'a str, value: String, ttl: Duration) -> BoxFuture<'a, ()>;
+
+} // trait
+
+// -----------------------------------------------------------------------------
+
+/// Query parameters that identify or authenticate the caller, rather than
+/// describe the request. They are removed from cache keys.
+const CREDENTIAL_PARAMETERS: [&str; 2] = ["key=", "signature="];
+
+/// Returns the cache key for a request URL: the URL with its `key` and
+/// `signature` query parameters removed, so that credentials are never stored
+/// in the cache.
+///
+/// Every other parameter of the built query string is kept, in the order that
+/// the request's `build` method wrote it. Requests that differ in any parameter
+/// that changes the response, such as the language, region, units, travel mode
+/// or traffic model, therefore never share a cache entry.
+
+pub(crate) fn cache_key(url: &str) -> String {
+    match url.split_once('?') {
+        Some((path, query)) => {
+            let query = query
+                .split('&')
+                .filter(|parameter| !CREDENTIAL_PARAMETERS
+                    .iter()
+                    .any(|credential| parameter.starts_with(credential)))
+                .collect::<Vec<&str>>()
+                .join("&");
+            format!("{path}?{query}")
+        }, // Some
+        None => url.to_string(),
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn credentials_are_removed() {
+        assert_eq!(
+            cache_key("https://example.com/json?address=Ottawa&key=SECRET&signature=abc%3D"),
+            "https://example.com/json?address=Ottawa",
+        ); // assert_eq!
+        assert_eq!(cache_key("https://example.com/json"), "https://example.com/json");
+    } // fn
+
+    #[test]
+    fn parameters_resembling_credentials_are_kept() {
+        // Only the `key` and `signature` parameters themselves are removed,
+        // not parameters whose names or values contain them:
+        assert_eq!(
+            cache_key("https://example.com/json?keyword=key%3D1&key=SECRET&monkey=signature%3D"),
+            "https://example.com/json?keyword=key%3D1&monkey=signature%3D",
+        ); // assert_eq!
+    } // fn
+
+    #[cfg(feature = "geocoding")]
+    #[test]
+    fn requests_differing_only_in_language_do_not_collide() {
+        use crate::client::GoogleMapsClient;
+        use crate::types::Language;
+
+        let client = GoogleMapsClient::new("SECRET_API_KEY");
+        let url = |language: Language| client
+            .geocoding()
+            .with_address("Ottawa")
+            .with_language(language)
+            .query_url()
+            .unwrap();
+
+        let english = cache_key(&url(Language::English));
+        let french = cache_key(&url(Language::French));
+        assert_ne!(english, french);
+        assert!(!english.contains("SECRET_API_KEY"));
+        assert!(!french.contains("SECRET_API_KEY"));
+
+        // The same request with a different API key shares a cache entry:
+        let rotated = client.clone_with_key("OTHER_API_KEY");
+        let url = rotated
+            .geocoding()
+            .with_address("Ottawa")
+            .with_language(Language::English)
+            .query_url()
+            .unwrap();
+        assert_eq!(cache_key(&url), english);
+    } // fn
+
+    #[cfg(feature = "geocoding")]
+    #[test]
+    fn cached_response_is_not_returned_for_another_language() {
+        use crate::client::test_server::{block_on, TestServer};
+        use crate::client::{GoogleMapsClient, RetryPolicy};
+        use crate::request_rate::api::Api;
+        use crate::types::Language;
+
+        let body = r#"{"results":[],"status":"OK"}"#;
+        let server = TestServer::start(200, &[], body);
+        let mut client = GoogleMapsClient::new("SECRET_API_KEY");
+        client
+            .with_endpoint_override(Api::Geocoding, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none())
+            .with_cache(100, Duration::from_secs(60));
+        let client = client.build();
+
+        for language in [Language::English, Language::French, Language::English] {
+            block_on(client.geocoding().with_address("Ottawa").with_language(language).execute())
+                .unwrap();
+        } // for
+
+        // The French request is sent despite the cached English response, and
+        // the repeated English request is answered from the cache:
+        let targets = server.targets();
+        assert_eq!(targets.len(), 2, "{targets:?}");
+        assert!(targets[0].contains("language=en"), "{}", targets[0]);
+        assert!(targets[1].contains("language=fr"), "{}", targets[1]);
+    } // fn
+
+} // mod
@@ -0,0 +1,249 @@
+//! Contains the `RetryPolicy` struct, which controls how the Google Maps client
+//! retries failed requests.
+
+use backoff::{backoff::Backoff, ExponentialBackoff, ExponentialBackoffBuilder};
+use std::sync::Mutex;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Controls how failed requests are retried. Requests that fail with an error
+/// that is eligible for retries (for example, a connection error or an HTTP
+/// `503 Service Unavailable` response) are retried with an exponential
+/// back-off: the delay between attempts starts at `initial_interval` and is
+/// multiplied by `multiplier` after each attempt.
+///
+/// The default policy matches the `backoff` crate's defaults: an initial
+/// interval of 500 milliseconds, a multiplier of 1.5, and a maximum elapsed
+/// time of 15 minutes.
+///
+/// Use `RetryPolicy::none()` to disable retries.
+///
+/// If the server sends a `Retry-After` header, the requested delay is used in
+/// place of the policy's delay. It is capped at the back-off's maximum interval
+/// and the time remaining before `max_elapsed_time`. The header is ignored once
+/// the policy has given up.
+///
+/// ```rust
+/// let retry_policy = RetryPolicy {
+///     max_elapsed_time: Some(std::time::Duration::from_secs(30)),
+///     ..RetryPolicy::default()
+/// };
+/// ```
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+
+    /// The maximum amount of time spent retrying a request, measured from the
+    /// first attempt. Once this time has elapsed, the last error is returned
+    /// to the caller. `None` means that requests are retried indefinitely.
+    pub max_elapsed_time: Option<Duration>,
+
+    /// The delay before the first retry.
+    pub initial_interval: Duration,
+
+    /// The factor that the delay is multiplied by after each retry.
+    pub multiplier: f64,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl RetryPolicy {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a policy that disables retries. The first error is returned to
+    /// the caller immediately. Useful for latency-sensitive code that would
+    /// rather fail fast.
+
+    #[must_use]
+    pub fn none() -> RetryPolicy {
+        RetryPolicy {
+            max_elapsed_time: Some(Duration::ZERO),
+            ..RetryPolicy::default()
+        } // RetryPolicy
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the back-off for this policy. A new back-off is built for every
+    /// request.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `retry_after` ‧ Where the request records the delay that the server
+    /// asked for in a `Retry-After` header. The delay is only honoured if the
+    /// policy allows another attempt, and is capped at the policy's limits.
+
+    pub(crate) fn to_backoff<'a>(&self, retry_after: &'a RetryAfter) -> PolicyBackoff<'a> {
+        PolicyBackoff {
+            backoff: ExponentialBackoffBuilder::new()
+                .with_max_elapsed_time(self.max_elapsed_time)
+                .with_initial_interval(self.initial_interval)
+                .with_multiplier(self.multiplier)
+                .build(),
+            retry_after,
+        } // PolicyBackoff
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for RetryPolicy {
+    /// Returns the `backoff` crate's default exponential back-off settings.
+    fn default() -> Self {
+        RetryPolicy {
+            max_elapsed_time: Some(Duration::from_millis(
+                backoff::default::MAX_ELAPSED_TIME_MILLIS
+            )),
+            initial_interval: Duration::from_millis(
+                backoff::default::INITIAL_INTERVAL_MILLIS
+            ),
+            multiplier: backoff::default::MULTIPLIER,
+        } // RetryPolicy
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The delay that the server asked for in the `Retry-After` header of the most
+/// recent failed attempt, if any.
+
+#[derive(Debug, Default)]
+pub(crate) struct RetryAfter(Mutex<Option<Duration>>);
+
+impl RetryAfter {
+
+    /// Records the delay that the server asked for.
+
+    pub(crate) fn set(&self, delay: Duration) {
+        *self.0.lock().unwrap_or_else(|error| error.into_inner()) = Some(delay);
+    } // fn
+
+    /// Returns and clears the delay that the server asked for.
+
+    fn take(&self) -> Option<Duration> {
+        self.0.lock().unwrap_or_else(|error| error.into_inner()).take()
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A `RetryPolicy`'s exponential back-off, which also honours the server's
+/// `Retry-After` header.
+///
+/// The policy is always asked first, so that a policy that has given up (for
+/// example `RetryPolicy::none()`, or once `max_elapsed_time` has passed) is
+/// never overridden by the server. If the policy allows another attempt, the
+/// server's delay is used instead of the policy's, capped at the policy's
+/// maximum interval and the time remaining before `max_elapsed_time`.
+
+#[derive(Debug)]
+pub(crate) struct PolicyBackoff<'a> {
+
+    /// The policy's exponential back-off.
+    backoff: ExponentialBackoff,
+
+    /// The delay that the server asked for, if any.
+    retry_after: &'a RetryAfter,
+
+} // struct
+
+impl Backoff for PolicyBackoff<'_> {
+
+    /// Resets the policy's exponential back-off.
+    fn reset(&mut self) {
+        self.backoff.reset();
+    } // fn
+
+    /// Returns the delay before the next attempt, or `None` to stop retrying.
+    fn next_backoff(&mut self) -> Option<Duration> {
+        let retry_after = self.retry_after.take();
+        let delay = self.backoff.next_backoff()?;
+        match retry_after {
+            Some(retry_after) => {
+                let remaining = self.backoff.max_elapsed_time.map_or(
+                    Duration::MAX,
+                    |max_elapsed_time| max_elapsed_time.saturating_sub(self.backoff.get_elapsed_time()),
+                ); // map_or
+                Some(retry_after.min(self.backoff.max_interval).min(remaining))
+            }, // Some
+            None => Some(delay),
+        } // match
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn none_gives_up_despite_retry_after() {
+        let retry_after = RetryAfter::default();
+        let mut backoff = RetryPolicy::none().to_backoff(&retry_after);
+        retry_after.set(Duration::from_secs(1));
+        assert_eq!(backoff.next_backoff(), None);
+    } // fn
+
+    #[test]
+    fn retry_after_replaces_policy_delay() {
+        let retry_after = RetryAfter::default();
+        let mut backoff = RetryPolicy::default().to_backoff(&retry_after);
+        retry_after.set(Duration::from_secs(3));
+        assert_eq!(backoff.next_backoff(), Some(Duration::from_secs(3)));
+        // The server's delay is only used once:
+        assert!(backoff.next_backoff().unwrap() < Duration::from_secs(3));
+    } // fn
+
+    #[test]
+    fn retry_after_is_capped_at_max_interval() {
+        let retry_after = RetryAfter::default();
+        let mut backoff = RetryPolicy {
+            max_elapsed_time: None,
+            ..RetryPolicy::default()
+        }.to_backoff(&retry_after);
+        retry_after.set(Duration::from_secs(24 * 60 * 60));
+        assert_eq!(
+            backoff.next_backoff(),
+            Some(Duration::from_millis(backoff::default::MAX_INTERVAL_MILLIS)),
+        );
+    } // fn
+
+    #[test]
+    fn retry_after_is_capped_at_max_elapsed_time() {
+        let retry_after = RetryAfter::default();
+        let mut backoff = RetryPolicy {
+            max_elapsed_time: Some(Duration::from_secs(10)),
+            ..RetryPolicy::default()
+        }.to_backoff(&retry_after);
+        retry_after.set(Duration::from_secs(30));
+        assert!(backoff.next_backoff().unwrap() <= Duration::from_secs(10));
+    } // fn
+
+    #[cfg(feature = "geocoding")]
+    #[test]
+    fn none_makes_exactly_one_attempt() {
+        use crate::client::test_server::{block_on, TestServer};
+        use crate::client::GoogleMapsClient;
+        use crate::request_rate::api::Api;
+
+        let server = TestServer::start(429, &[("Retry-After", "1")], "{}");
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_endpoint_override(Api::Geocoding, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none());
+        let client = client.build();
+
+        let result = block_on(client.geocoding().with_address("Ottawa").execute());
+        assert!(result.is_err());
+        assert_eq!(server.targets().len(), 1);
+    } // fn
+
+} // mod
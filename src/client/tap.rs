@@ -0,0 +1,160 @@
+//! Contains the `TapEvent` struct, which is passed to the callback set using
+//! `GoogleMapsClient::with_tap`.
+
+use crate::client::{replace_key, GoogleMapsClient};
+use reqwest::StatusCode;
+
+// -----------------------------------------------------------------------------
+
+/// The value that the API key is replaced with in a `TapEvent`'s URL.
+
+const REDACTED_KEY: &str = "REDACTED";
+
+// -----------------------------------------------------------------------------
+//
+/// A callback that sees each HTTP request attempt. Set using the
+/// `GoogleMapsClient::with_tap` method.
+
+pub(crate) type Tap = std::sync::Arc<dyn Fn(&TapEvent) + Send + Sync>;
+
+// -----------------------------------------------------------------------------
+//
+/// Describes a single HTTP request attempt and the server's raw response,
+/// before the response is deserialized. An event is passed to the client's tap
+/// for every attempt, including attempts that are retried.
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TapEvent<'a> {
+
+    /// The URL that the request was sent to. The value of the `key` parameter
+    /// is replaced with `REDACTED`, so that the API key is not leaked.
+    pub url: &'a str,
+
+    /// The HTTP status code of the response. This is `None` if the client did
+    /// not get a response from the server, for example because of a connection
+    /// error or a timeout.
+    pub status: Option<StatusCode>,
+
+    /// The body of the response, whatever its status. This is `None` if the
+    /// client did not get a response from the server, or if reading the body
+    /// failed.
+    pub body: Option<&'a str>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the URL to report to the tap, with the API key redacted. Returns
+    /// `None` if the client has no tap, so that the URL is only rewritten when
+    /// it will be used.
+
+    pub(crate) fn tap_url(&self, url: &str) -> Option<String> {
+        self.tap.as_ref().map(|_tap| replace_key(url, REDACTED_KEY))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Passes a request attempt to the client's tap, if it has one.
+
+    pub(crate) fn tap(
+        &self,
+        url: Option<&str>,
+        status: Option<StatusCode>,
+        body: Option<&str>,
+    ) {
+        if let (Some(tap), Some(url)) = (&self.tap, url) {
+            tap(&TapEvent { url, status, body });
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Passes an unsuccessful response to the client's tap, if it has one. The
+    /// response's body is only read if there is a tap to show it to, since the
+    /// caller does not use the body of an unsuccessful response.
+
+    pub(crate) async fn tap_error_response(
+        &self,
+        url: Option<&str>,
+        status: StatusCode,
+        response: reqwest::Response,
+    ) {
+        if self.tap.is_some() && url.is_some() {
+            let text = response.text().await;
+            self.tap(url, Some(status), text.as_deref().ok());
+        } // if
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "geocoding"))]
+mod tests {
+
+    use crate::client::test_server::{block_on, TestServer};
+    use crate::client::{GoogleMapsClient, RetryPolicy};
+    use crate::request_rate::api::Api;
+    use std::sync::{Arc, Mutex};
+
+    /// A tap event, copied out of the callback.
+    type Event = (String, Option<u16>, Option<String>);
+
+    /// Sends a geocoding request to a server that answers with the specified
+    /// status and body, and returns the events that were passed to the tap.
+    fn tap_events(status: u16, body: &str) -> Vec<Event> {
+        let server = TestServer::start(status, &[], body);
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded_events = events.clone();
+        let mut client = GoogleMapsClient::new("SECRET_API_KEY");
+        client
+            .with_endpoint_override(Api::Geocoding, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none())
+            .with_tap(move |event| recorded_events.lock().unwrap().push((
+                event.url.to_string(),
+                event.status.map(|status| status.as_u16()),
+                event.body.map(str::to_string),
+            ))); // with_tap
+        let client = client.build();
+
+        let _ = block_on(client.geocoding().with_address("Ottawa").execute());
+        let events = events.lock().unwrap().clone();
+        events
+    } // fn
+
+    #[test]
+    fn successful_response_is_tapped_with_a_redacted_url() {
+        let body = r#"{"results":[],"status":"ZERO_RESULTS"}"#;
+        let events = tap_events(200, body);
+        assert_eq!(events.len(), 1);
+        let (url, status, tapped_body) = &events[0];
+        assert!(url.contains("key=REDACTED"), "`{url}` is not redacted");
+        assert!(!url.contains("SECRET_API_KEY"), "`{url}` leaks the API key");
+        assert_eq!(*status, Some(200));
+        assert_eq!(tapped_body.as_deref(), Some(body));
+    } // fn
+
+    #[test]
+    fn client_error_response_body_is_tapped() {
+        let body = r#"{"error_message":"The provided API key is invalid."}"#;
+        let events = tap_events(403, body);
+        assert_eq!(events.len(), 1);
+        assert!(events[0].0.contains("key=REDACTED"));
+        assert_eq!(events[0].1, Some(403));
+        assert_eq!(events[0].2.as_deref(), Some(body));
+    } // fn
+
+    #[test]
+    fn server_error_response_body_is_tapped() {
+        let events = tap_events(503, "Service Unavailable");
+        assert_eq!(events.len(), 1);
+        assert!(events[0].0.contains("key=REDACTED"));
+        assert_eq!(events[0].1, Some(503));
+        assert_eq!(events[0].2.as_deref(), Some("Service Unavailable"));
+    } // fn
+
+} // mod
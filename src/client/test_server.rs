@@ -0,0 +1,177 @@
+//! Contains `TestServer`, a minimal HTTP server that the client's unit tests
+//! send their requests to, and helpers for running the client in tests.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use tracing::{field::{Field, Visit}, span, Event, Metadata, Subscriber};
+
+// -----------------------------------------------------------------------------
+
+/// A request received by a `TestServer`: its target, and its headers as
+/// lower-case name and value pairs.
+
+type ReceivedRequest = (String, Vec<(String, String)>);
+
+/// A canned response for a `TestServer` to send: its HTTP status, its extra
+/// headers as name and value pairs, and its body.
+
+type CannedResponse<'a> = (u16, &'a [(&'a str, &'a str)], &'a str);
+
+// -----------------------------------------------------------------------------
+//
+/// An HTTP server on a local port that answers requests with canned
+/// responses, and records the target (path and query string) and
+/// headers of each request that it receives.
+
+#[derive(Debug)]
+pub(crate) struct TestServer {
+
+    /// The server's base URL, for example `http://127.0.0.1:12345`.
+    pub(crate) url: String,
+
+    /// The target and headers of each request received, in order.
+    requests: Arc<Mutex<Vec<ReceivedRequest>>>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl TestServer {
+
+    /// Starts a server that answers every request with the specified HTTP
+    /// status, extra headers, and body.
+
+    pub(crate) fn start(status: u16, headers: &[(&str, &str)], body: &str) -> TestServer {
+        TestServer::start_sequence(&[(status, headers, body)])
+    } // fn
+
+    /// Starts a server that answers each request with the next of the
+    /// specified HTTP statuses, extra headers, and bodies. Once they have all
+    /// been sent, the last one is repeated.
+
+    pub(crate) fn start_sequence(responses: &[CannedResponse]) -> TestServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let responses: Vec<String> = responses
+            .iter()
+            .map(|(status, headers, body)| {
+                let mut response = format!(
+                    "HTTP/1.1 {status} Test\r\nContent-Type: application/json\r\n\
+                    Content-Length: {}\r\nConnection: close\r\n",
+                    body.len(),
+                ); // format!
+                for (name, value) in *headers {
+                    response.push_str(&format!("{name}: {value}\r\n"));
+                } // for
+                response.push_str("\r\n");
+                response.push_str(body);
+                response
+            }) // map
+            .collect();
+
+        let recorded_requests = requests.clone();
+        std::thread::spawn(move || {
+            for (index, stream) in listener.incoming().enumerate() {
+                let Ok(mut stream) = stream else { continue };
+                let mut reader = BufReader::new(&mut stream);
+                let mut request_line = String::new();
+                if reader.read_line(&mut request_line).is_err() { continue }
+                // Read the rest of the request's headers. The client only
+                // sends `GET` requests, so there is no body:
+                let mut headers = Vec::new();
+                let mut line = String::new();
+                while reader.read_line(&mut line).is_ok() && line != "\r\n" && !line.is_empty() {
+                    if let Some((name, value)) = line.split_once(':') {
+                        headers.push((name.to_ascii_lowercase(), value.trim().to_string()));
+                    } // if
+                    line.clear();
+                } // while
+                if let Some(target) = request_line.split_whitespace().nth(1) {
+                    recorded_requests.lock().unwrap().push((target.to_string(), headers));
+                } // if
+                let response = &responses[index.min(responses.len() - 1)];
+                let _ = stream.write_all(response.as_bytes());
+            } // for
+        }); // spawn
+
+        TestServer { url, requests }
+    } // fn
+
+    /// Returns the target of each request received so far, in order.
+
+    pub(crate) fn targets(&self) -> Vec<String> {
+        self.requests.lock().unwrap().iter().map(|(target, _headers)| target.clone()).collect()
+    } // fn
+
+    /// Returns the value of the named header of each request received so far,
+    /// in order. Header names are compared case-insensitively.
+
+    pub(crate) fn header(&self, name: &str) -> Vec<Option<String>> {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_target, headers)| headers
+                .iter()
+                .find(|(header_name, _value)| header_name.eq_ignore_ascii_case(name))
+                .map(|(_name, value)| value.clone()))
+            .collect()
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Runs a future to completion on a new single-threaded `tokio` runtime.
+
+pub(crate) fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(future)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// A `tracing` subscriber that records the fields of each span, in the order
+/// that the spans were created.
+
+#[derive(Default)]
+pub(crate) struct Spans(pub(crate) Arc<Mutex<Vec<HashMap<String, String>>>>);
+
+/// Records visited fields into a map.
+
+struct Fields<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for Fields<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    } // fn
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(field.name().to_string(), format!("{value:?}"));
+    } // fn
+} // impl
+
+impl Subscriber for Spans {
+    fn enabled(&self, _metadata: &Metadata) -> bool { true }
+    fn new_span(&self, attributes: &span::Attributes) -> span::Id {
+        let mut spans = self.0.lock().unwrap();
+        let mut fields = HashMap::new();
+        attributes.record(&mut Fields(&mut fields));
+        spans.push(fields);
+        span::Id::from_u64(spans.len() as u64)
+    } // fn
+    fn record(&self, id: &span::Id, values: &span::Record) {
+        let index = usize::try_from(id.into_u64()).unwrap() - 1;
+        values.record(&mut Fields(&mut self.0.lock().unwrap()[index]));
+    } // fn
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+    fn event(&self, _event: &Event) {}
+    fn enter(&self, _span: &span::Id) {}
+    fn exit(&self, _span: &span::Id) {}
+} // impl
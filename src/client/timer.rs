@@ -0,0 +1,73 @@
+//! Contains the `Timer` trait, which lets the Google Maps client wait without
+//! depending on a specific async runtime, and its `TokioTimer` implementation.
+
+use futures::future::BoxFuture;
+use std::sync::Arc;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Waits for a duration. The client uses its timer to wait between retry
+/// attempts, and between the pages of a streamed Text Search.
+///
+/// `TokioTimer`, the default, uses `tokio::time::sleep`. Implement this trait
+/// to use another runtime's timer, for example `async_std::task::sleep`.
+///
+/// Note that the HTTP requests themselves are sent by `reqwest`, and rate
+/// limiting is performed by `stream_throttle`. Both use `tokio` internally.
+/// Under other runtimes, a compatibility layer such as
+/// [async-compat](https://crates.io/crates/async-compat) is needed for them.
+///
+/// Set using the `GoogleMapsClient::with_timer` method.
+///
+/// ## Example:
+///
+/// ```rust
+/// #[derive(Debug)]
+/// struct AsyncStdTimer;
+///
+/// impl Timer for AsyncStdTimer {
+///     fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+///         Box::pin(async_std::task::sleep(duration))
+///     }
+/// }
+/// ```
+
+pub trait Timer: std::fmt::Debug + Send + Sync {
+
+    /// Returns a future that completes once the duration has elapsed.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+
+} // trait
+
+// -----------------------------------------------------------------------------
+//
+/// A `Timer` that uses `tokio::time::sleep`. This is the default timer.
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioTimer;
+
+// -----------------------------------------------------------------------------
+
+impl Timer for TokioTimer {
+    /// Waits using `tokio::time::sleep`.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Adapts the client's `Timer` to the `backoff` crate's `Sleeper` trait, so
+/// that retries wait using the client's timer.
+
+pub(crate) struct TimerSleeper(pub(crate) Arc<dyn Timer>);
+
+// -----------------------------------------------------------------------------
+
+impl backoff::future::Sleeper for TimerSleeper {
+    type Sleep = BoxFuture<'static, ()>;
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        self.0.sleep(duration)
+    } // fn
+} // impl
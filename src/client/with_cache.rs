@@ -0,0 +1,86 @@
+use crate::client::{GoogleMapsClient, MemoryCache, ResponseCache};
+use std::sync::Arc;
+use std::time::Duration;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Caches successful responses in memory, so that repeated requests do not
+    /// cost quota.
+    ///
+    /// ## Description:
+    ///
+    /// Responses are cached by their request URL, with the API key removed.
+    /// Before a request is sent, the cache is checked. If an unexpired
+    /// response for the same URL is found, it is returned without contacting
+    /// Google or observing rate limits. Only responses with an `OK` status are
+    /// cached. Errors are never cached.
+    ///
+    /// The cache is shared by every clone of this client. By default, there
+    /// is no cache. To share a cache between several instances of your
+    /// application, see `with_cache_backend`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `capacity` ‧ The maximum number of responses to cache. Once the cache
+    /// is full, the least recently used response is evicted.
+    ///
+    /// * `ttl` ‧ How long each response is cached for.
+    ///
+    /// ## Examples:
+    ///
+    /// * Cache up to 10,000 responses for a day:
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_cache(10_000, std::time::Duration::from_secs(24 * 60 * 60))
+    ///     .build();
+    /// ```
+
+    pub fn with_cache(
+        &mut self,
+        capacity: usize,
+        ttl: Duration,
+    ) -> &mut GoogleMapsClient {
+        self.with_cache_backend(MemoryCache::new(capacity), ttl)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Caches successful responses in the specified storage backend.
+    ///
+    /// ## Description:
+    ///
+    /// This works like `with_cache`, except that any implementation of the
+    /// `ResponseCache` trait may be used to store the responses. For example,
+    /// a thin Redis adapter lets several instances of your application share
+    /// a cache, without this crate depending on Redis.
+    ///
+    /// ## Arguments
+    ///
+    /// * `cache` ‧ The storage backend.
+    ///
+    /// * `ttl` ‧ How long each response is cached for. This is passed to the
+    /// backend's `put` method.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_cache_backend(RedisCache::new(redis_client), std::time::Duration::from_secs(24 * 60 * 60))
+    ///     .build();
+    /// ```
+
+    pub fn with_cache_backend(
+        &mut self,
+        cache: impl ResponseCache + 'static,
+        ttl: Duration,
+    ) -> &mut GoogleMapsClient {
+        self.cache = Some(Arc::new(cache));
+        self.cache_ttl = ttl;
+        self
+    } // fn
+
+} // impl
@@ -0,0 +1,160 @@
+use crate::client::{CircuitBreaker, CircuitState, GoogleMapsClient};
+use crate::request_rate::api::Api;
+use std::time::Duration;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Stops sending requests to an API after too many consecutive failures.
+    ///
+    /// ## Description:
+    ///
+    /// When a Google Maps Platform API is having a systemic problem, such as
+    /// an outage, retrying every request through its full back-off wastes
+    /// time. With a circuit breaker, once `failure_threshold` consecutive
+    /// attempts to an API fail in a way that would normally be retried, the
+    /// request in progress stops retrying and the following requests to that
+    /// API fail immediately with `Error::CircuitOpen`. After `cooldown` has
+    /// elapsed, a single probe request is allowed through. If it succeeds,
+    /// requests are sent normally again.
+    ///
+    /// Each API has its own circuit. The circuits are shared by every clone of
+    /// this client. By default, there is no circuit breaker. See
+    /// `with_circuit_breaker_rate` to open the circuit based on an error rate
+    /// instead.
+    ///
+    /// ## Arguments
+    ///
+    /// * `failure_threshold` ‧ The number of consecutive failures that will
+    /// open the circuit.
+    ///
+    /// * `cooldown` ‧ How long requests are rejected for once the circuit is
+    /// open.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_circuit_breaker(10, std::time::Duration::from_secs(60))
+    ///     .build();
+    /// ```
+
+    pub fn with_circuit_breaker(
+        &mut self,
+        failure_threshold: u32,
+        cooldown: Duration,
+    ) -> &mut GoogleMapsClient {
+        self.circuit_breaker = Some(CircuitBreaker::new(1.0, failure_threshold, cooldown));
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Stops sending requests to an API when too many of its recent requests
+    /// have failed.
+    ///
+    /// ## Description:
+    ///
+    /// This works like `with_circuit_breaker`, except that the circuit opens
+    /// when the proportion of failures among the API's most recent
+    /// `window_size` attempts reaches `failure_rate`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `failure_rate` ‧ The proportion of failed attempts, from `0.0` to
+    /// `1.0`, that will open the circuit.
+    ///
+    /// * `window_size` ‧ The number of most recent attempts that the failure
+    /// rate is calculated from.
+    ///
+    /// * `cooldown` ‧ How long requests are rejected for once the circuit is
+    /// open.
+    ///
+    /// ## Examples:
+    ///
+    /// * Open the circuit when half of the last 20 attempts have failed:
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_circuit_breaker_rate(0.5, 20, std::time::Duration::from_secs(60))
+    ///     .build();
+    /// ```
+
+    pub fn with_circuit_breaker_rate(
+        &mut self,
+        failure_rate: f64,
+        window_size: u32,
+        cooldown: Duration,
+    ) -> &mut GoogleMapsClient {
+        self.circuit_breaker = Some(CircuitBreaker::new(failure_rate, window_size, cooldown));
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the state of the circuit breaker for the specified API, or
+    /// `None` if the client has no circuit breaker.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ The API to inspect. For example, `Api::Directions`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// if google_maps_client.circuit_state(&Api::Directions) == Some(CircuitState::Open) {
+    ///     println!("The Directions API is unavailable");
+    /// }
+    /// ```
+
+    pub fn circuit_state(&self, api: &Api) -> Option<CircuitState> {
+        self.circuit_breaker
+            .as_ref()
+            .map(|circuit_breaker| circuit_breaker.state(api))
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "geocoding"))]
+mod tests {
+
+    use crate::client::test_server::{block_on, TestServer};
+    use crate::client::{CircuitState, GoogleMapsClient, RetryPolicy};
+    use crate::error::Error as GoogleMapsError;
+    use crate::request_rate::api::Api;
+    use std::time::Duration;
+
+    #[test]
+    fn breaker_trips_after_threshold() {
+        let server = TestServer::start(503, &[], "Service Unavailable");
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_endpoint_override(Api::Geocoding, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none())
+            .with_circuit_breaker(3, Duration::from_secs(60));
+        let client = client.build();
+        assert_eq!(client.circuit_state(&Api::Geocoding), Some(CircuitState::Closed));
+
+        // Each failure below the threshold is sent to the server:
+        for attempt in 1..=3 {
+            let result = block_on(client.geocoding().with_address("Ottawa").execute());
+            assert!(result.is_err());
+            assert!(!matches!(result, Err(GoogleMapsError::CircuitOpen { .. })), "{result:?}");
+            assert_eq!(server.targets().len(), attempt);
+        } // for
+        assert_eq!(client.circuit_state(&Api::Geocoding), Some(CircuitState::Open));
+
+        // Once the circuit is open, requests fail without being sent:
+        let result = block_on(client.geocoding().with_address("Ottawa").execute());
+        assert!(matches!(result, Err(GoogleMapsError::CircuitOpen { api: Api::Geocoding })), "{result:?}");
+        assert_eq!(server.targets().len(), 3);
+
+        // Other APIs keep their own circuit:
+        assert_eq!(client.circuit_state(&Api::Directions), Some(CircuitState::Closed));
+    } // fn
+
+} // mod
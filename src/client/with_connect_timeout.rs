@@ -0,0 +1,46 @@
+use crate::client::GoogleMapsClient;
+use std::time::Duration;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the time allowed for connecting to the Google Maps server.
+    ///
+    /// ## Description:
+    ///
+    /// By default, there is no connect timeout.
+    ///
+    /// A connection attempt that times out fails with the API's
+    /// `Error::Timeout` variant, which is eligible for retries.
+    ///
+    /// This setting only applies to the `reqwest` client that the Google Maps
+    /// client builds for itself. If a client has been passed using
+    /// `with_reqwest_client`, it takes precedence: it is kept as it is, and a
+    /// warning is logged. Configure the connect timeout on the custom client
+    /// directly.
+    ///
+    /// ## Arguments
+    ///
+    /// * `connect_timeout` ‧ The time allowed for connecting to the server.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_connect_timeout(std::time::Duration::from_secs(3))
+    ///     .build();
+    /// ```
+
+    pub fn with_connect_timeout(
+        &mut self,
+        connect_timeout: Duration,
+    ) -> &mut GoogleMapsClient {
+        self.connect_timeout = Some(connect_timeout);
+        self.rebuild_reqwest_client("with_connect_timeout");
+        self
+    } // fn
+
+} // impl
@@ -0,0 +1,49 @@
+use crate::client::GoogleMapsClient;
+use crate::types::Language;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the default language for every request made with this client.
+    ///
+    /// ## Description:
+    ///
+    /// Requests to APIs that support a `language` parameter (Directions,
+    /// Distance Matrix, forward and reverse Geocoding, Places, Place
+    /// Autocomplete, Query Autocomplete, and Time Zone) inherit this language
+    /// when they are created. A request's own `with_language` method overrides
+    /// the default.
+    ///
+    /// Google does not document a `language` parameter for the Elevation,
+    /// Roads, and Geolocation APIs, since their responses contain no localized
+    /// text. Their requests have no `with_language` method, and the default is
+    /// not sent to them.
+    ///
+    /// By default, no language is sent and Google attempts to use the
+    /// preferred language as specified in the `Accept-Language` header, or the
+    /// native language of the domain from which the request is sent.
+    ///
+    /// ## Arguments
+    ///
+    /// * `language` ‧ The language in which to return results.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_default_language(Language::French)
+    ///     .build();
+    /// ```
+
+    pub fn with_default_language(
+        &mut self,
+        language: Language,
+    ) -> &mut GoogleMapsClient {
+        self.default_language = Some(language);
+        self
+    } // fn
+
+} // impl
@@ -0,0 +1,45 @@
+use crate::client::GoogleMapsClient;
+use crate::types::Region;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the default region bias for every request made with this client.
+    ///
+    /// ## Description:
+    ///
+    /// Requests to APIs that support a `region` parameter (Directions,
+    /// Distance Matrix, Geocoding, Place Autocomplete, Place Details, and Text
+    /// Search) inherit this region when they are created. A request's own
+    /// `with_region` method overrides the default. By default, no region is
+    /// sent.
+    ///
+    /// The region is sent as a ccTLD ("top-level domain") code. It biases
+    /// results towards the region but does not restrict them to it. For
+    /// example, a geocoding request for "Toledo" returns the city in Spain
+    /// when biased to `Region::Spain`, rather than the city in Ohio.
+    ///
+    /// ## Arguments
+    ///
+    /// * `region` ‧ The region to bias results towards.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_default_region(Region::Canada)
+    ///     .build();
+    /// ```
+
+    pub fn with_default_region(
+        &mut self,
+        region: Region,
+    ) -> &mut GoogleMapsClient {
+        self.default_region = Some(region);
+        self
+    } // fn
+
+} // impl
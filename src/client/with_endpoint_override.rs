@@ -0,0 +1,56 @@
+use crate::client::GoogleMapsClient;
+use crate::request_rate::api::Api;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sends requests for the specified API to a different base URL, for
+    /// example a regional endpoint or a proxy that mirrors the Google Maps
+    /// Platform.
+    ///
+    /// ## Description:
+    ///
+    /// The base URL replaces the scheme and host of Google's URL, which is
+    /// `https://maps.googleapis.com` for most APIs and
+    /// `https://roads.googleapis.com` for the Roads API. The rest of the URL is
+    /// unchanged. For example, with a base URL of
+    /// `https://proxy.example.com/google`, a Directions request is sent to
+    /// `https://proxy.example.com/google/maps/api/directions/json?...`.
+    ///
+    /// An override for `Api::All` applies to every API that does not have an
+    /// override of its own. APIs without an override use Google's URL. This
+    /// may be called several times to override several APIs.
+    ///
+    /// Cached responses are shared between the overridden and default URLs.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ The API whose requests should be sent to the base URL, or
+    /// `Api::All` for every API.
+    ///
+    /// * `base_url` ‧ The scheme, host and optional path prefix to send
+    /// requests to. A trailing slash is ignored.
+    ///
+    /// ## Examples:
+    ///
+    /// * Route only Places API requests through a proxy:
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_endpoint_override(Api::Places, "https://places-proxy.example.com")
+    ///     .build();
+    /// ```
+
+    pub fn with_endpoint_override(
+        &mut self,
+        api: Api,
+        base_url: impl Into<String>,
+    ) -> &mut GoogleMapsClient {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        self.endpoint_overrides.insert(api, base_url);
+        self
+    } // fn
+
+} // impl
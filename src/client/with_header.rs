@@ -0,0 +1,52 @@
+use crate::client::GoogleMapsClient;
+use reqwest::header::{HeaderName, HeaderValue};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds an HTTP header that will be sent with every request made by this
+    /// client. This method may be called several times to add several headers.
+    ///
+    /// ## Description:
+    ///
+    /// If a header with the same name has already been added, its value is
+    /// replaced. Headers added using this method take precedence over the
+    /// `reqwest` client's default headers, so this method may be used to
+    /// override the `User-Agent` header, for example.
+    ///
+    /// Unlike `with_reqwest_client`, this method does not replace the client's
+    /// `reqwest` client, and it works with a custom `reqwest` client too.
+    ///
+    /// ## Arguments
+    ///
+    /// * `name` ‧ The name of the HTTP header.
+    ///
+    /// * `value` ‧ The value of the HTTP header.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// use reqwest::header::{HeaderName, HeaderValue, USER_AGENT};
+    ///
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_header(USER_AGENT, HeaderValue::from_static("my-app/1.0"))
+    ///     .with_header(
+    ///         HeaderName::from_static("x-trace-id"),
+    ///         HeaderValue::from_static("abc123"),
+    ///     )
+    ///     .build();
+    /// ```
+
+    pub fn with_header(
+        &mut self,
+        name: HeaderName,
+        value: HeaderValue,
+    ) -> &mut GoogleMapsClient {
+        self.headers.insert(name, value);
+        self
+    } // fn
+
+} // impl
@@ -0,0 +1,45 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets whether the Google Maps client only uses HTTP/2 when connecting to
+    /// the Google Maps server. HTTP/2 multiplexes many requests over a single
+    /// connection, which can improve throughput for high-volume or bursty
+    /// workloads.
+    ///
+    /// ## Description:
+    ///
+    /// By default, this is `false` and the HTTP version is negotiated with the
+    /// server.
+    ///
+    /// This setting only applies to the `reqwest` client that the Google Maps
+    /// client builds for itself. If a client has been passed using
+    /// `with_reqwest_client`, it takes precedence: it is kept as it is, and a
+    /// warning is logged. Configure HTTP/2 on the custom client directly.
+    ///
+    /// ## Arguments
+    ///
+    /// * `http2_prior_knowledge` ‧ Whether to only use HTTP/2.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_http2_prior_knowledge(true)
+    ///     .build();
+    /// ```
+
+    pub fn with_http2_prior_knowledge(
+        &mut self,
+        http2_prior_knowledge: bool,
+    ) -> &mut GoogleMapsClient {
+        self.http2_prior_knowledge = http2_prior_knowledge;
+        self.rebuild_reqwest_client("with_http2_prior_knowledge");
+        self
+    } // fn
+
+} // impl
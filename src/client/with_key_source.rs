@@ -0,0 +1,45 @@
+use crate::client::{GoogleMapsClient, KeySource};
+use std::sync::Arc;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Chooses the API key for each request using a key source, rather than
+    /// always sending the client's `key`.
+    ///
+    /// ## Description:
+    ///
+    /// Each time a request is sent, the key source is asked for a key for the
+    /// API being called. This may be used to spread requests across several
+    /// keys to pool their quotas, or to use a different key for each API. See
+    /// the `KeySource` trait, and its `RoundRobinKeys` and `EnvKeys`
+    /// implementations.
+    ///
+    /// The key source is shared by every clone of this client. By default,
+    /// there is no key source.
+    ///
+    /// ## Arguments
+    ///
+    /// * `key_source` ‧ Supplies the API key for each request.
+    ///
+    /// ## Examples:
+    ///
+    /// * Use two API keys in turn:
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("")
+    ///     .with_key_source(RoundRobinKeys::new(["FIRST_API_KEY", "SECOND_API_KEY"]))
+    ///     .build();
+    /// ```
+
+    pub fn with_key_source(
+        &mut self,
+        key_source: impl KeySource + 'static,
+    ) -> &mut GoogleMapsClient {
+        self.key_source = Some(Arc::new(key_source));
+        self
+    } // fn
+
+} // impl
@@ -0,0 +1,46 @@
+use crate::client::GoogleMapsClient;
+use reqwest::header::HeaderName;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sends a generated request ID with every request made by this client.
+    /// This allows a single request to be found in your own logs and, if you
+    /// route requests through a proxy, in the proxy's logs.
+    ///
+    /// ## Description:
+    ///
+    /// A new UUID (version 4) is generated for every request and is sent in
+    /// the specified HTTP header. If a request is retried, every attempt
+    /// carries the same ID. The ID is also recorded in the `request_id` field
+    /// of the request's `tracing` span.
+    ///
+    /// By default, no request ID header is sent.
+    ///
+    /// ## Arguments
+    ///
+    /// * `header_name` ‧ The name of the HTTP header that will carry the
+    /// request ID, for example `x-request-id`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// use reqwest::header::HeaderName;
+    ///
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_request_id_header(HeaderName::from_static("x-request-id"))
+    ///     .build();
+    /// ```
+
+    pub fn with_request_id_header(
+        &mut self,
+        header_name: HeaderName,
+    ) -> &mut GoogleMapsClient {
+        self.request_id_header = Some(header_name);
+        self
+    } // fn
+
+} // impl
@@ -13,6 +13,11 @@ impl GoogleMapsClient {
     /// [Mause](https://github.com/Mause) mentioned that this feature could be
     /// useful for writing tests. Thanks for the suggestion!
     ///
+    /// The custom client takes precedence over the connection settings of the
+    /// `with_http2_prior_knowledge`, `with_tcp_nodelay`, and
+    /// `with_connect_timeout` methods, whether they are called before or after
+    /// this method. Those settings must be configured on the custom client.
+    ///
     /// ## Arguments
     ///
     /// * `reqwest_client` ‧ A reqwest client built using the
@@ -35,6 +40,7 @@ impl GoogleMapsClient {
         reqwest_client: reqwest::Client,
     ) -> &mut GoogleMapsClient {
         self.reqwest_client = reqwest_client;
+        self.custom_reqwest_client = true;
         self
     } // fn
 
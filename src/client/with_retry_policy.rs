@@ -0,0 +1,50 @@
+use crate::client::{GoogleMapsClient, RetryPolicy};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the policy used to retry failed requests.
+    ///
+    /// ## Description:
+    ///
+    /// By default, failed requests are retried with the `backoff` crate's
+    /// default exponential back-off, for up to 15 minutes. Use this method to
+    /// cap the total time spent retrying, to change the delays between
+    /// attempts, or to disable retries entirely with `RetryPolicy::none()`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `retry_policy` ‧ The retry policy to use for every request made by
+    /// this client.
+    ///
+    /// ## Examples:
+    ///
+    /// * Give up after 30 seconds:
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_retry_policy(RetryPolicy {
+    ///         max_elapsed_time: Some(std::time::Duration::from_secs(30)),
+    ///         ..RetryPolicy::default()
+    ///     })
+    ///     .build();
+    /// ```
+    ///
+    /// * Fail fast, do not retry:
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_retry_policy(RetryPolicy::none())
+    ///     .build();
+    /// ```
+
+    pub fn with_retry_policy(
+        &mut self,
+        retry_policy: RetryPolicy,
+    ) -> &mut GoogleMapsClient {
+        self.retry_policy = retry_policy;
+        self
+    } // fn
+
+} // impl
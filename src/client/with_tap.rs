@@ -0,0 +1,45 @@
+use crate::client::{GoogleMapsClient, TapEvent};
+use std::sync::Arc;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets a callback that sees every HTTP request attempt and the server's
+    /// raw response before it is deserialized. This is intended for ad-hoc
+    /// debugging and for tests.
+    ///
+    /// ## Description:
+    ///
+    /// The callback is passed a `TapEvent` for each attempt, including attempts
+    /// that are retried, carrying the URL with the API key redacted, the HTTP
+    /// status code, and the response body. Responses returned from the
+    /// client's cache are not sent, and so are not passed to the callback.
+    ///
+    /// The callback is called on the task that is sending the request, and so
+    /// it should return quickly. Only requests sent with HTTP `GET` are
+    /// tapped, which excludes the Geolocation API.
+    ///
+    /// ## Arguments
+    ///
+    /// * `tap` ‧ The callback to pass each request attempt to.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_tap(|event| println!("{} {:?}", event.url, event.status))
+    ///     .build();
+    /// ```
+
+    pub fn with_tap(
+        &mut self,
+        tap: impl Fn(&TapEvent) + Send + Sync + 'static,
+    ) -> &mut GoogleMapsClient {
+        self.tap = Some(Arc::new(tap));
+        self
+    } // fn
+
+} // impl
@@ -0,0 +1,44 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets whether the Google Maps client's sockets have `TCP_NODELAY`
+    /// enabled. When enabled, small requests are sent immediately rather than
+    /// being buffered, which lowers latency.
+    ///
+    /// ## Description:
+    ///
+    /// By default, this is `true`.
+    ///
+    /// This setting only applies to the `reqwest` client that the Google Maps
+    /// client builds for itself. If a client has been passed using
+    /// `with_reqwest_client`, it takes precedence: it is kept as it is, and a
+    /// warning is logged. Configure `TCP_NODELAY` on the custom client
+    /// directly.
+    ///
+    /// ## Arguments
+    ///
+    /// * `tcp_nodelay` ‧ Whether to enable `TCP_NODELAY`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_tcp_nodelay(false)
+    ///     .build();
+    /// ```
+
+    pub fn with_tcp_nodelay(
+        &mut self,
+        tcp_nodelay: bool,
+    ) -> &mut GoogleMapsClient {
+        self.tcp_nodelay = tcp_nodelay;
+        self.rebuild_reqwest_client("with_tcp_nodelay");
+        self
+    } // fn
+
+} // impl
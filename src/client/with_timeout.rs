@@ -0,0 +1,45 @@
+use crate::client::GoogleMapsClient;
+use std::time::Duration;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the total time allowed for each HTTP request, from connecting to
+    /// the server until the response has been read.
+    ///
+    /// ## Description:
+    ///
+    /// By default, there is no timeout.
+    ///
+    /// The timeout applies to each attempt, not to the request as a whole. A
+    /// request that times out fails with the API's `Error::Timeout` variant,
+    /// which is eligible for retries. Use `with_retry_policy` to limit the
+    /// total time spent retrying.
+    ///
+    /// The timeout is applied to every request, so it also works with a custom
+    /// `reqwest` client passed using `with_reqwest_client`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `timeout` ‧ The time allowed for each HTTP request.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_timeout(std::time::Duration::from_secs(10))
+    ///     .build();
+    /// ```
+
+    pub fn with_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> &mut GoogleMapsClient {
+        self.timeout = Some(timeout);
+        self
+    } // fn
+
+} // impl
@@ -0,0 +1,39 @@
+use crate::client::{GoogleMapsClient, Timer};
+use std::sync::Arc;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the timer that the client waits with, so that the client may be
+    /// used with async runtimes other than `tokio`.
+    ///
+    /// ## Description:
+    ///
+    /// The timer is used to wait between retry attempts, and between the pages
+    /// of a streamed Text Search. By default, `TokioTimer` is used. See the
+    /// `Timer` trait for the parts of the client that still depend on `tokio`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `timer` ‧ The timer to wait with.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_timer(AsyncStdTimer)
+    ///     .build();
+    /// ```
+
+    pub fn with_timer(
+        &mut self,
+        timer: impl Timer + 'static,
+    ) -> &mut GoogleMapsClient {
+        self.timer = Arc::new(timer);
+        self
+    } // fn
+
+} // impl
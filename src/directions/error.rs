@@ -15,7 +15,10 @@ use thiserror::Error;
 pub enum Error {
     /// An arrival time may only be specified in Transit travel mode.
     ArrivalTimeIsForTransitOnly(String, String),
-    /// Alternatives may not be requested when waypoints are specified.
+    /// Alternatives may not be requested when waypoints are specified, as
+    /// Google ignores them. This is only returned when strict validation has
+    /// been enabled using `with_strict_validation(true)`, and is otherwise
+    /// logged as a warning.
     EitherAlternativesOrWaypoints(usize),
     /// An departure time may not be specified when an arrival time is
     /// specified.
@@ -83,11 +86,19 @@ pub enum Error {
     /// vehicle type code. See `google_maps\src\directions\vehicle_type.rs` for
     /// more information.
     InvalidVehicleTypeCode(String),
+    /// A route's `waypoint_order` is not a permutation of the indices of the
+    /// supplied waypoints. This indicates an unexpected response from Google.
+    InvalidWaypointOrder(Vec<u8>, usize),
     /// API client library attempted to parse a string that contained an invalid
     /// departure time. See
     /// `google_maps\src\directions\request\departure_time.rs` for more
     /// information.
     InvalidDepartureTime(String),
+    /// Google Maps Directions API server reported that the daily usage limit
+    /// has been exceeded, or that billing is not set up for the API key. This
+    /// error is never retried, as retrying will not help until the quota is
+    /// reset or the billing and quota settings are fixed.
+    OverDailyLimit(Option<String>),
     /// The query string must be built before the request may be sent to the
     /// Google Maps Directions API service.
     QueryNotBuilt,
@@ -102,6 +113,12 @@ pub enum Error {
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
     SerdeJson(serde_json::error::Error),
+    /// The HTTP request to the Google Maps Directions API server timed out. See
+    /// `GoogleMapsClient::with_timeout` and
+    /// `GoogleMapsClient::with_connect_timeout`. This error is eligible for
+    /// retries.
+    #[cfg(feature = "enable-reqwest")]
+    Timeout(reqwest::Error),
     /// Too many waypoints specified.
     TooManyWaypoints(usize),
     /// Transit mode may only be specified in Transit travel mode.
@@ -125,7 +142,7 @@ impl std::fmt::Display for Error {
             Error::EitherAlternativesOrWaypoints(waypoint_count) => write!(f,
                 "Google Maps Directions API client: \
                 The with_alternatives() method cannot be set to `true` if with_waypoints() has been set. \
-                {waypoint_count} waypoint(s) are set. \
+                {waypoint_count} waypoint(s) are set, so Google will not return alternative routes. \
                 Try again either with no waypoints or no alternatives."),
             Error::EitherDepartureTimeOrArrivalTime(arrival_time, departure_time) => write!(f,
                 "Google Maps Directions API client: \
@@ -248,11 +265,24 @@ impl std::fmt::Display for Error {
                 `HIGH_SPEED_TRAIN`, `INTERCITY_BUS`, `LONG_DISTANCE_TRAIN`, \
                 `METRO_RAIL`, `MONORAIL`, `OTHER`, `RAIL`, `SHARE_TAXI`, \
                 `SUBWAY`, `TRAM`, and `TROLLEYBUS`."),
+            Error::InvalidWaypointOrder(waypoint_order, waypoint_count) => write!(f,
+                "Google Maps Directions API client: \
+                The route's waypoint order `{waypoint_order:?}` is not a valid order for {waypoint_count} waypoint(s). \
+                Each waypoint index below {waypoint_count} must appear exactly once."),
             Error::InvalidDepartureTime(invalid_departure_time_string) => write!(f,
                 "Google Maps Directions API client: \
                 `{invalid_departure_time_string}` is not a valid departure time. \
                 Valid departure times are `now`, or a UNIX timestamp."
             ),
+            Error::OverDailyLimit(error_message) => match error_message {
+                Some(error_message) => write!(f, "Google Maps Directions API service: \
+                    Over daily limit. {error_message} \
+                    Check the billing and quota settings for your API key in the Google Cloud Console."),
+                None => write!(f, "Google Maps Directions API service: \
+                    Over daily limit. \
+                    Usage cap has been exceeded, API key is invalid, billing has not been enabled, or method of payment is no longer valid. \
+                    Check the billing and quota settings for your API key in the Google Cloud Console."),
+            }, // match
             Error::QueryNotBuilt => write!(f,
                 "Google Maps Directions API client: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
@@ -266,6 +296,9 @@ impl std::fmt::Display for Error {
             #[cfg(feature = "enable-reqwest")]
             Error::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
             Error::SerdeJson(error) => write!(f, "Google Maps Directions API client in the Serde JSON library: {error}"),
+            #[cfg(feature = "enable-reqwest")]
+            Error::Timeout(error) => write!(f, "Google Maps Directions API client: \
+                The request timed out: {error}"),
             Error::TooManyWaypoints(waypoint_count) => write!(f,
                 "Google Maps Directions API client: \
                 The maximum allowed number of waypoints is 25 plus the origin and destination. \
@@ -310,4 +343,25 @@ impl From<serde_json::error::Error> for Error {
     fn from(error: serde_json::error::Error) -> Error {
         Error::SerdeJson(error)
     } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::client::ApiError for Error {
+    /// Wraps an unsuccessful HTTP status in a Google Maps Directions API
+    /// error type. This function is required by the shared `get` method.
+    fn http_unsuccessful(status: String) -> Error {
+        Error::HttpUnsuccessful(status)
+    } // fn
+    /// Wraps a HTTP client error message in a Google Maps Directions API
+    /// error type. This function is required by the shared `get` method.
+    fn reqwest_message(message: String) -> Error {
+        Error::ReqwestMessage(message)
+    } // fn
+    /// Wraps a HTTP client timeout in a Google Maps Directions API
+    /// error type. This function is required by the shared `get` method.
+    fn timeout(error: reqwest::Error) -> Error {
+        Error::Timeout(error)
+    } // fn
 } // impl
\ No newline at end of file
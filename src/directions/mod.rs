@@ -109,6 +109,7 @@ pub use crate::directions::{
     response::{
         directions_distance::DirectionsDistance,
         directions_duration::DirectionsDuration,
+        distance::Distance,
         driving_maneuver::DrivingManeuver,
         leg::Leg,
         overview_polyline::OverviewPolyline,
@@ -125,6 +126,7 @@ pub use crate::directions::{
         transit_stop::TransitStop,
         transit_time::TransitTime,
         transit_vehicle::TransitVehicle,
+        turn_instruction::TurnInstruction,
     }, // crate::directions::response
     travel_mode::TravelMode,
     vehicle_type::VehicleType,
@@ -1,6 +1,4 @@
-use backoff::Error::{Permanent, Transient};
-use backoff::ExponentialBackoff;
-use backoff::future::retry;
+use crate::client::HasStatus;
 use crate::directions::{
     SERVICE_URL,
     OUTPUT_FORMAT,
@@ -14,6 +12,36 @@ use crate::request_rate::api::Api;
 
 // -----------------------------------------------------------------------------
 
+impl HasStatus for DirectionsResponse {
+
+    type Error = DirectionsError;
+
+    fn error(&self) -> Option<DirectionsError> {
+        if self.status == DirectionsStatus::Ok {
+            None
+        } else if self.status == DirectionsStatus::OverDailyLimit {
+            // The daily quota has been exceeded. This gets its own error so
+            // that callers may detect it and stop sending requests:
+            Some(DirectionsError::OverDailyLimit(self.error_message.to_owned()))
+        } else {
+            Some(DirectionsError::GoogleMapsService(
+                self.status.to_owned(),
+                self.error_message.to_owned(),
+            )) // Some
+        } // if
+    } // fn
+
+    fn is_retryable(&self) -> bool {
+        // Only Google's "Unknown Error" is eligible for retries. Notably,
+        // "Over Daily Limit" is not retried since the quota will not reset
+        // until the next day:
+        self.status == DirectionsStatus::UnknownError
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl<'a> DirectionsRequest<'a> {
 
     /// Performs the HTTP get request and returns the response to the caller.
@@ -22,8 +50,19 @@ impl<'a> DirectionsRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    #[tracing::instrument(level = "debug", name = "Google Maps Directions", skip(self))]
     pub async fn get(&mut self) -> Result<DirectionsResponse, GoogleMapsError> {
+        self.get_with_raw().await.map(|(response, _body)| response)
+    } // fn
+
+    /// Performs the HTTP get request and returns the response, along with the
+    /// raw JSON response body, to the caller.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "debug", name = "Google Maps Directions", skip(self), fields(request_id = tracing::field::Empty, billing_tag = self.billing_tag.as_deref()))]
+    pub(crate) async fn get_with_raw(&mut self) -> Result<(DirectionsResponse, String), GoogleMapsError> {
 
         // Build the URL stem for the HTTP get request:
         let mut url = format!("{SERVICE_URL}/{OUTPUT_FORMAT}?");
@@ -35,107 +74,12 @@ impl<'a> DirectionsRequest<'a> {
             None => return Err(DirectionsError::QueryNotBuilt)?,
         } // match
 
-        // Observe any rate limiting before executing request:
-        self.client.rate_limit.limit_apis(vec![&Api::All, &Api::Directions]).await;
-
         // Emit debug message so client can monitor activity:
         tracing::debug!("Making HTTP GET request to Google Maps Directions API: `{url}`");
 
-        // Retries the get request until successful, an error ineligible for
-        // retries is returned, or we have reached the maximum retries. Note:
-        // errors wrapped in `Transient()` will retried by the `backoff` crate
-        // while errors wrapped in `Permanent()` will exit the retry loop.
-        let response = retry(ExponentialBackoff::default(), || async {
-
-            // Query the Google Cloud Maps Platform using using an HTTP get
-            // request, and return result to caller:
-            let response: Result<reqwest::Response, reqwest::Error> =
-                match self.client.reqwest_client.get(&*url).build() {
-                    Ok(request) => self.client.reqwest_client.execute(request).await,
-                    Err(error) => Err(error),
-                }; // match
-
-            // Check response from the HTTP client:
-            match response {
-                Ok(response) => {
-                    // HTTP client was successful getting a response from the
-                    // server. Check the HTTP status code:
-                    if response.status().is_success() {
-                        // If the HTTP GET request was successful, get the
-                        // response text:
-                        let text = &response.text().await;
-                        match text {
-                            Ok(text) => {
-                                match serde_json::from_str::<DirectionsResponse>(text) {
-                                    Ok(deserialized) => {
-                                        // If the response JSON was successfully
-                                        // parsed, check the Google API status
-                                        // before returning it to the caller:
-                                        if deserialized.status == DirectionsStatus::Ok {
-                                            // If Google's response was "Ok"
-                                            // return the struct deserialized
-                                            // from JSON:
-                                            Ok(deserialized)
-                                        // Google API returned an error. This
-                                        // indicates an issue with the request.
-                                        // In most cases, retrying will not
-                                        // help:
-                                        } else {
-                                            let error = DirectionsError::GoogleMapsService(
-                                                deserialized.status.to_owned(),
-                                                deserialized.error_message,
-                                            );
-                                            // Check Google API response status
-                                            // for error type:
-                                            if deserialized.status == DirectionsStatus::UnknownError {
-                                                // Only Google's "Unknown Error"
-                                                // is eligible for retries:
-                                                tracing::warn!("{}", error);
-                                                Err(Transient { err: error, retry_after: None })
-                                            } else {
-                                                // Not an "Unknown Error." The
-                                                // error is permanent, do not
-                                                // retry:
-                                                tracing::error!("{}", error);
-                                                Err(Permanent(error))
-                                            } // if
-                                        } // if
-                                    }, // Ok(deserialized)
-                                    Err(error) => {
-                                        tracing::error!("JSON parsing error: {}", error);
-                                        Err(Permanent(DirectionsError::SerdeJson(error)))
-                                    }, // Err
-                                } // match
-                            }, // Ok(text)
-                            Err(error) => {
-                                tracing::error!("HTTP client returned: {}", error);
-                                Err(Permanent(DirectionsError::ReqwestMessage(error.to_string())))
-                            }, // Err
-                        } // match
-                    // We got a response from the server but it was not OK.
-                    // Only HTTP "500 Server Errors", and HTTP "429 Too Many
-                    // Requests" are eligible for retries.
-                    } else if response.status().is_server_error() || response.status() == 429 {
-                        tracing::warn!("HTTP client returned: {}", response.status());
-                        Err(Transient { err: DirectionsError::HttpUnsuccessful(response.status().to_string()), retry_after: None })
-                    // Not a 500 Server Error or "429 Too Many Requests" error.
-                    // The error is permanent, do not retry:
-                    } else {
-                        tracing::error!("HTTP client returned: {}", response.status());
-                        Err(Permanent(DirectionsError::HttpUnsuccessful(response.status().to_string())))
-                    } // if
-                } // case
-                // HTTP client did not get a response from the server. Retry:
-                Err(error) => {
-                    tracing::warn!("HTTP client returned: {}", error);
-                    Err(Transient { err: DirectionsError::Reqwest(error), retry_after: None })
-                } // case
-            } // match
-
-        }).await?;
-
-        // Return response to caller:
-        Ok(response)
+        // Observe any rate limiting, execute the request, and check the
+        // response:
+        self.client.get_with_raw(&url, vec![&Api::All, &Api::Directions]).await
 
     } // fn
 
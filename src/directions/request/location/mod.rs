@@ -7,7 +7,7 @@ mod geo_conversions;
 
 // -----------------------------------------------------------------------------
 
-use crate::types::LatLng;
+use crate::types::{LatLng, PlaceId};
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 // -----------------------------------------------------------------------------
@@ -64,7 +64,7 @@ impl std::convert::From<&Location> for String {
 /// and destination.
 
 #[cfg(feature = "geo")]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Location {
     /// If you pass an address, the Directions service geocodes the string and
     /// converts it to a latitude/longitude coordinate to calculate directions.
@@ -127,4 +127,14 @@ impl std::convert::From<&Location> for String {
 
         } // match
     } // fn
-} // impl
\ No newline at end of file
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<PlaceId> for Location {
+    /// Converts a validated `PlaceId` struct into a `Location::PlaceId`
+    /// variant.
+    fn from(place_id: PlaceId) -> Self {
+        Location::PlaceId(place_id.into())
+    } // fn
+} // impl
@@ -4,6 +4,7 @@
 //! for building your Google Maps Platform request.
 
 mod build;
+mod deserialize_with_client;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
 #[cfg(feature = "enable-reqwest")]
@@ -13,10 +14,12 @@ mod query_url;
 mod validate;
 mod with_alternatives;
 mod with_arrival_time;
+mod with_billing_tag;
 mod with_departure_time;
 mod with_language;
 mod with_region;
 mod with_restrictions;
+mod with_strict_validation;
 mod with_traffic_model;
 mod with_transit_modes;
 mod with_transit_route_preference;
@@ -49,14 +52,20 @@ use crate::directions::request::{
 use crate::client::GoogleMapsClient;
 use crate::directions::travel_mode::TravelMode;
 use crate::types::{Language, Region};
+use serde::Serialize;
 
 // -----------------------------------------------------------------------------
 //
 /// **Look at this `Request` struct for documentation on how to build your
 /// _Directions API_ query**. The methods implemented for this struct are what's
 /// used to build your request.
+///
+/// A request's parameters may be serialized, for example to save a search to
+/// disk. The client, and any query string that has already been built, are not
+/// serialized. Use `Request::deserialize_with_client` to load the parameters
+/// again.
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Request<'a> {
 
     // Required parameters:
@@ -64,6 +73,7 @@ pub struct Request<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
+    #[serde(skip)]
     client: &'a GoogleMapsClient,
 
     /// The address, latitude/longitude, or place ID to which you wish to
@@ -101,6 +111,11 @@ pub struct Request<'a> {
     /// `with_restrictions()` for more information.
     restrictions: Option<Vec<Avoid>>,
 
+    /// Whether parameters that Google would silently ignore are an error, or
+    /// only logged. See method `with_strict_validation()` for more
+    /// information.
+    strict_validation: bool,
+
     /// Assumptions to use when calculating time in traffic. See file
     /// `traffic_model.rs` and method `with_traffic_model()` for more
     /// information.
@@ -133,11 +148,19 @@ pub struct Request<'a> {
     // Internal use only:
     // ------------------
 
+    /// A caller-defined tag, such as a product feature, that is recorded in the
+    /// `billing_tag` field of the request's `tracing` span. It is never sent to
+    /// Google.
+    #[serde(skip)]
+    billing_tag: Option<String>,
+
     /// The URL-encoded query string that is passed to the Google Maps
     /// Directions API through cURL.
+    #[serde(skip)]
     query: Option<String>,
 
     /// Has the request been validated?
+    #[serde(skip)]
     validated: bool,
 
 } // struct
\ No newline at end of file
@@ -30,9 +30,10 @@ impl<'a> Request<'a> {
             alternatives: None,
             arrival_time: None,
             departure_time: None,
-            language: None,
-            region: None,
+            language: client.default_language.clone(),
+            region: client.default_region.clone(),
             restrictions: None,
+            strict_validation: false,
             traffic_model: None,
             transit_modes: None,
             transit_route_preference: None,
@@ -41,6 +42,7 @@ impl<'a> Request<'a> {
             waypoint_optimization: false,
             waypoints: None,
             // Internal use only:
+            billing_tag: None,
             query: None,
             validated: false,
         } // struct
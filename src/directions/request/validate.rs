@@ -13,6 +13,12 @@ impl<'a> Request<'a> {
     /// not check parameter values for validity - i.e. it will not ensure
     /// Polylines or Place ID's are valid and well-formed.
     ///
+    /// Parameters that Google accepts but silently ignores, such as
+    /// alternatives with waypoints, or transit options without a travel mode
+    /// of `TravelMode::Transit`, are logged as a warning unless
+    /// `with_strict_validation(true)` has been set, in which case they are an
+    /// error.
+    ///
     /// ## Arguments:
     ///
     /// This method accepts no arguments.
@@ -62,17 +68,52 @@ impl<'a> Request<'a> {
 
             } // if
 
+        // If the travel mode has not been set, Google uses the default
+        // (driving) travel mode and ignores any transit options. This is an
+        // error in strict mode, and is otherwise logged:
+        } else {
+
+            let travel_mode = TravelMode::default().to_string();
+
+            if let Some(transit_modes) = &self.transit_modes {
+                let error = Error::TransitModeIsForTransitOnly(
+                    travel_mode.clone(),
+                    transit_modes
+                        .iter()
+                        .map(|mode| mode.to_string())
+                        .collect::<Vec<String>>()
+                        .join("|"),
+                ); // Error
+                if self.strict_validation {
+                    return Err(error);
+                } // if
+                tracing::warn!("{error}");
+            } // if
+
+            if let Some(transit_route_preference) = &self.transit_route_preference {
+                let error = Error::TransitRoutePreferenceIsForTransitOnly(
+                    travel_mode,
+                    transit_route_preference.to_string(),
+                ); // Error
+                if self.strict_validation {
+                    return Err(error);
+                } // if
+                tracing::warn!("{error}");
+            } // if
+
         } // if
 
         // If waypoints have been set...
         if let Some(waypoints) = &self.waypoints {
 
-            // ...alternatives cannot be set to true:
-            if let Some(alternatives) = &self.alternatives {
-                if !alternatives {
-                    return Err(Error::EitherAlternativesOrWaypoints(waypoints.len()));
-                    // Err
+            // ...alternatives will be ignored by Google. This is an error in
+            // strict mode, and is otherwise logged:
+            if self.alternatives == Some(true) {
+                let error = Error::EitherAlternativesOrWaypoints(waypoints.len());
+                if self.strict_validation {
+                    return Err(error);
                 } // if
+                tracing::warn!("{error}");
             } // if
 
             // ...restrictions cannot be set:
@@ -115,4 +156,91 @@ impl<'a> Request<'a> {
 
     } // fn
 
-} // impl
\ No newline at end of file
+} // impl
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::GoogleMapsClient;
+    use crate::directions::{error::Error, request::{location::Location, waypoint::Waypoint}};
+    use std::sync::{Arc, Mutex};
+    use tracing::{field::{Field, Visit}, span, Event, Level, Metadata, Subscriber};
+
+    /// A `tracing` subscriber that records the message of each warning.
+    struct Warnings(Arc<Mutex<Vec<String>>>);
+
+    impl Subscriber for Warnings {
+        fn enabled(&self, _metadata: &Metadata) -> bool { true }
+        fn new_span(&self, _span: &span::Attributes) -> span::Id { span::Id::from_u64(1) }
+        fn record(&self, _span: &span::Id, _values: &span::Record) {}
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+        fn event(&self, event: &Event) {
+            if *event.metadata().level() == Level::WARN {
+                let mut message = Message(String::new());
+                event.record(&mut message);
+                self.0.lock().unwrap().push(message.0);
+            } // if
+        } // fn
+        fn enter(&self, _span: &span::Id) {}
+        fn exit(&self, _span: &span::Id) {}
+    } // impl
+
+    /// Records an event's `message` field.
+    struct Message(String);
+
+    impl Visit for Message {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                self.0 = format!("{value:?}");
+            } // if
+        } // fn
+    } // impl
+
+    /// Validates a request with alternatives and a waypoint, and returns the
+    /// result along with the warnings that were logged.
+    fn validate(alternatives: bool, strict_validation: bool) -> (Result<(), Error>, Vec<String>) {
+        let warnings = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = Warnings(warnings.clone());
+        let result = tracing::subscriber::with_default(subscriber, || {
+            let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+            let mut request = client.directions(
+                Location::Address(String::from("Los Angeles")),
+                Location::Address(String::from("New York")),
+            ); // directions
+            request
+                .with_alternatives(alternatives)
+                .with_waypoints(&[Waypoint::Address(String::from("Dallas"))])
+                .with_strict_validation(strict_validation)
+                .validate()
+                .map(|_request| ())
+        }); // with_default
+        let warnings = warnings.lock().unwrap().clone();
+        (result, warnings)
+    } // fn
+
+    #[test]
+    fn strict_mode_rejects_alternatives_with_waypoints() {
+        let (result, warnings) = validate(true, true);
+        assert!(matches!(result, Err(Error::EitherAlternativesOrWaypoints(1))));
+        assert!(warnings.is_empty());
+    } // fn
+
+    #[test]
+    fn lenient_mode_warns_about_alternatives_with_waypoints() {
+        let (result, warnings) = validate(true, false);
+        assert!(result.is_ok());
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("will not return alternative routes"), "{}", warnings[0]);
+    } // fn
+
+    #[test]
+    fn waypoints_without_alternatives_are_valid() {
+        for strict_validation in [false, true] {
+            let (result, warnings) = validate(false, strict_validation);
+            assert!(result.is_ok());
+            assert!(warnings.is_empty());
+        } // for
+    } // fn
+
+} // mod
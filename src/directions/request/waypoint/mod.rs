@@ -7,7 +7,7 @@ mod geo_conversions;
 
 // -----------------------------------------------------------------------------
 
-use crate::types::LatLng;
+use crate::types::{LatLng, PlaceId};
 
 // -----------------------------------------------------------------------------
 //
@@ -64,7 +64,7 @@ impl std::convert::From<&Waypoint> for String {
 /// Used to specify pass throughs or stopovers at intermediate locations.
 
 #[cfg(feature = "geo")]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Waypoint {
     /// If you pass an address, the Directions service geocodes the string and
     /// converts it to latitude & longitude coordinates to calculate directions.
@@ -132,4 +132,14 @@ impl std::convert::From<&Waypoint> for String {
 
         } // match
     } // fn
-} // impl
\ No newline at end of file
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<PlaceId> for Waypoint {
+    /// Converts a validated `PlaceId` struct into a `Waypoint::PlaceId`
+    /// variant.
+    fn from(place_id: PlaceId) -> Self {
+        Waypoint::PlaceId(place_id.into())
+    } // fn
+} // impl
@@ -17,6 +17,11 @@ impl<'a> Request<'a> {
     /// alternatives may increase the response time from the server. This is
     /// only available for requests without intermediate waypoints.
     ///
+    /// Google silently ignores this parameter for requests with waypoints. If
+    /// both are set, `validate()` logs a warning or, if
+    /// `with_strict_validation(true)` has been set, returns an
+    /// `EitherAlternativesOrWaypoints` error.
+    ///
     /// ## Example:
     ///
     /// * Allow more than one route in the response:
@@ -25,6 +25,16 @@ impl<'a> Request<'a> {
     /// results, because "Toledo" is interpreted as the city in Ohio and not
     /// Spain.
     ///
+    /// The region is sent as a ccTLD ("top-level domain") code, rather than as
+    /// an ISO 3166-1 country code. For example, `Region::Canada` is sent as
+    /// `region=ca`. Some ccTLD codes differ from their ISO 3166-1 codes:
+    /// `Region::UnitedKingdom` is sent as `co.uk`, not `gb`.
+    ///
+    /// The region only biases how address origins, destinations, and
+    /// waypoints are geocoded. It does not affect the routing itself: once the
+    /// endpoints are resolved, the route between them is the same. It has no
+    /// effect on endpoints specified as latitude/longitude pairs or place IDs.
+    ///
     /// ## Example:
     ///
     /// * Bias region to Canada:
@@ -37,4 +47,51 @@ impl<'a> Request<'a> {
         self
     } // fn
 
-} // impl
\ No newline at end of file
+    /// Specifies the region bias. This is an alias for `with_region`.
+    ///
+    /// ## Example:
+    ///
+    /// * Bias region to Canada:
+    /// ```rust
+    /// .with_region_bias(Region::Canada)
+    /// ```
+
+    pub fn with_region_bias(&'a mut self, region: Region) -> &'a mut Request {
+        self.with_region(region)
+    } // fn
+
+} // impl
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::GoogleMapsClient;
+    use crate::directions::request::location::Location;
+    use crate::types::Region;
+
+    fn query_url(region: Option<Region>) -> String {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let mut request = client.directions(
+            Location::Address(String::from("London")),
+            Location::Address(String::from("Toronto")),
+        ); // directions
+        match region {
+            Some(region) => request.with_region(region).query_url().unwrap(),
+            None => request.query_url().unwrap(),
+        } // match
+    } // fn
+
+    #[test]
+    fn region_is_sent_as_cctld() {
+        assert!(query_url(Some(Region::Canada)).contains("&region=ca"));
+        // The ccTLD for the United Kingdom differs from its ISO 3166-1 code:
+        assert!(query_url(Some(Region::UnitedKingdom)).contains("&region=co.uk"));
+    } // fn
+
+    #[test]
+    fn region_is_omitted_by_default() {
+        assert!(!query_url(None).contains("region="));
+    } // fn
+
+} // mod
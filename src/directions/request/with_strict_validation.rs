@@ -0,0 +1,41 @@
+use crate::directions::request::Request;
+
+impl<'a> Request<'a> {
+
+    /// Specifies whether requests that Google would partially ignore are
+    /// rejected, or only logged.
+    ///
+    /// ## Arguments
+    ///
+    /// * `strict_validation` ‧ Whether `validate()` should return an error,
+    /// rather than log a warning, when the request contains parameters that
+    /// Google will silently ignore.
+    ///
+    /// ## Description
+    ///
+    /// Some combinations of parameters are accepted by Google, but one of the
+    /// parameters has no effect. For example, Google does not return
+    /// alternative routes for a request with waypoints, so
+    /// `with_alternatives(true)` is ignored when `with_waypoints()` has been
+    /// set.
+    ///
+    /// By default, such requests are sent and a warning is logged using the
+    /// `tracing` crate. With strict validation, `validate()` returns an error
+    /// instead.
+    ///
+    /// ## Example:
+    ///
+    /// * Reject requests that ask for alternatives with waypoints:
+    /// ```rust
+    /// .with_strict_validation(true)
+    /// ```
+
+    pub fn with_strict_validation(
+        &'a mut self,
+        strict_validation: bool,
+    ) -> &'a mut Request {
+        self.strict_validation = strict_validation;
+        self
+    } // fn
+
+} // impl
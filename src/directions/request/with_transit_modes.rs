@@ -33,6 +33,10 @@ impl<'a> Request<'a> {
     /// travel by train, tram, light rail, and subway. This is equivalent to
     /// `TransitMode::Train|Tram|Subway`.
     ///
+    /// Using this method without a travel mode of `TravelMode::Transit` is an
+    /// error. If no travel mode is set, it is logged as a warning instead,
+    /// unless `with_strict_validation(true)` has been set.
+    ///
     /// ## Examples:
     ///
     /// * Set preferred transit mode to rail:
@@ -28,6 +28,10 @@ impl<'a> Request<'a> {
     /// * `TransitRoutePreference::FewerTransfers` indicates that the
     /// calculated route should prefer a limited number of transfers.
     ///
+    /// Using this method without a travel mode of `TravelMode::Transit` is an
+    /// error. If no travel mode is set, it is logged as a warning instead,
+    /// unless `with_strict_validation(true)` has been set.
+    ///
     /// ## Example:
     ///
     /// * Set transit route preference to fewer transfers:
@@ -43,4 +47,22 @@ impl<'a> Request<'a> {
         self
     } // fn
 
+    /// Specifies the preferences for transit routes. This is an alias for
+    /// `with_transit_route_preference`, named after Google's
+    /// `transit_routing_preference` parameter.
+    ///
+    /// ## Example:
+    ///
+    /// * Set transit routing preference to fewer transfers:
+    /// ```rust
+    /// .with_transit_routing_preference(TransitRoutePreference::FewerTransfers)
+    /// ```
+
+    pub fn with_transit_routing_preference(
+        &'a mut self,
+        transit_route_preference: TransitRoutePreference,
+    ) -> &'a mut Request {
+        self.with_transit_route_preference(transit_route_preference)
+    } // fn
+
 } // impl
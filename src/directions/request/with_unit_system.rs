@@ -32,7 +32,8 @@ impl<'a> Request<'a> {
     ///
     /// *Note*: this unit system setting only affects the `text` displayed
     /// within `distance` fields. The `distance` fields also contain `values`
-    /// which are always expressed in meters.
+    /// which are always expressed in meters. `duration` fields are not
+    /// affected: their `values` are always expressed in seconds.
     ///
     /// ## Example:
     ///
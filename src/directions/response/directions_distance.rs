@@ -1,14 +1,68 @@
 //! A representation of distance as a numeric value and a display string.
 
+use crate::directions::response::distance::Distance;
 use serde::{Deserialize, Serialize};
 
 /// A representation of distance as a numeric value and a display string.
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct DirectionsDistance {
     /// A string representation of the distance value, using the
     /// `with_unit_system()` specified in the request.
     pub text: String,
-    /// Indicates the distance in meters.
-    pub value: u32,
-} // struct
\ No newline at end of file
+    /// Indicates the distance in meters. Use `as_km()` or `as_miles()` to
+    /// convert it to another unit.
+    pub value: Distance,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::cmp::Ord for DirectionsDistance {
+    /// Orders distances by their `value`, so that distances can be compared
+    /// and sorted directly. The `text` is only compared when the values are
+    /// equal.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.value
+            .cmp(&other.value)
+            .then_with(|| self.text.cmp(&other.text))
+    } // fn
+} // impl
+
+impl std::cmp::PartialOrd for DirectionsDistance {
+    /// Orders distances by their `value`. See the `Ord` implementation.
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    fn distance(text: &str, meters: u32) -> DirectionsDistance {
+        DirectionsDistance { text: text.to_string(), value: Distance::from(meters) }
+    } // fn
+
+    #[test]
+    fn orders_by_value() {
+        // Ordered by their text, these would sort as "12 km", "800 m", "9.5 km":
+        let mut distances = [distance("9.5 km", 9_500), distance("12 km", 12_000), distance("800 m", 800)];
+        distances.sort();
+        let texts: Vec<&str> = distances.iter().map(|distance| distance.text.as_str()).collect();
+        assert_eq!(texts, ["800 m", "9.5 km", "12 km"]);
+        assert_eq!(distances.iter().max(), Some(&distance("12 km", 12_000)));
+    } // fn
+
+    #[test]
+    fn serde_round_trip() {
+        let json = json!({ "text": "9.5 km", "value": 9_500 });
+        let deserialized: DirectionsDistance = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(deserialized, distance("9.5 km", 9_500));
+        assert_eq!(serde_json::to_value(&deserialized).unwrap(), json);
+    } // fn
+
+} // mod
@@ -7,11 +7,71 @@ use serde::{Deserialize, Serialize};
 
 /// A representation of duration as a numeric value and a display string.
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct DirectionsDuration {
     /// A string representation of the duration value.
     pub text: String,
-    /// The duration in seconds.
+    /// The duration in seconds. Google occasionally returns this as a float,
+    /// which is rounded.
     #[serde(deserialize_with = "seconds_to_duration", serialize_with = "duration_to_seconds")]
     pub value: Duration,
-} // struct
\ No newline at end of file
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::cmp::Ord for DirectionsDuration {
+    /// Orders durations by their `value`, so that travel times can be compared
+    /// and sorted directly. The `text` is only compared when the values are
+    /// equal.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.value
+            .cmp(&other.value)
+            .then_with(|| self.text.cmp(&other.text))
+    } // fn
+} // impl
+
+impl std::cmp::PartialOrd for DirectionsDuration {
+    /// Orders durations by their `value`. See the `Ord` implementation.
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    fn duration(text: &str, seconds: i64) -> DirectionsDuration {
+        DirectionsDuration { text: text.to_string(), value: Duration::seconds(seconds) }
+    } // fn
+
+    #[test]
+    fn orders_by_value() {
+        // Ordered by their text, these would sort as "1 hour 5 mins",
+        // "2 mins", "45 mins":
+        let mut durations = [
+            duration("45 mins", 2_700),
+            duration("1 hour 5 mins", 3_900),
+            duration("2 mins", 120),
+        ];
+        durations.sort();
+        let texts: Vec<&str> = durations.iter().map(|duration| duration.text.as_str()).collect();
+        assert_eq!(texts, ["2 mins", "45 mins", "1 hour 5 mins"]);
+        assert!(duration("9 mins", 540) < duration("10 mins", 600));
+        // Equal values are ordered by their text:
+        assert!(duration("1 min", 60) < duration("60 secs", 60));
+    } // fn
+
+    #[test]
+    fn serde_round_trip() {
+        let json = json!({ "text": "1 hour 5 mins", "value": 3_900 });
+        let deserialized: DirectionsDuration = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(deserialized, duration("1 hour 5 mins", 3_900));
+        assert_eq!(serde_json::to_value(&deserialized).unwrap(), json);
+    } // fn
+
+} // mod
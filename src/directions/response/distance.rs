@@ -0,0 +1,97 @@
+//! A distance in meters, as returned in the `value` of a `DirectionsDistance`.
+
+use crate::serde::rounded_integer::rounded_u32;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+
+/// The number of meters in a kilometer.
+
+const METERS_PER_KILOMETER: f64 = 1_000.0;
+
+/// The number of meters in an international mile.
+
+const METERS_PER_MILE: f64 = 1_609.344;
+
+// -----------------------------------------------------------------------------
+//
+/// A distance in meters. The distance is always in meters, regardless of the
+/// `with_unit_system()` specified in the request, which only changes the
+/// `text` of the `DirectionsDistance`.
+///
+/// Wrapping the meters in a distinct type prevents a distance from being
+/// confused with a duration or a value in some other unit.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Distance(
+    /// The distance in meters. Google occasionally returns this as a float,
+    /// which is rounded.
+    #[serde(deserialize_with = "rounded_u32")]
+    pub u32,
+); // struct
+
+// -----------------------------------------------------------------------------
+
+impl Distance {
+
+    /// Returns the distance in meters.
+    /// ```rust
+    /// let meters = leg.distance.value.as_meters();
+    /// ```
+
+    pub fn as_meters(&self) -> u32 {
+        self.0
+    } // fn
+
+    /// Returns the distance in kilometers.
+    /// ```rust
+    /// let kilometers = leg.distance.value.as_km();
+    /// ```
+
+    pub fn as_km(&self) -> f64 {
+        f64::from(self.0) / METERS_PER_KILOMETER
+    } // fn
+
+    /// Returns the distance in international miles.
+    /// ```rust
+    /// let miles = leg.distance.value.as_miles();
+    /// ```
+
+    pub fn as_miles(&self) -> f64 {
+        f64::from(self.0) / METERS_PER_MILE
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<u32> for Distance {
+    /// Converts a number of meters into a `Distance`.
+    fn from(meters: u32) -> Self {
+        Distance(meters)
+    } // fn
+} // impl
+
+impl std::convert::From<Distance> for u32 {
+    /// Converts a `Distance` into a number of meters.
+    fn from(distance: Distance) -> Self {
+        distance.0
+    } // fn
+} // impl
+
+impl std::convert::From<Distance> for u64 {
+    /// Converts a `Distance` into a number of meters.
+    fn from(distance: Distance) -> Self {
+        u64::from(distance.0)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Distance {
+    /// Formats the distance as a number of meters, for example `1234 m`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} m", self.0)
+    } // fn
+} // impl
@@ -12,6 +12,10 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// This field is used to determine which icon to display. Values in this
 /// [list](https://developers.google.com/maps/documentation/directions/intro#Steps)
 /// are subject to change.
+///
+/// Maneuvers that are not known to this crate are deserialized into the
+/// `Other` variant, rather than failing, so that new values from Google do
+/// not break existing applications.
 
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(u8)]
@@ -37,6 +41,9 @@ pub enum DrivingManeuver {
     TurnSlightRight = 18,
     UturnLeft = 19,
     UturnRight = 20,
+    /// A maneuver that is not known to this crate. This contains the
+    /// maneuver code exactly as returned by Google.
+    Other(String) = 21,
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -44,11 +51,12 @@ pub enum DrivingManeuver {
 impl<'de> Deserialize<'de> for DrivingManeuver {
     /// Manual implementation of `Deserialize` for `serde`. This will take
     /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    /// Unknown maneuver codes are deserialized into the `Other` variant.
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let string = String::deserialize(deserializer)?;
         match DrivingManeuver::try_from(string.as_str()) {
             Ok(variant) => Ok(variant),
-            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+            Err(_error) => Ok(DrivingManeuver::Other(string)),
         } // match
     } // fn
 } // impl
@@ -65,12 +73,12 @@ impl Serialize for DrivingManeuver {
 
 // -----------------------------------------------------------------------------
 
-impl std::convert::From<&DrivingManeuver> for &str {
+impl<'a> std::convert::From<&'a DrivingManeuver> for &'a str {
     /// Converts a `DrivingManeuver` enum to a `String` that contains a
     /// [maneuver
     /// type](https://developers.google.com/maps/documentation/directions/intro#Steps)
     /// code.
-    fn from(maneuver_type: &DrivingManeuver) -> Self {
+    fn from(maneuver_type: &'a DrivingManeuver) -> Self {
         match maneuver_type {
             DrivingManeuver::Ferry => "ferry",
             DrivingManeuver::FerryTrain => "ferry-train",
@@ -93,6 +101,7 @@ impl std::convert::From<&DrivingManeuver> for &str {
             DrivingManeuver::TurnSlightRight => "turn-slight-right",
             DrivingManeuver::UturnLeft => "uturn-left",
             DrivingManeuver::UturnRight => "uturn-right",
+            DrivingManeuver::Other(code) => code,
         } // match
     } // fn
 } // impl
@@ -211,6 +220,7 @@ impl DrivingManeuver {
             DrivingManeuver::TurnSlightRight => "Turn Slight Right",
             DrivingManeuver::UturnLeft => "U-turn Left",
             DrivingManeuver::UturnRight => "U-turn Right",
+            DrivingManeuver::Other(code) => code,
         } // match
     } // fn
 } // impl
\ No newline at end of file
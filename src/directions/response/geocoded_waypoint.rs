@@ -1,6 +1,14 @@
+//! Details about how an origin, waypoint, or destination was geocoded.
+
 use crate::{directions::response::geocoder_status::GeocoderStatus, types::PlaceType}; // use
 use serde::{Deserialize, Serialize};
 
+// -----------------------------------------------------------------------------
+
+/// Details about the geocoding of a single origin, waypoint, or destination of
+/// a Directions request. Look at the `geocoder_status`, `partial_match`, and
+/// `types` fields to find out how Google interpreted each location.
+
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct GeocodedWaypoint {
     /// Indicates the status code resulting from the geocoding operation.
@@ -6,6 +6,7 @@ use crate::directions::response::{
     directions_duration::DirectionsDuration,
     step::Step,
     transit_time::TransitTime,
+    turn_instruction::TurnInstruction,
 }; // crate::directions::response
 use crate::types::LatLng;
 use serde::{Deserialize, Serialize};
@@ -160,4 +161,76 @@ impl Leg {
         self.departure_time.as_ref().map(|time| time.time_zone.name().to_string())
     } // fn
 
-} // impl
\ No newline at end of file
+    /// Returns the leg's steps as turn-by-turn navigation instructions. Each
+    /// instruction contains the maneuver, the distance & duration until the
+    /// next maneuver, and the instruction as plain text (HTML removed).
+    /// ```rust
+    /// let turn_instructions = leg.turn_instructions();
+    /// ```
+
+    pub fn turn_instructions(&self) -> Vec<TurnInstruction> {
+        self.steps.iter().map(TurnInstruction::from).collect()
+    } // fn
+
+} // impl
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::directions::response::driving_maneuver::DrivingManeuver;
+    use serde_json::json;
+
+    fn step(html_instructions: &str, maneuver: Option<&str>, meters: u32, seconds: u32) -> serde_json::Value {
+        json!({
+            "distance": { "text": "", "value": meters },
+            "duration": { "text": "", "value": seconds },
+            "end_location": { "lat": 45.5, "lng": -73.56 },
+            "html_instructions": html_instructions,
+            "maneuver": maneuver,
+            "polyline": { "points": "" },
+            "start_location": { "lat": 45.5, "lng": -73.56 },
+            "travel_mode": "DRIVING",
+        }) // json!
+    } // fn
+
+    #[test]
+    fn turn_instructions_for_a_multi_step_leg() {
+        let leg: Leg = serde_json::from_value(json!({
+            "distance": { "text": "1.5 km", "value": 1_500 },
+            "duration": { "text": "4 mins", "value": 240 },
+            "end_address": "Place d'Armes, Montréal, QC",
+            "end_location": { "lat": 45.5045, "lng": -73.5576 },
+            "start_address": "Rue Sherbrooke O, Montréal, QC",
+            "start_location": { "lat": 45.4985, "lng": -73.5795 },
+            "steps": [
+                step("Head <b>east</b> on <b>Rue Sherbrooke O</b>", None, 800, 120),
+                step("Turn <b>right</b> onto <b>Rue Saint-Urbain</b>", Some("turn-right"), 650, 100),
+                step("Turn <b>left</b> onto <b>Rue Notre-Dame O</b> &amp; continue to <b>Place d&#39;Armes</b>", Some("turn-left"), 50, 20),
+            ],
+        })).unwrap(); // from_value
+
+        let turn_instructions = leg.turn_instructions();
+        assert_eq!(turn_instructions.len(), 3);
+        let maneuvers: Vec<Option<DrivingManeuver>> = turn_instructions
+            .iter()
+            .map(|turn_instruction| turn_instruction.maneuver.clone())
+            .collect();
+        assert_eq!(maneuvers, [None, Some(DrivingManeuver::TurnRight), Some(DrivingManeuver::TurnLeft)]);
+        assert_eq!(
+            turn_instructions.iter().map(|turn_instruction| turn_instruction.distance_m).collect::<Vec<u32>>(),
+            [800, 650, 50],
+        ); // assert_eq!
+        assert_eq!(
+            turn_instructions.iter().map(|turn_instruction| turn_instruction.duration_s).collect::<Vec<i64>>(),
+            [120, 100, 20],
+        ); // assert_eq!
+        assert_eq!(turn_instructions[1].instruction, "Turn right onto Rue Saint-Urbain");
+        assert_eq!(
+            turn_instructions[2].instruction,
+            "Turn left onto Rue Notre-Dame O & continue to Place d'Armes",
+        ); // assert_eq!
+    } // fn
+
+} // mod
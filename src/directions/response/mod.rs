@@ -4,6 +4,7 @@
 
 pub mod directions_distance;
 pub mod directions_duration;
+pub mod distance;
 pub mod driving_maneuver;
 pub mod geocoded_waypoint;
 pub mod geocoder_status;
@@ -21,10 +22,14 @@ pub mod transit_line;
 pub mod transit_stop;
 pub mod transit_time;
 pub mod transit_vehicle;
+pub mod turn_instruction;
 
+use chrono::Duration;
 use crate::directions::{
+    request::waypoint::Waypoint,
     response::{
         geocoded_waypoint::GeocodedWaypoint,
+        geocoder_status::GeocoderStatus,
         route::Route,
         status::Status
     }, // response
@@ -55,7 +60,13 @@ pub struct Response {
     /// Contains an array with details about the geocoding of origin,
     /// destination and waypoints. See [Geocoded
     /// Waypoints](https://developers.google.com/maps/documentation/directions/intro#GeocodedWaypoints).
-    pub geocoded_waypoints: Option<Vec<GeocodedWaypoint>>,
+    ///
+    /// There is one element for the origin, one for each waypoint in the order
+    /// that they were supplied, and one for the destination. These details are
+    /// useful for diagnosing why a route resolved to an unexpected location.
+    /// This array is empty if Google did not return it.
+    #[serde(default)]
+    pub geocoded_waypoints: Vec<GeocodedWaypoint>,
 
     /// Contains an array of routes from the origin to the destination. See
     /// [Routes](https://developers.google.com/maps/documentation/directions/intro#Routes).
@@ -76,4 +87,290 @@ impl std::str::FromStr for Response {
     fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
         serde_json::from_str(s)
     }
-}
\ No newline at end of file
+}
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the route with the shortest total distance, or `None` if there
+    /// are no routes. Google only returns more than one route when the
+    /// request was made with `with_alternatives(true)`.
+    /// ```rust
+    /// let shortest = directions.shortest_route();
+    /// ```
+    pub fn shortest_route(&self) -> Option<&Route> {
+        self.routes.iter().min_by_key(|route| route.total_distance_meters())
+    } // fn
+
+    /// Returns the route with the shortest total duration, or `None` if there
+    /// are no routes. Traffic is not taken into account. Google only returns
+    /// more than one route when the request was made with
+    /// `with_alternatives(true)`.
+    /// ```rust
+    /// let fastest = directions.fastest_route();
+    /// ```
+    pub fn fastest_route(&self) -> Option<&Route> {
+        self.routes.iter().min_by_key(|route| route.total_duration())
+    } // fn
+
+    /// Returns how much travel time was saved by optimizing the order of the
+    /// waypoints, using `with_waypoint_optimization(true)`.
+    ///
+    /// Google only returns the legs for the _optimized_ order. The travel time
+    /// for the original order cannot be derived from this response, so the
+    /// savings can only be calculated when the original order was already
+    /// optimal (i.e. the `waypoint_order` is unchanged), in which case zero is
+    /// returned. When the waypoints were re-ordered, this function returns
+    /// `None`. To measure the savings in that case, make a second request
+    /// without waypoint optimization and compare the two routes' durations.
+    ///
+    /// `None` is also returned if there are no routes, or if the number of
+    /// `original_order` waypoints does not match the response's
+    /// `waypoint_order`.
+    /// ```rust
+    /// let savings = directions.optimization_savings(&waypoints);
+    /// ```
+    pub fn optimization_savings(&self, original_order: &[Waypoint]) -> Option<Duration> {
+        let route = self.routes.first()?;
+        if route.waypoint_order.len() != original_order.len() { return None } // if
+        let unchanged = route.waypoint_order
+            .iter()
+            .enumerate()
+            .all(|(index, waypoint)| usize::from(*waypoint) == index);
+        if unchanged { Some(Duration::zero()) } else { None }
+    } // fn
+
+    /// Returns the indices of the geocoded waypoints that Google could only
+    /// partially match. A partial match means that Google did not find an
+    /// exact match for the address, but was able to match part of it. The
+    /// route may not go where you expect, so consider asking the user "did you
+    /// mean...?" before trusting it.
+    ///
+    /// The indices refer to the `geocoded_waypoints` array: `0` is the origin,
+    /// followed by the waypoints in the order they were supplied, and the last
+    /// index is the destination.
+    /// ```rust
+    /// for index in directions.partial_match_waypoints() {
+    ///     println!("Waypoint #{index} was only partially matched");
+    /// }
+    /// ```
+    pub fn partial_match_waypoints(&self) -> Vec<usize> {
+        self.geocoded_waypoints
+            .iter()
+            .enumerate()
+            .filter(|(_index, waypoint)| waypoint.partial_match == Some(true))
+            .map(|(index, _waypoint)| index)
+            .collect()
+    } // fn
+
+    /// Returns the indices of the geocoded waypoints that Google could not
+    /// geocode, along with their `geocoder_status`. Google may still return a
+    /// route when an intermediate waypoint could not be geocoded, because it
+    /// skips that waypoint, so an `OK` response does not guarantee that every
+    /// location was visited.
+    ///
+    /// The indices refer to the `geocoded_waypoints` array: `0` is the origin,
+    /// followed by the waypoints in the order they were supplied, and the last
+    /// index is the destination.
+    /// ```rust
+    /// for (index, status) in directions.failed_waypoints() {
+    ///     println!("Waypoint #{index} could not be geocoded: {status}");
+    /// }
+    /// ```
+    pub fn failed_waypoints(&self) -> Vec<(usize, &GeocoderStatus)> {
+        self.geocoded_waypoints
+            .iter()
+            .enumerate()
+            .filter_map(|(index, waypoint)| match &waypoint.geocoder_status {
+                Some(GeocoderStatus::Ok) | None => None,
+                Some(status) => Some((index, status)),
+            }) // filter_map
+            .collect()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Response {
+    /// Formats a concise, one-line summary of the Directions response for
+    /// logging: the status, the number of routes, and the summary, total
+    /// distance and total duration of the primary route. Use `{:#?}` to see
+    /// the entire response.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Directions {}: {} route(s)", self.status, self.routes.len())?;
+        if let Some(route) = self.routes.first() {
+            let meters: u64 = route.legs
+                .iter()
+                .map(|leg| u64::from(leg.distance.value))
+                .sum();
+            let minutes: i64 = route.legs
+                .iter()
+                .map(|leg| leg.duration.value.num_minutes())
+                .sum();
+            write!(f, ". Primary route")?;
+            if let Some(summary) = route.get_summary() {
+                write!(f, " via {summary}")?;
+            } // if
+            write!(f, ": {:.1} km, {}h {:02}m", meters as f64 / 1_000.0, minutes / 60, minutes % 60)?;
+        } // if
+        Ok(())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::PlaceType;
+    use serde_json::json;
+
+    /// Returns a leg with the specified distance and duration, and no steps.
+    fn leg(meters: u32, seconds: u32) -> serde_json::Value {
+        json!({
+            "distance": { "text": "", "value": meters },
+            "duration": { "text": "", "value": seconds },
+            "end_address": "",
+            "end_location": { "lat": 0.0, "lng": 0.0 },
+            "start_address": "",
+            "start_location": { "lat": 0.0, "lng": 0.0 },
+            "steps": [],
+        }) // json!
+    } // fn
+
+    /// Returns a route with the specified summary, legs and waypoint order.
+    fn route(summary: &str, legs: Vec<serde_json::Value>, waypoint_order: &[u8]) -> serde_json::Value {
+        json!({
+            "bounds": {
+                "northeast": { "lat": 45.5, "lng": -73.5 },
+                "southwest": { "lat": 43.6, "lng": -79.4 },
+            },
+            "copyrights": "",
+            "legs": legs,
+            "overview_polyline": { "points": "" },
+            "summary": summary,
+            "warnings": [],
+            "waypoint_order": waypoint_order,
+        }) // json!
+    } // fn
+
+    /// Returns an optimized response with a single route that visits the
+    /// waypoints in the specified order.
+    fn optimized_response(waypoint_order: &[u8]) -> Response {
+        let legs = vec![leg(1_000, 60); waypoint_order.len() + 1];
+        serde_json::from_value(json!({
+            "routes": [route("", legs, waypoint_order)],
+            "status": "OK",
+        })).unwrap() // from_value
+    } // fn
+
+    /// Returns a response whose geocoded origin, waypoint and destination
+    /// have the specified `partial_match` values.
+    fn geocoded_response(partial_matches: [Option<bool>; 3]) -> Response {
+        let geocoded_waypoints: Vec<serde_json::Value> = partial_matches
+            .iter()
+            .map(|partial_match| json!({
+                "geocoder_status": "OK",
+                "partial_match": partial_match,
+                "place_id": "ChIJpTvG15DL1IkRd8S0KlBVNTI",
+                "types": ["locality", "political"],
+            })) // map
+            .collect();
+        serde_json::from_value(json!({
+            "geocoded_waypoints": geocoded_waypoints,
+            "routes": [],
+            "status": "OK",
+        })).unwrap() // from_value
+    } // fn
+
+    #[test]
+    fn geocoded_waypoints_are_deserialized() {
+        let response: Response = serde_json::from_value(json!({
+            "geocoded_waypoints": [
+                {
+                    "geocoder_status": "OK",
+                    "place_id": "ChIJpTvG15DL1IkRd8S0KlBVNTI",
+                    "types": ["locality", "political"],
+                },
+                {
+                    "geocoder_status": "OK",
+                    "partial_match": true,
+                    "place_id": "ChIJ2WrMN9MDDUsRpY9Doiq3aJk",
+                    "types": ["street_address"],
+                },
+                { "geocoder_status": "ZERO_RESULTS" },
+            ],
+            "routes": [],
+            "status": "OK",
+        })).unwrap(); // from_value
+
+        let waypoints = &response.geocoded_waypoints;
+        assert_eq!(waypoints.len(), 3);
+        assert_eq!(waypoints[0].geocoder_status, Some(GeocoderStatus::Ok));
+        assert_eq!(waypoints[0].partial_match, None);
+        assert_eq!(waypoints[0].types, Some(vec![PlaceType::Locality, PlaceType::Political]));
+        assert_eq!(waypoints[1].partial_match, Some(true));
+        assert_eq!(waypoints[1].place_id.as_deref(), Some("ChIJ2WrMN9MDDUsRpY9Doiq3aJk"));
+        assert_eq!(waypoints[2].geocoder_status, Some(GeocoderStatus::ZeroResults));
+        assert_eq!(waypoints[2].place_id, None);
+    } // fn
+
+    #[test]
+    fn geocoded_waypoints_default_to_empty() {
+        let response: Response = serde_json::from_value(json!({
+            "routes": [],
+            "status": "ZERO_RESULTS",
+        })).unwrap(); // from_value
+        assert!(response.geocoded_waypoints.is_empty());
+    } // fn
+
+    #[test]
+    fn display_summarizes_the_primary_route() {
+        let response: Response = serde_json::from_value(json!({
+            "routes": [
+                // Two legs, totalling 541.6 km and 5 hours 23 minutes:
+                route("ON-401 E", vec![leg(450_000, 4 * 3_600 + 30 * 60), leg(91_600, 53 * 60)], &[]),
+                route("ON-7 E", vec![leg(600_000, 7 * 3_600)], &[]),
+            ],
+            "status": "OK",
+        })).unwrap(); // from_value
+        assert_eq!(
+            response.to_string(),
+            "Directions OK: 2 route(s). Primary route via ON-401 E: 541.6 km, 5h 23m",
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn display_without_routes() {
+        let response: Response = serde_json::from_value(json!({
+            "routes": [],
+            "status": "ZERO_RESULTS",
+        })).unwrap(); // from_value
+        assert_eq!(response.to_string(), "Directions Zero Results: 0 route(s)");
+    } // fn
+
+    #[test]
+    fn no_savings_are_derivable_for_a_reordered_route() {
+        let waypoints = ["Dallas", "Bangor", "Phoenix"]
+            .map(|address| Waypoint::Address(String::from(address)));
+        // Google moved Phoenix first, so the original order's duration is not
+        // in the response:
+        assert_eq!(optimized_response(&[2, 0, 1]).optimization_savings(&waypoints), None);
+        // The original order was already optimal:
+        assert_eq!(
+            optimized_response(&[0, 1, 2]).optimization_savings(&waypoints),
+            Some(Duration::zero()),
+        ); // assert_eq!
+        // The waypoint count does not match the response:
+        assert_eq!(optimized_response(&[1, 0]).optimization_savings(&waypoints), None);
+    } // fn
+
+    #[test]
+    fn partial_match_waypoints() {
+        assert_eq!(geocoded_response([None, Some(true), Some(false)]).partial_match_waypoints(), [1]);
+        assert_eq!(geocoded_response([Some(true), None, Some(true)]).partial_match_waypoints(), [0, 2]);
+        assert!(geocoded_response([None, None, Some(false)]).partial_match_waypoints().is_empty());
+    } // fn
+
+} // mod
@@ -1,6 +1,7 @@
 //! An [encoded polyline representation](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
 //! of the route.
 
+use crate::types::{polyline::decode, LatLng};
 use serde::{Deserialize, Serialize};
 
 /// An [encoded polyline representation](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
@@ -9,4 +10,21 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Polyline {
     pub points: String,
-} // struct
\ No newline at end of file
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Polyline {
+
+    /// Decodes the polyline into the coordinates that it passes through.
+    /// Returns `None` if the polyline is malformed.
+    ///
+    /// ```rust
+    /// let path = step.polyline.decode().unwrap_or_default();
+    /// ```
+
+    pub fn decode(&self) -> Option<Vec<LatLng>> {
+        decode(&self.points)
+    } // fn
+
+} // impl
@@ -1,11 +1,17 @@
 //! A single route containing a set of legs.
 
 use crate::directions::response::{
+    driving_maneuver::DrivingManeuver,
     leg::Leg,
     overview_polyline::OverviewPolyline,
+    step::Step,
     transit_fare::TransitFare,
 }; // crate::directions::response
-use crate::types::Bounds;
+use chrono::Duration;
+use crate::directions::error::Error;
+use crate::directions::vehicle_type::VehicleType;
+use crate::html::html_to_plain_text;
+use crate::types::{Bounds, LatLng};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -152,4 +158,415 @@ impl Route {
         } // if
     } // fn
 
-} // impl
\ No newline at end of file
+    /// Returns the number of legs that a route will contain for the specified
+    /// number of stopover waypoints. This is always `n + 1`: one leg from the
+    /// origin to the first waypoint, one leg between each pair of consecutive
+    /// waypoints, and one leg from the last waypoint to the destination. A
+    /// route with no waypoints has a single leg.
+    ///
+    /// Unless `optimize_waypoints` was requested, the legs are in the same
+    /// order as the waypoints were supplied, so leg `i` ends at waypoint `i`.
+    /// Waypoints passed as `via:` (non-stopover) waypoints do not create legs
+    /// and should not be counted.
+    /// ```rust
+    /// assert_eq!(route.legs.len(), Route::leg_count_for_waypoints(waypoints.len()));
+    /// ```
+
+    pub fn leg_count_for_waypoints(n: usize) -> usize {
+        n + 1
+    } // fn
+
+    /// Returns the total distance of the route in meters: the sum of the
+    /// distances of its legs.
+    /// ```rust
+    /// let meters = route.total_distance_meters();
+    /// ```
+
+    pub fn total_distance_meters(&self) -> u64 {
+        self.legs
+            .iter()
+            .map(|leg| u64::from(leg.distance.value))
+            .sum()
+    } // fn
+
+    /// Returns the total duration of the route: the sum of the durations of
+    /// its legs. Traffic is not taken into account.
+    /// ```rust
+    /// let duration = route.total_duration();
+    /// ```
+
+    pub fn total_duration(&self) -> Duration {
+        self.legs
+            .iter()
+            .fold(Duration::zero(), |total, leg| total + leg.duration.value)
+    } // fn
+
+    /// Checks that the route's `waypoint_order` is a permutation of the
+    /// indices of the supplied waypoints: each index from `0` to
+    /// `waypoint_count - 1` appears exactly once. An empty `waypoint_order` is
+    /// also accepted, since Google returns one when the waypoints were not
+    /// optimized.
+    ///
+    /// Returns `Error::InvalidWaypointOrder` if Google returned anything else.
+    /// ```rust
+    /// route.validate_waypoint_order(waypoints.len())?;
+    /// ```
+
+    pub fn validate_waypoint_order(&self, waypoint_count: usize) -> Result<(), Error> {
+        if self.waypoint_order.is_empty() {
+            return Ok(());
+        } // if
+        let mut seen = vec![false; waypoint_count];
+        let is_permutation = self.waypoint_order.len() == waypoint_count
+            && self.waypoint_order.iter().all(|index| {
+                match seen.get_mut(usize::from(*index)) {
+                    Some(seen @ false) => { *seen = true; true },
+                    _ => false,
+                } // match
+            }); // all
+        if is_permutation {
+            Ok(())
+        } else {
+            Err(Error::InvalidWaypointOrder(self.waypoint_order.clone(), waypoint_count))
+        } // if
+    } // fn
+
+    /// Returns the supplied waypoints in the order that the route visits them,
+    /// using the route's `waypoint_order`. If the waypoints were not
+    /// optimized, they are returned in their original order.
+    ///
+    /// The `waypoint_order` is validated first, so a malformed response
+    /// returns `Error::InvalidWaypointOrder` rather than panicking.
+    /// ```rust
+    /// let visiting_order = route.ordered_waypoints(&waypoints)?;
+    /// ```
+
+    pub fn ordered_waypoints<'w, T>(&self, waypoints: &'w [T]) -> Result<Vec<&'w T>, Error> {
+        self.validate_waypoint_order(waypoints.len())?;
+        if self.waypoint_order.is_empty() {
+            return Ok(waypoints.iter().collect());
+        } // if
+        Ok(self.waypoint_order
+            .iter()
+            .map(|index| &waypoints[usize::from(*index)])
+            .collect())
+    } // fn
+
+    /// Returns `true` if the route appears to use toll roads.
+    ///
+    /// Google does not return a dedicated toll flag for a route, so this is a
+    /// heuristic. The route's `warnings`, and the instructions of every step,
+    /// are searched for the words _toll_, _tolls_, _tolled_ or _tollway_. For
+    /// example, Google typically warns "This route has tolls." and describes
+    /// some steps as a "Toll road".
+    ///
+    /// Limitations: the heuristic only understands English. Request the
+    /// directions with `Language::English` (or a variant) if you rely on it.
+    /// Google does not always mention tolls, so a `false` result does not
+    /// guarantee that the route is toll-free. Requesting the route with
+    /// `Avoid::Tolls` is the only way to ask Google to exclude them.
+    /// ```rust
+    /// let has_tolls = route.has_tolls();
+    /// ```
+
+    pub fn has_tolls(&self) -> bool {
+        self.warnings.iter().any(|warning| mentions(warning, TOLL_WORDS))
+            || self.steps().any(|step| mentions(&html_to_plain_text(&step.html_instructions), TOLL_WORDS))
+    } // fn
+
+    /// Returns the steps of the route that appear to be ferry crossings.
+    ///
+    /// Google does not return a dedicated ferry flag for a route, so this is a
+    /// heuristic. A step is considered a ferry crossing if its `maneuver` is
+    /// `ferry` or `ferry-train`, if it is a transit step on a ferry, or if its
+    /// instructions mention a _ferry_ (for example, "Take the Horseshoe Bay -
+    /// Departure Bay ferry"). Sub-steps of transit and walking steps are also
+    /// checked.
+    ///
+    /// Limitations: instructions are only understood in English. Google does
+    /// not always describe ferry crossings, so an empty result does not
+    /// guarantee that the route has no ferries. Requesting the route with
+    /// `Avoid::Ferries` is the only way to ask Google to exclude them.
+    /// ```rust
+    /// let ferry_segments = route.ferry_segments();
+    /// ```
+
+    pub fn ferry_segments(&self) -> Vec<&Step> {
+        self.steps()
+            .filter(|step| {
+                matches!(step.maneuver, Some(DrivingManeuver::Ferry | DrivingManeuver::FerryTrain))
+                    || step.transit_details
+                        .as_ref()
+                        .is_some_and(|details| details.line.vehicle.vehicle_type == VehicleType::Ferry)
+                    || mentions(&html_to_plain_text(&step.html_instructions), FERRY_WORDS)
+            }) // filter
+            .collect()
+    } // fn
+
+    /// Returns the path of the route, decoded from the polylines of its steps,
+    /// with each coordinate paired with its distance in meters from the start
+    /// of the route.
+    ///
+    /// Distances are measured along the path using the haversine formula, so
+    /// they increase monotonically and may differ slightly from the distances
+    /// that Google reports for each step. The last distance is the length of
+    /// the path. Dividing a distance by it gives the fraction of the route
+    /// that has been travelled, for example for a progress bar.
+    ///
+    /// Consecutive steps share their end and start coordinate, which is only
+    /// included once. A step whose polyline can not be decoded is skipped.
+    ///
+    /// ```rust
+    /// let path = route.path_with_distances();
+    /// let length = path.last().map_or(0.0, |(_latlng, distance)| *distance);
+    /// ```
+
+    pub fn path_with_distances(&self) -> Vec<(LatLng, f64)> {
+        let mut path: Vec<(LatLng, f64)> = Vec::new();
+        let coordinates = self.legs
+            .iter()
+            .flat_map(|leg| leg.steps.iter())
+            .filter_map(|step| step.polyline.decode())
+            .flatten();
+        for latlng in coordinates {
+            let distance = match path.last() {
+                Some((previous, _distance)) if *previous == latlng => continue,
+                Some((previous, distance)) => distance + previous.haversine_distance_meters(&latlng),
+                None => 0.0,
+            }; // match
+            path.push((latlng, distance));
+        } // for
+        path
+    } // fn
+
+    /// Returns every step of every leg in the route, including any sub-steps,
+    /// in order.
+
+    fn steps(&self) -> impl Iterator<Item = &Step> {
+        self.legs
+            .iter()
+            .flat_map(|leg| leg.steps.iter())
+            .flat_map(|step| std::iter::once(step).chain(step.steps.iter().flatten()))
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Words that indicate that a route or step uses a toll road.
+
+const TOLL_WORDS: &[&str] = &["toll", "tolls", "tolled", "tollway", "tollways"];
+
+/// Words that indicate that a step is a ferry crossing.
+
+const FERRY_WORDS: &[&str] = &["ferry", "ferries"];
+
+/// Returns `true` if the text contains any of the specified (lower-case) words.
+/// Words are matched whole, so that _Tolleson_ is not mistaken for a _toll_.
+
+fn mentions(text: &str, words: &[&str]) -> bool {
+    text.split(|character: char| !character.is_alphanumeric())
+        .any(|word| words.iter().any(|candidate| word.eq_ignore_ascii_case(candidate)))
+} // fn
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    /// Returns a route without legs, with the specified `waypoint_order`.
+    fn route(waypoint_order: &[u8]) -> Route {
+        serde_json::from_value(json!({
+            "bounds": {
+                "northeast": { "lat": 40.7, "lng": -73.9 },
+                "southwest": { "lat": 34.0, "lng": -118.2 },
+            },
+            "copyrights": "Map data ©2024",
+            "legs": [],
+            "overview_polyline": { "points": "" },
+            "summary": "I-40 E",
+            "warnings": [],
+            "waypoint_order": waypoint_order,
+        })).unwrap() // from_value
+    } // fn
+
+    const WAYPOINTS: [&str; 3] = ["Dallas", "Bangor", "Phoenix"];
+
+    fn step(html_instructions: &str, maneuver: Option<&str>) -> serde_json::Value {
+        json!({
+            "distance": { "text": "", "value": 1_000 },
+            "duration": { "text": "", "value": 60 },
+            "end_location": { "lat": 49.19, "lng": -123.95 },
+            "html_instructions": html_instructions,
+            "maneuver": maneuver,
+            "polyline": { "points": "" },
+            "start_location": { "lat": 49.37, "lng": -123.27 },
+            "travel_mode": "DRIVING",
+        }) // json!
+    } // fn
+
+    /// Returns a route with a single leg made of the specified steps, and the
+    /// specified warnings.
+    fn route_with_steps(warnings: &[&str], steps: Vec<serde_json::Value>) -> Route {
+        let mut route = route(&[]);
+        route.warnings = warnings.iter().map(ToString::to_string).collect();
+        route.legs = vec![serde_json::from_value(json!({
+            "distance": { "text": "", "value": 1_000 * steps.len() },
+            "duration": { "text": "", "value": 60 * steps.len() },
+            "end_address": "Nanaimo, BC",
+            "end_location": { "lat": 49.17, "lng": -123.94 },
+            "start_address": "Vancouver, BC",
+            "start_location": { "lat": 49.28, "lng": -123.12 },
+            "steps": steps,
+        })).unwrap()]; // from_value
+        route
+    } // fn
+
+    /// A drive from Vancouver to Nanaimo, crossing on the Horseshoe Bay -
+    /// Departure Bay ferry.
+    fn ferry_route(warnings: &[&str]) -> Route {
+        route_with_steps(warnings, vec![
+            step("Head <b>west</b> on <b>W Georgia St</b>", None),
+            step("Merge onto <b>BC-99 N</b>", Some("merge")),
+            step("Take the <b>Horseshoe Bay - Departure Bay</b> ferry", Some("ferry")),
+            step("Continue onto <b>Stewart Ave</b>", None),
+        ]) // route_with_steps
+    } // fn
+
+    #[test]
+    fn valid_permutation() {
+        let route = route(&[2, 0, 1]);
+        assert!(route.validate_waypoint_order(WAYPOINTS.len()).is_ok());
+        assert_eq!(
+            route.ordered_waypoints(&WAYPOINTS).unwrap(),
+            vec![&"Phoenix", &"Dallas", &"Bangor"],
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn unoptimized_waypoints_keep_their_order() {
+        let route = route(&[]);
+        assert!(route.validate_waypoint_order(WAYPOINTS.len()).is_ok());
+        assert_eq!(
+            route.ordered_waypoints(&WAYPOINTS).unwrap(),
+            vec![&"Dallas", &"Bangor", &"Phoenix"],
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn malformed_permutations() {
+        // A repeated index, an index out of range, and too few and too many
+        // indices:
+        for waypoint_order in [&[0, 0, 1][..], &[0, 1, 3], &[1, 0], &[2, 0, 1, 3]] {
+            let route = route(waypoint_order);
+            assert!(
+                matches!(
+                    route.validate_waypoint_order(WAYPOINTS.len()),
+                    Err(Error::InvalidWaypointOrder(ref order, 3)) if order == waypoint_order,
+                ),
+                "{waypoint_order:?} was accepted",
+            ); // assert!
+            assert!(route.ordered_waypoints(&WAYPOINTS).is_err());
+        } // for
+    } // fn
+
+    #[test]
+    fn ferry_segments_of_a_route_with_a_ferry() {
+        let route = ferry_route(&[]);
+        let ferry_segments = route.ferry_segments();
+        assert_eq!(ferry_segments.len(), 1);
+        assert_eq!(ferry_segments[0].maneuver, Some(DrivingManeuver::Ferry));
+        assert!(ferry_segments[0].html_instructions.contains("Departure Bay"));
+        assert!(!route.has_tolls());
+    } // fn
+
+    #[test]
+    fn ferry_mentioned_without_maneuver() {
+        let route = route_with_steps(&[], vec![
+            step("Take the <b>Tsawwassen - Swartz Bay</b> Ferry", None),
+            step("Turn <b>left</b> onto <b>Lands End Rd</b>", Some("turn-left")),
+        ]); // route_with_steps
+        assert_eq!(route.ferry_segments().len(), 1);
+    } // fn
+
+    #[test]
+    fn route_without_ferries() {
+        let route = route_with_steps(&[], vec![step("Head <b>north</b> on <b>Main St</b>", None)]);
+        assert!(route.ferry_segments().is_empty());
+        assert!(!route.has_tolls());
+    } // fn
+
+    #[test]
+    fn tolls_from_warnings_or_instructions() {
+        assert!(ferry_route(&["This route has tolls."]).has_tolls());
+        let route = route_with_steps(&[], vec![
+            step("Merge onto <b>Golden Ears Bridge</b> (Toll road)", Some("merge")),
+        ]); // route_with_steps
+        assert!(route.has_tolls());
+    } // fn
+
+    #[test]
+    fn leg_count_matches_waypoint_count() {
+        assert_eq!(Route::leg_count_for_waypoints(0), 1);
+        assert_eq!(Route::leg_count_for_waypoints(3), 4);
+        // A route through two unoptimized waypoints has one leg ending at each
+        // waypoint, in the order supplied, and a final leg to the destination:
+        let stops = ["Hamilton, ON", "Kingston, ON", "Montréal, QC"];
+        let mut route = route(&[]);
+        route.legs = ["Toronto, ON", stops[0], stops[1]]
+            .iter()
+            .zip(stops)
+            .map(|(start_address, end_address)| serde_json::from_value(json!({
+                "distance": { "text": "", "value": 1_000 },
+                "duration": { "text": "", "value": 60 },
+                "end_address": end_address,
+                "end_location": { "lat": 45.5, "lng": -73.56 },
+                "start_address": start_address,
+                "start_location": { "lat": 43.65, "lng": -79.38 },
+                "steps": [],
+            })).unwrap()) // map
+            .collect();
+        let waypoints = &stops[..2];
+        assert_eq!(route.legs.len(), Route::leg_count_for_waypoints(waypoints.len()));
+        for (leg, waypoint) in route.legs.iter().zip(waypoints) {
+            assert_eq!(&leg.end_address, waypoint);
+        } // for
+    } // fn
+
+    #[test]
+    fn path_distances_increase_monotonically() {
+        use crate::types::polyline::encode;
+        let latlng = |lat: f64, lng: f64| LatLng::try_from_f64(lat, lng).unwrap();
+        // Two steps that share a coordinate, from Google's polyline example:
+        let first = [latlng(38.5, -120.2), latlng(40.7, -120.95)];
+        let second = [latlng(40.7, -120.95), latlng(43.252, -126.453)];
+        let route = route_with_steps(&[], [&first[..], &second[..]]
+            .iter()
+            .map(|points| {
+                let mut step = step("Continue", None);
+                step["polyline"]["points"] = json!(encode(points));
+                step
+            }) // map
+            .collect()); // route_with_steps
+
+        let path = route.path_with_distances();
+        let coordinates: Vec<LatLng> = path.iter().map(|(latlng, _distance)| latlng.clone()).collect();
+        assert_eq!(coordinates, [first[0].clone(), first[1].clone(), second[1].clone()]);
+        assert_eq!(path[0].1, 0.0);
+        assert!(path.windows(2).all(|pair| pair[0].1 < pair[1].1), "{path:?}");
+
+        // Each distance is the previous one plus the length of the segment:
+        let first_segment = first[0].haversine_distance_meters(&first[1]);
+        let second_segment = second[0].haversine_distance_meters(&second[1]);
+        assert!((path[1].1 - first_segment).abs() < 1e-6);
+        assert!((path[2].1 - (first_segment + second_segment)).abs() < 1e-6);
+    } // fn
+
+    #[test]
+    fn path_without_polylines() {
+        assert!(route_with_steps(&[], vec![step("Continue", None)]).path_with_distances().is_empty());
+    } // fn
+
+} // mod
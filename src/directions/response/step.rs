@@ -9,6 +9,7 @@ use crate::directions::response::{
     transit_details::TransitDetails,
 }; // crate::directions::response
 use crate::directions::travel_mode::TravelMode;
+use crate::html::html_to_plain_text;
 use crate::types::LatLng;
 use serde::{Deserialize, Serialize};
 
@@ -81,4 +82,16 @@ impl Step {
         self.maneuver.as_ref().map(String::from)
     } // fn
 
+    /// Returns the step's instructions as plain text, for example for voice
+    /// guidance. HTML tags are removed from `html_instructions` and HTML
+    /// entities are decoded. Notes that Google places in their own block, such
+    /// as "Destination will be on the right", are separated by a space.
+    /// ```rust
+    /// let instructions = step.plain_instructions();
+    /// ```
+
+    pub fn plain_instructions(&self) -> String {
+        html_to_plain_text(&self.html_instructions)
+    } // fn
+
 } // impl
\ No newline at end of file
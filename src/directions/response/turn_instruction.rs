@@ -0,0 +1,40 @@
+//! Contains the `TurnInstruction` struct. It packages the essentials of a
+//! `Step` that are needed for a turn-by-turn navigation user interface.
+
+use crate::directions::response::{driving_maneuver::DrivingManeuver, step::Step};
+use crate::html::html_to_plain_text;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+
+/// A single turn-by-turn navigation instruction, projected from a `Step`. The
+/// distance and duration are measured from the start of this instruction to
+/// the next maneuver.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct TurnInstruction {
+    /// The action to take for this instruction (turn left, merge, straight,
+    /// etc.), if Google provided one.
+    pub maneuver: Option<DrivingManeuver>,
+    /// The distance, in meters, until the next maneuver.
+    pub distance_m: u32,
+    /// The typical time, in seconds, until the next maneuver.
+    pub duration_s: i64,
+    /// The instruction as plain text. HTML tags are removed and HTML entities
+    /// are decoded.
+    pub instruction: String,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Step> for TurnInstruction {
+    /// Converts a `Step` into a `TurnInstruction`.
+    fn from(step: &Step) -> Self {
+        TurnInstruction {
+            maneuver: step.maneuver.clone(),
+            distance_m: step.distance.value.as_meters(),
+            duration_s: step.duration.value.num_seconds(),
+            instruction: html_to_plain_text(&step.html_instructions),
+        } // TurnInstruction
+    } // fn
+} // impl
@@ -75,6 +75,11 @@ pub enum Error {
     /// vehicle type code. See `google_maps\src\directions\vehicle_type.rs` for
     /// more information.
     InvalidVehicleTypeCode(String),
+    /// Google Maps Distance Matrix API server reported that the daily usage
+    /// limit has been exceeded, or that billing is not set up for the API key.
+    /// This error is never retried, as retrying will not help until the quota
+    /// is reset or the billing and quota settings are fixed.
+    OverDailyLimit(Option<String>),
     /// The query string must be built before the request may be sent to the
     /// Google Maps Distance Matrix API server.
     QueryNotBuilt,
@@ -89,6 +94,12 @@ pub enum Error {
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
     SerdeJson(serde_json::error::Error),
+    /// The HTTP request to the Google Maps Distance Matrix API server timed
+    /// out. See `GoogleMapsClient::with_timeout` and
+    /// `GoogleMapsClient::with_connect_timeout`. This error is eligible for
+    /// retries.
+    #[cfg(feature = "enable-reqwest")]
+    Timeout(reqwest::Error),
     /// Too many waypoints specified.
     TooManyWaypoints(usize),
     /// Transit mode may only be specified in Transit travel mode.
@@ -224,6 +235,15 @@ impl std::fmt::Display for Error {
                 `HIGH_SPEED_TRAIN`, `INTERCITY_BUS`, `LONG_DISTANCE_TRAIN`, \
                 `METRO_RAIL`, `MONORAIL`, `OTHER`, `RAIL`, `SHARE_TAXI`, \
                 `SUBWAY`, `TRAM`, and `TROLLEYBUS`."),
+            Error::OverDailyLimit(error_message) => match error_message {
+                Some(error_message) => write!(f, "Google Maps Distance Matrix API service: \
+                    Over daily limit. {error_message} \
+                    Check the billing and quota settings for your API key in the Google Cloud Console."),
+                None => write!(f, "Google Maps Distance Matrix API service: \
+                    Over daily limit. \
+                    Usage cap has been exceeded, API key is invalid, billing has not been enabled, or method of payment is no longer valid. \
+                    Check the billing and quota settings for your API key in the Google Cloud Console."),
+            }, // match
             Error::QueryNotBuilt => write!(f,
                 "Google Maps Distance Matrix API client: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
@@ -237,6 +257,9 @@ impl std::fmt::Display for Error {
             #[cfg(feature = "enable-reqwest")]
             Error::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
             Error::SerdeJson(error) => write!(f, "Google Maps Distance Matrix API client in the Serde JSON library: {error}"),
+            #[cfg(feature = "enable-reqwest")]
+            Error::Timeout(error) => write!(f, "Google Maps Distance Matrix API client: \
+                The request timed out: {error}"),
             Error::TooManyWaypoints(waypoint_count) => write!(f,
                 "Google Maps Distance Matrix API client: \
                 The maximum allowed number of waypoints is 25 plus the origin and destination. \
@@ -281,4 +304,25 @@ impl From<serde_json::error::Error> for Error {
     fn from(error: serde_json::error::Error) -> Error {
         Error::SerdeJson(error)
     } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::client::ApiError for Error {
+    /// Wraps an unsuccessful HTTP status in a Google Maps Distance Matrix API
+    /// error type. This function is required by the shared `get` method.
+    fn http_unsuccessful(status: String) -> Error {
+        Error::HttpUnsuccessful(status)
+    } // fn
+    /// Wraps a HTTP client error message in a Google Maps Distance Matrix API
+    /// error type. This function is required by the shared `get` method.
+    fn reqwest_message(message: String) -> Error {
+        Error::ReqwestMessage(message)
+    } // fn
+    /// Wraps a HTTP client timeout in a Google Maps Distance Matrix API
+    /// error type. This function is required by the shared `get` method.
+    fn timeout(error: reqwest::Error) -> Error {
+        Error::Timeout(error)
+    } // fn
 } // impl
\ No newline at end of file
@@ -100,6 +100,7 @@ pub use crate::directions::{
     response::{
         directions_distance::DirectionsDistance,
         directions_duration::DirectionsDuration,
+        distance::Distance,
         driving_maneuver::DrivingManeuver,
         leg::Leg,
         overview_polyline::OverviewPolyline,
@@ -0,0 +1,187 @@
+use chrono::NaiveDateTime;
+use crate::client::GoogleMapsClient;
+use crate::directions::request::{
+    avoid::Avoid,
+    departure_time::DepartureTime,
+    traffic_model::TrafficModel,
+    transit_mode::TransitMode,
+    transit_route_preference::TransitRoutePreference,
+    unit_system::UnitSystem,
+    waypoint::Waypoint,
+}; // crate::directions::request
+use crate::directions::travel_mode::TravelMode;
+use crate::distance_matrix::request::Request;
+use crate::types::{Language, Region};
+use serde::{Deserialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+//
+/// The serializable parameters of a `Request`. A `Request` holds a reference to
+/// its client, so it can not be deserialized on its own. The parameters are
+/// deserialized into this struct first, and then bound to a client.
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Parameters {
+    destinations: Vec<Waypoint>,
+    origins: Vec<Waypoint>,
+    #[serde(default)]
+    arrival_time: Option<NaiveDateTime>,
+    #[serde(default)]
+    departure_time: Option<DepartureTime>,
+    #[serde(default)]
+    language: Option<Language>,
+    #[serde(default)]
+    region: Option<Region>,
+    #[serde(default)]
+    restrictions: Option<Vec<Avoid>>,
+    #[serde(default)]
+    traffic_model: Option<TrafficModel>,
+    #[serde(default)]
+    transit_modes: Option<Vec<TransitMode>>,
+    #[serde(default)]
+    transit_route_preference: Option<TransitRoutePreference>,
+    #[serde(default)]
+    travel_mode: Option<TravelMode>,
+    #[serde(default)]
+    unit_system: Option<UnitSystem>,
+    #[serde(default)]
+    auto_chunking: bool,
+} // struct
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Deserializes a request's parameters, such as those previously saved
+    /// by serializing a `Request`, and binds them to a client.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ The client that the request will be sent with.
+    ///
+    /// * `deserializer` ‧ Any `serde` deserializer, for example a
+    /// `serde_json::Deserializer`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let json = serde_json::to_string(&google_maps_client.distance_matrix(
+    ///     vec![Waypoint::Address(String::from("Toronto, ON"))],
+    ///     vec![Waypoint::Address(String::from("Montreal, QC"))],
+    /// ).with_travel_mode(TravelMode::Driving))?;
+    ///
+    /// let distance_matrix = DistanceMatrixRequest::deserialize_with_client(
+    ///     &google_maps_client,
+    ///     &mut serde_json::Deserializer::from_str(&json),
+    /// )?
+    /// .execute()
+    /// .await?;
+    /// ```
+
+    pub fn deserialize_with_client<'de, D: Deserializer<'de>>(
+        client: &'a GoogleMapsClient,
+        deserializer: D,
+    ) -> Result<Request<'a>, D::Error> {
+
+        let parameters = Parameters::deserialize(deserializer)?;
+
+        Ok(Request {
+            // Required parameters:
+            client,
+            destinations: parameters.destinations,
+            origins: parameters.origins,
+            // Optional parameters:
+            arrival_time: parameters.arrival_time,
+            departure_time: parameters.departure_time,
+            language: parameters.language.or_else(|| client.default_language.clone()),
+            region: parameters.region.or_else(|| client.default_region.clone()),
+            restrictions: parameters.restrictions,
+            traffic_model: parameters.traffic_model,
+            transit_modes: parameters.transit_modes,
+            transit_route_preference: parameters.transit_route_preference,
+            travel_mode: parameters.travel_mode,
+            unit_system: parameters.unit_system,
+            auto_chunking: parameters.auto_chunking,
+            // Internal use only:
+            billing_tag: None,
+            query: None,
+            validated: false,
+        }) // Request
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn request(client: &GoogleMapsClient) -> Request<'_> {
+        let mut request = client.distance_matrix(
+            vec![Waypoint::Address(String::from("Toronto, ON"))],
+            vec![Waypoint::PlaceId(String::from("ChIJpTvG15DL1IkRd8S0KlBVNTI"))],
+        ); // distance_matrix
+        request.arrival_time = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .and_then(|date| date.and_hms_opt(9, 0, 0));
+        request.departure_time = Some(DepartureTime::Now);
+        request.language = Some(Language::French);
+        request.region = Some(Region::Canada);
+        request.restrictions = Some(vec![Avoid::Tolls, Avoid::Ferries]);
+        request.traffic_model = Some(TrafficModel::Pessimistic);
+        request.transit_modes = Some(vec![TransitMode::Bus, TransitMode::Rail]);
+        request.transit_route_preference = Some(TransitRoutePreference::LessWalking);
+        request.travel_mode = Some(TravelMode::Transit);
+        request.unit_system = Some(UnitSystem::Imperial);
+        request.auto_chunking = true;
+        request
+    } // fn
+
+    #[test]
+    fn round_trips_every_parameter() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let json = serde_json::to_value(request(&client)).unwrap();
+        // Every parameter is populated, so that a parameter that is missing
+        // from `Parameters` is rejected as an unknown field:
+        assert!(
+            json.as_object().unwrap().values().all(|value| !value.is_null()),
+            "a parameter is not populated: {json}",
+        ); // assert!
+        let restored = Request::deserialize_with_client(&client, json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(restored).unwrap(), json);
+    } // fn
+
+    #[test]
+    fn rejects_unknown_parameters() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let mut json = serde_json::to_value(request(&client)).unwrap();
+        json["unknown"] = serde_json::Value::Bool(true);
+        assert!(Request::deserialize_with_client(&client, json).is_err());
+    } // fn
+
+    #[test]
+    fn falls_back_to_the_client_defaults() {
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client.with_default_language(Language::German).with_default_region(Region::Germany);
+        let client = client.build();
+        let saved = request(&client);
+        let mut json = serde_json::to_value(&saved).unwrap();
+        // A saved language and region take precedence over the defaults:
+        let restored = Request::deserialize_with_client(&client, json.clone()).unwrap();
+        assert_eq!((restored.language, restored.region), (saved.language, saved.region));
+        // A request saved without them gets the client's defaults, just like a
+        // new request:
+        json.as_object_mut().unwrap().remove("language");
+        json.as_object_mut().unwrap().remove("region");
+        let restored = Request::deserialize_with_client(&client, json).unwrap();
+        assert_eq!(restored.language, Some(Language::German));
+        assert_eq!(restored.region, Some(Region::Germany));
+    } // fn
+
+} // mod
@@ -2,8 +2,23 @@ use crate::error::Error as GoogleMapsError;
 use crate::distance_matrix::{
     request::Request as DistanceMatrixRequest,
     response::Response as DistanceMatrixResponse,
+    response::row::Row,
+    response::status::Status as DistanceMatrixStatus,
 }; // crate::distance_matrix
 
+// -----------------------------------------------------------------------------
+
+/// Google's _Distance Matrix_ service accepts at most 25 origins per request.
+const MAX_ORIGINS: usize = 25;
+
+/// Google's _Distance Matrix_ service accepts at most 25 destinations per
+/// request.
+const MAX_DESTINATIONS: usize = 25;
+
+/// Google's _Distance Matrix_ service accepts at most 100 elements (origins ×
+/// destinations) per request.
+const MAX_ELEMENTS: usize = 100;
+
 // =============================================================================
 
 impl<'a> DistanceMatrixRequest<'a> {
@@ -18,6 +33,13 @@ impl<'a> DistanceMatrixRequest<'a> {
     /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
     /// builder pattern.
     ///
+    /// If auto chunking has been enabled using `with_auto_chunking(true)`, and
+    /// the request exceeds Google's limits of 25 origins, 25 destinations, or
+    /// 100 elements, this method splits it into several requests and
+    /// reassembles the results into a single response. The rows, elements,
+    /// and addresses are in the same order as the origins and destinations
+    /// that were passed in.
+    ///
     /// ## Arguments:
     ///
     /// This method accepts no arguments.
@@ -26,8 +48,184 @@ impl<'a> DistanceMatrixRequest<'a> {
         &'a mut self
     ) -> Result<DistanceMatrixResponse, GoogleMapsError> {
 
-        self.validate()?.build()?.get().await
+        let origins_len = self.origins.len();
+        let destinations_len = self.destinations.len();
+
+        if !self.auto_chunking
+            || (origins_len <= MAX_ORIGINS
+                && destinations_len <= MAX_DESTINATIONS
+                && origins_len * destinations_len <= MAX_ELEMENTS) {
+            self.validate()?.build()?.get().await
+        } else {
+            self.execute_in_chunks().await
+        } // if
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes a request that exceeds Google's limits, by submitting every
+    /// combination of an origin chunk and a destination chunk.
+    ///
+    /// Each chunk is sent using a copy of the request, so that this request is
+    /// left untouched if a chunk fails or the future is dropped.
+
+    async fn execute_in_chunks(
+        &self
+    ) -> Result<DistanceMatrixResponse, GoogleMapsError> {
+
+        let origins_len = self.origins.len();
+        let destinations_len = self.destinations.len();
+
+        // Work out how many origins and destinations may be sent in each chunk
+        // while staying within all of Google's limits:
+        let destinations_per_chunk = destinations_len.clamp(1, MAX_DESTINATIONS);
+        let origins_per_chunk = (MAX_ELEMENTS / destinations_per_chunk).clamp(1, MAX_ORIGINS);
+
+        // Submit every combination of origin chunk and destination chunk:
+        let origins = &self.origins;
+        let destinations = &self.destinations;
+
+        let mut origin_addresses: Vec<String> = Vec::with_capacity(origins_len);
+        let mut destination_addresses: Vec<String> = Vec::with_capacity(destinations_len);
+        let mut rows: Vec<Row> = Vec::with_capacity(origins_len);
+
+        for (origin_chunk_index, origin_chunk) in origins.chunks(origins_per_chunk).enumerate() {
+
+            // One row per origin. The elements from each destination chunk are
+            // appended to the rows in order:
+            let mut chunk_rows: Vec<Row> = vec![Row { elements: Vec::new() }; origin_chunk.len()];
+
+            for (destination_chunk_index, destination_chunk) in destinations.chunks(destinations_per_chunk).enumerate() {
+
+                let mut request = self.clone();
+                request.origins = origin_chunk.to_vec();
+                request.destinations = destination_chunk.to_vec();
+                let response = request.validate()?.build()?.get().await?;
+
+                // The origin addresses are the same for every destination
+                // chunk, so only keep them once:
+                if destination_chunk_index == 0 {
+                    origin_addresses.extend(response.origin_addresses);
+                } // if
+
+                // The destination addresses are the same for every origin
+                // chunk, so only keep them once:
+                if origin_chunk_index == 0 {
+                    destination_addresses.extend(response.destination_addresses);
+                } // if
+
+                for (chunk_row, row) in chunk_rows.iter_mut().zip(response.rows) {
+                    chunk_row.elements.extend(row.elements);
+                } // for
+
+            } // for
+
+            rows.extend(chunk_rows);
+
+        } // for
+
+        Ok(DistanceMatrixResponse {
+            destination_addresses,
+            error_message: None,
+            origin_addresses,
+            rows,
+            status: DistanceMatrixStatus::Ok,
+        }) // DistanceMatrixResponse
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, and returns the raw JSON response body
+    /// along with the response.
+    ///
+    /// ## Description:
+    ///
+    /// Works like `execute`, but also returns the response body exactly as
+    /// Google sent it. Fields that this crate does not model yet are dropped
+    /// from the deserialized response, but remain in the raw body. This is
+    /// useful for debugging, or for reading fields that Google has recently
+    /// added.
+    ///
+    /// Unlike `execute`, the request is never split into chunks, since a
+    /// single raw body could not describe the merged response. Requests that
+    /// exceed Google's per-request limits must be split by the caller.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let (response, body) = request.execute_with_raw().await?;
+    /// ```
+
+    pub async fn execute_with_raw(
+        &'a mut self
+    ) -> Result<(DistanceMatrixResponse, String), GoogleMapsError> {
+
+        self.validate()?.build()?.get_with_raw().await
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::test_server::{block_on, TestServer};
+    use crate::client::{GoogleMapsClient, RetryPolicy};
+    use crate::directions::request::waypoint::Waypoint;
+    use crate::request_rate::api::Api;
+
+    /// Returns a client that sends _Distance Matrix API_ requests to the test
+    /// server, without retrying.
+    fn client(server: &TestServer) -> GoogleMapsClient {
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_endpoint_override(Api::DistanceMatrix, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none());
+        client.build()
+    } // fn
+
+    /// Returns the specified number of waypoints, named with a prefix.
+    fn waypoints(prefix: &str, count: usize) -> Vec<Waypoint> {
+        (0..count)
+            .map(|index| Waypoint::PlaceId(format!("{prefix}{index}")))
+            .collect()
+    } // fn
+
+    #[test]
+    fn request_is_split_within_limits() {
+        let server = TestServer::start(200, &[], r#"{
+            "destination_addresses": [], "origin_addresses": [], "rows": [], "status": "OK"
+        }"#);
+        let client = client(&server);
+        let request = client.distance_matrix(waypoints("o", 30), waypoints("d", 5));
+
+        assert!(block_on(request.execute_in_chunks()).is_ok());
+
+        // With 5 destinations, at most 20 origins fit within 100 elements:
+        let targets = server.targets();
+        assert_eq!(targets.len(), 2);
+        assert!(targets[0].contains("%3Ao19") && !targets[0].contains("%3Ao20"));
+        assert!(targets[1].contains("%3Ao20") && targets[1].contains("%3Ao29"));
+    } // fn
+
+    #[test]
+    fn failed_chunk_leaves_request_untouched() {
+        let server = TestServer::start(500, &[], "");
+        let client = client(&server);
+        let request = client.distance_matrix(waypoints("o", 30), waypoints("d", 5));
 
+        assert!(block_on(request.execute_in_chunks()).is_err());
+        assert_eq!(server.targets().len(), 1);
+        assert_eq!(request.origins, waypoints("o", 30));
+        assert_eq!(request.destinations, waypoints("d", 5));
     } // fn
 
-} // impl
\ No newline at end of file
+} // mod
@@ -4,6 +4,7 @@
 //! for building your Google Maps Platform request.
 
 mod build;
+mod deserialize_with_client;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
 #[cfg(feature = "enable-reqwest")]
@@ -12,6 +13,8 @@ mod new;
 mod query_url;
 mod validate;
 mod with_arrival_time;
+mod with_auto_chunking;
+mod with_billing_tag;
 mod with_departure_time;
 mod with_language;
 mod with_region;
@@ -37,14 +40,20 @@ use crate::directions::request::{
 use crate::client::GoogleMapsClient;
 use crate::directions::travel_mode::TravelMode;
 use crate::types::{Language, Region};
+use serde::Serialize;
 
 // -----------------------------------------------------------------------------
 //
 /// **Look at this `Request` struct for documentation on how to build your
 /// _Distance Matrix API_ query**. The methods implemented for this struct are
 /// what's used to build your request.
+///
+/// A request's parameters may be serialized, for example to save a search to
+/// disk. The client, and any query string that has already been built, are not
+/// serialized. Use `Request::deserialize_with_client` to load the parameters
+/// again.
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct Request<'a> {
 
     // Required parameters:
@@ -52,6 +61,7 @@ pub struct Request<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
+    #[serde(skip)]
     client: &'a GoogleMapsClient,
 
     /// One or more locations to use as the finishing point for calculating
@@ -107,14 +117,27 @@ pub struct Request<'a> {
     /// and method `with_unit_system()` for more information.
     unit_system: Option<UnitSystem>,
 
+    /// Whether requests that exceed Google's limits are automatically split
+    /// into several smaller requests. See method `with_auto_chunking()` for
+    /// more information.
+    auto_chunking: bool,
+
     // Internal use only:
     // ------------------
 
+    /// A caller-defined tag, such as a product feature, that is recorded in the
+    /// `billing_tag` field of the request's `tracing` span. It is never sent to
+    /// Google.
+    #[serde(skip)]
+    billing_tag: Option<String>,
+
     /// The URL-encoded query string that is passed to the Google Maps
     /// Directions API through cURL.
+    #[serde(skip)]
     query: Option<String>,
 
     /// Has the request been validated?
+    #[serde(skip)]
     validated: bool,
 
 } // struct
\ No newline at end of file
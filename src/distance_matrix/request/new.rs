@@ -31,15 +31,17 @@ impl<'a> Request<'a> {
             // Optional parameters:
             arrival_time: None,
             departure_time: None,
-            language: None,
-            region: None,
+            language: client.default_language.clone(),
+            region: client.default_region.clone(),
             restrictions: None,
             traffic_model: None,
             transit_modes: None,
             transit_route_preference: None,
             travel_mode: None,
             unit_system: None,
+            auto_chunking: false,
             // Internal use only:
+            billing_tag: None,
             query: None,
             validated: false,
         } // struct
@@ -0,0 +1,41 @@
+use crate::distance_matrix::request::Request;
+
+impl<'a> Request<'a> {
+
+    /// Specifies whether oversized requests are automatically split into
+    /// several smaller requests.
+    ///
+    /// ## Arguments
+    ///
+    /// * `auto_chunking` ‧ Whether to split requests that exceed Google's
+    /// limits.
+    ///
+    /// ## Description
+    ///
+    /// Google limits each _Distance Matrix_ request to 25 origins, 25
+    /// destinations, and 100 elements (origins × destinations). When auto
+    /// chunking is enabled and a request exceeds these limits, `execute()`
+    /// splits the origins and destinations into several requests that are
+    /// within the limits, and reassembles the results into a single response
+    /// with the rows and elements in the original order. The client's rate
+    /// limiting is observed for every request.
+    ///
+    /// Auto chunking is disabled by default, in which case oversized requests
+    /// are sent to Google as-is.
+    ///
+    /// ## Example:
+    ///
+    /// * Enable auto chunking:
+    /// ```rust
+    /// .with_auto_chunking(true)
+    /// ```
+
+    pub fn with_auto_chunking(
+        &'a mut self,
+        auto_chunking: bool
+    ) -> &'a mut Request {
+        self.auto_chunking = auto_chunking;
+        self
+    } // fn
+
+} // impl
@@ -38,4 +38,17 @@ impl<'a> Request<'a> {
         self
     } // fn
 
+    /// Specifies the region bias. This is an alias for `with_region`.
+    ///
+    /// ## Example:
+    ///
+    /// * Bias region to Canada:
+    /// ```rust
+    /// .with_region_bias(Region::Canada)
+    /// ```
+
+    pub fn with_region_bias(&'a mut self, region: Region) -> &'a mut Request {
+        self.with_region(region)
+    } // fn
+
 } // impl
\ No newline at end of file
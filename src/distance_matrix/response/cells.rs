@@ -0,0 +1,165 @@
+use crate::distance_matrix::response::{element::Element, Response};
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the element for the specified origin and destination pairing.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `origin_idx` ‧ The index of the origin, in the order that the origins
+    /// were passed to the request.
+    ///
+    /// * `destination_idx` ‧ The index of the destination, in the order that
+    /// the destinations were passed to the request.
+    ///
+    /// ## Description:
+    ///
+    /// `None` is returned if either index is out of bounds of the response's
+    /// `origin_addresses` or `destination_addresses`, or if Google did not
+    /// return an element for the pairing.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// // The distance from the first origin to the third destination:
+    /// let element = distance_matrix.get(0, 2);
+    /// ```
+
+    pub fn get(&self, origin_idx: usize, destination_idx: usize) -> Option<&Element> {
+        if origin_idx >= self.origin_addresses.len()
+            || destination_idx >= self.destination_addresses.len() {
+            return None;
+        } // if
+        self.rows
+            .get(origin_idx)?
+            .elements
+            .get(destination_idx)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns an iterator over every element in the matrix, along with the
+    /// index of its origin and destination.
+    ///
+    /// ## Description:
+    ///
+    /// The iterator yields `(origin_idx, destination_idx, &Element)` tuples,
+    /// row by row. The indices are in the order that the origins and
+    /// destinations were passed to the request, which makes it easy to pair
+    /// the results back up with the request's inputs. Only pairings within
+    /// the bounds of the response's `origin_addresses` and
+    /// `destination_addresses` are yielded.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// for (origin_idx, destination_idx, element) in distance_matrix.iter_cells() {
+    ///     println!("{} → {}: {:?}", origins[origin_idx], destinations[destination_idx], element.distance);
+    /// }
+    /// ```
+
+    pub fn iter_cells(&self) -> impl Iterator<Item = (usize, usize, &Element)> {
+        let origins_len = self.origin_addresses.len();
+        let destinations_len = self.destination_addresses.len();
+        self.rows
+            .iter()
+            .take(origins_len)
+            .enumerate()
+            .flat_map(move |(origin_idx, row)| row.elements
+                .iter()
+                .take(destinations_len)
+                .enumerate()
+                .map(move |(destination_idx, element)| (origin_idx, destination_idx, element))
+            ) // flat_map
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> IntoIterator for &'a Response {
+    type Item = (usize, usize, &'a Element);
+    type IntoIter = Box<dyn Iterator<Item = (usize, usize, &'a Element)> + 'a>;
+    /// Iterates over every element in the matrix, along with the index of its
+    /// origin and destination, row by row. See `Response::iter_cells` for more
+    /// information.
+    ///
+    /// ```rust
+    /// for (origin_idx, destination_idx, element) in &distance_matrix {
+    ///     println!("{origin_idx} → {destination_idx}: {:?}", element.distance);
+    /// }
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter_cells())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    /// Returns a matrix whose element for origin `i` and destination `j` is
+    /// `100 × i + j` meters long.
+    fn matrix(origins: usize, destinations: usize) -> Response {
+        let rows: Vec<serde_json::Value> = (0..origins)
+            .map(|origin_idx| json!({
+                "elements": (0..destinations)
+                    .map(|destination_idx| json!({
+                        "distance": { "text": "", "value": 100 * origin_idx + destination_idx },
+                        "duration": { "text": "", "value": 60 },
+                        "status": "OK",
+                    })) // map
+                    .collect::<Vec<serde_json::Value>>(),
+            })) // map
+            .collect();
+        serde_json::from_value(json!({
+            "destination_addresses": vec![""; destinations],
+            "origin_addresses": vec![""; origins],
+            "rows": rows,
+            "status": "OK",
+        })).unwrap() // from_value
+    } // fn
+
+    fn meters(element: &Element) -> u32 {
+        element.distance.as_ref().unwrap().value.as_meters()
+    } // fn
+
+    #[test]
+    fn iterates_in_row_major_order() {
+        let response = matrix(2, 3);
+        let mut count = 0;
+        let mut expected = (0..2)
+            .flat_map(|origin_idx| (0..3).map(move |destination_idx| (origin_idx, destination_idx)));
+        for (origin_idx, destination_idx, element) in &response {
+            assert_eq!(Some((origin_idx, destination_idx)), expected.next());
+            assert_eq!(meters(element), u32::try_from(100 * origin_idx + destination_idx).unwrap());
+            count += 1;
+        } // for
+        assert_eq!(count, 2 * 3);
+        assert_eq!(expected.next(), None);
+    } // fn
+
+    #[test]
+    fn get_matches_iteration() {
+        let response = matrix(3, 2);
+        for (origin_idx, destination_idx, element) in response.iter_cells() {
+            assert_eq!(response.get(origin_idx, destination_idx), Some(element));
+        } // for
+        assert_eq!(response.get(3, 0), None);
+        assert_eq!(response.get(0, 2), None);
+    } // fn
+
+    #[test]
+    fn empty_matrix() {
+        assert_eq!((&matrix(0, 0)).into_iter().count(), 0);
+    } // fn
+
+} // mod
@@ -40,3 +40,36 @@ pub struct Element {
     /// for a list of possible status codes.
     pub status: ElementStatus,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl Element {
+
+    /// Returns `true` if Google was able to calculate a route for this
+    /// origin-destination pairing. Individual elements may fail, with a status
+    /// such as `NOT_FOUND` or `ZERO_RESULTS`, even when the overall request
+    /// succeeded.
+    /// ```rust
+    /// let is_ok = element.is_ok();
+    /// ```
+
+    pub fn is_ok(&self) -> bool {
+        self.status == ElementStatus::Ok
+    } // fn
+
+    /// Returns the distance of this origin-destination pairing in meters. If
+    /// the element's status is not `OK`, this function will return `None` so
+    /// that a failed pairing cannot be mistaken for a zero-distance pairing.
+    /// ```rust
+    /// let distance_meters = element.distance_meters();
+    /// ```
+
+    pub fn distance_meters(&self) -> Option<u32> {
+        if self.is_ok() {
+            self.distance.as_ref().map(|distance| distance.value.as_meters())
+        } else {
+            None
+        } // if
+    } // fn
+
+} // impl
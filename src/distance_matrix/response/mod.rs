@@ -5,12 +5,13 @@
 //! API_. If you're not finding what you're looking for in this module, check
 //! out the Directions modules also.
 
+mod cells;
 pub mod element;
 pub mod element_status;
 pub mod row;
 pub mod status;
 
-use crate::distance_matrix::response::{row::Row, status::Status};
+use crate::distance_matrix::response::{element_status::ElementStatus, row::Row, status::Status};
 use serde::{Deserialize, Serialize};
 
 /// Distance Matrix responses contain the following root elements.
@@ -52,4 +53,58 @@ impl std::str::FromStr for Response {
     fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
         serde_json::from_str(s)
     }
-}
\ No newline at end of file
+}
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Response {
+    /// Formats a concise, one-line summary of the Distance Matrix response for
+    /// logging: the status, the size of the matrix, and how many of its
+    /// elements were successfully calculated. Use `{:#?}` to see the entire
+    /// response.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let elements = self.rows.iter().flat_map(|row| row.elements.iter());
+        let total = elements.clone().count();
+        let ok = elements
+            .filter(|element| element.status == ElementStatus::Ok)
+            .count();
+        write!(f,
+            "Distance Matrix {}: {} origin(s) × {} destination(s), {ok} of {total} element(s) OK",
+            self.status,
+            self.origin_addresses.len(),
+            self.destination_addresses.len(),
+        ) // write!
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn display_counts_the_calculated_elements() {
+        let element = json!({
+            "distance": { "text": "541 km", "value": 541_000 },
+            "duration": { "text": "5 hours 23 mins", "value": 19_380 },
+            "status": "OK",
+        }); // json!
+        let response: Response = serde_json::from_value(json!({
+            "destination_addresses": ["Montréal, QC, Canada", "Ottawa, ON, Canada", "Kingston, ON, Canada"],
+            "origin_addresses": ["Toronto, ON, Canada", "Honolulu, HI, USA"],
+            "rows": [
+                { "elements": [element, element, element] },
+                { "elements": [{ "status": "ZERO_RESULTS" }, { "status": "ZERO_RESULTS" }, element] },
+            ],
+            "status": "OK",
+        })).unwrap(); // from_value
+        assert_eq!(
+            response.to_string(),
+            "Distance Matrix OK: 2 origin(s) × 3 destination(s), 4 of 6 element(s) OK",
+        ); // assert_eq!
+    } // fn
+
+} // mod
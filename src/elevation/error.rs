@@ -13,8 +13,14 @@ use thiserror::Error;
 #[derive(Debug, Diagnostic, Error)]
 #[diagnostic(code(google_maps::elevation::error), url(docsrs))]
 pub enum Error {
-    /// A sampled_path_request() method cannot be used when postional_request()
-    /// has been set.
+    /// A `Locations` list must contain at least one location. For example,
+    /// this error is returned when attempting to convert an empty `geo`
+    /// `LineString` into `Locations`.
+    EmptyLocations,
+    /// A request cannot be both a positional request and a sampled path
+    /// request. This error is returned by `validate()`, before the request is
+    /// sent, if `for_positional_request()` (or `for_line_string_request()`) and
+    /// `for_sampled_path_request()` have both been used on the same request.
     EitherPositionalOrSampledPath,
     /// Google Maps Elevation API server generated an error. See the `Status`
     /// enum for more information.
@@ -25,6 +31,15 @@ pub enum Error {
     /// status code. See `google_maps\src\elevation\response\status.rs` for
     /// more information.
     InvalidStatusCode(String),
+    /// A coordinate could not be used as a location because its latitude or
+    /// longitude is `NaN` or infinite. The index of the coordinate, its
+    /// latitude, and its longitude are included.
+    NonFiniteCoordinate(usize, f64, f64),
+    /// Google Maps Elevation API server reported that the daily usage limit
+    /// has been exceeded, or that billing is not set up for the API key. This
+    /// error is never retried, as retrying will not help until the quota is
+    /// reset or the billing and quota settings are fixed.
+    OverDailyLimit(Option<String>),
     /// The query string must be built before the request may be sent to the
     /// Google Maps Elevation API server.
     QueryNotBuilt,
@@ -39,6 +54,17 @@ pub enum Error {
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
     SerdeJson(serde_json::error::Error),
+    /// The HTTP request to the Google Maps Elevation API server timed out. See
+    /// `GoogleMapsClient::with_timeout` and
+    /// `GoogleMapsClient::with_connect_timeout`. This error is eligible for
+    /// retries.
+    #[cfg(feature = "enable-reqwest")]
+    Timeout(reqwest::Error),
+    /// A sampled path request asked for more than the 512 samples that Google
+    /// accepts in a single request, and the request could not be split
+    /// automatically. Only paths specified as `Locations::LatLngs`, and sent
+    /// using the `execute()` method, are split.
+    TooManySamples(u16),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -48,9 +74,13 @@ impl std::fmt::Display for Error {
     /// to the user.
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
+            Error::EmptyLocations => write!(f,
+                "Google Maps Elevation API client: \
+                A list of locations must contain at least one location. \
+                Try again with a path or list that is not empty."),
             Error::EitherPositionalOrSampledPath => write!(f,
                 "Google Maps Elevation API client: \
-                A for_sampled_path_request() method cannot be used when for_postional_request() has been set. \
+                A for_sampled_path_request() method cannot be used when for_positional_request() has been set. \
                 Try again with only a positional request or only a sampled path request."),
             Error::GoogleMapsService(status, error_message) => match error_message {
                 // If the Google Maps Elevation API server generated an error
@@ -93,11 +123,32 @@ impl std::fmt::Display for Error {
                 "Google Maps Elevation API client: \
                 The request must be validated before a query string may be built. \
                 Ensure the validate() method is called before build()."),
+            Error::TooManySamples(samples) => write!(f,
+                "Google Maps Elevation API client: \
+                {samples} samples were requested but Google accepts at most 512 per request. \
+                Try again with a `Locations::LatLngs` path and the execute() method \
+                so that the request can be split automatically."),
             #[cfg(feature = "enable-reqwest")]
             Error::Reqwest(error) => write!(f, "Google Maps Elevation API client in the Reqwest library: {error}"),
             #[cfg(feature = "enable-reqwest")]
             Error::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
             Error::SerdeJson(error) => write!(f, "Google Maps Elevation API client in the Serde JSON library: {error}"),
+            #[cfg(feature = "enable-reqwest")]
+            Error::Timeout(error) => write!(f, "Google Maps Elevation API client: \
+                The request timed out: {error}"),
+            Error::NonFiniteCoordinate(index, lat, lng) => write!(f,
+                "Google Maps Elevation API client: \
+                Coordinate #{index} ({lat}, {lng}) is not a finite number. \
+                Try again with latitudes and longitudes that are not NaN or infinite."),
+            Error::OverDailyLimit(error_message) => match error_message {
+                Some(error_message) => write!(f, "Google Maps Elevation API service: \
+                    Over daily limit. {error_message} \
+                    Check the billing and quota settings for your API key in the Google Cloud Console."),
+                None => write!(f, "Google Maps Elevation API service: \
+                    Over daily limit. \
+                    Usage cap has been exceeded, API key is invalid, billing has not been enabled, or method of payment is no longer valid. \
+                    Check the billing and quota settings for your API key in the Google Cloud Console."),
+            }, // match
             Error::QueryNotBuilt => write!(f,
                 "Google Maps Elevation API client: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
@@ -129,4 +180,25 @@ impl From<serde_json::error::Error> for Error {
     fn from(error: serde_json::error::Error) -> Error {
         Error::SerdeJson(error)
     } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::client::ApiError for Error {
+    /// Wraps an unsuccessful HTTP status in a Google Maps Elevation API
+    /// error type. This function is required by the shared `get` method.
+    fn http_unsuccessful(status: String) -> Error {
+        Error::HttpUnsuccessful(status)
+    } // fn
+    /// Wraps a HTTP client error message in a Google Maps Elevation API
+    /// error type. This function is required by the shared `get` method.
+    fn reqwest_message(message: String) -> Error {
+        Error::ReqwestMessage(message)
+    } // fn
+    /// Wraps a HTTP client timeout in a Google Maps Elevation API
+    /// error type. This function is required by the shared `get` method.
+    fn timeout(error: reqwest::Error) -> Error {
+        Error::Timeout(error)
+    } // fn
 } // impl
\ No newline at end of file
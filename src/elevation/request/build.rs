@@ -2,6 +2,7 @@ use crate::elevation::{
     error::Error,
     request::Request,
 }; // crate::elevation
+use crate::query_string::QueryString;
 
 impl<'a> Request<'a> {
 
@@ -21,16 +22,14 @@ impl<'a> Request<'a> {
         // This section builds the "required parameters" portion of the query
         // string:
 
-        let mut query = String::from("key=");
-        query.push_str(&self.client.key);
+        let mut query = QueryString::new(&self.client.key);
 
         // This section builds the "positional request" portion of the query
         // string:
 
         // Locations key/value pair:
         if let Some(locations) = &self.locations {
-            query.push_str("&locations=");
-            query.push_str(&String::from(locations))
+            query.push_encoded("locations", &String::from(locations))
         } // if
 
         // This section builds the "sampled path request" portion of the query
@@ -38,18 +37,16 @@ impl<'a> Request<'a> {
 
         // Path key/value pair:
         if let Some(path) = &self.path {
-            query.push_str("&path=");
-            query.push_str(&String::from(path))
+            query.push_encoded("path", &String::from(path))
         } // if
 
         // Samples key/value pair:
         if let Some(samples) = &self.samples {
-            query.push_str("&samples=");
-            query.push_str(&samples.to_string());
+            query.push_display("samples", samples)
         } // if
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         Ok(self)
@@ -0,0 +1,119 @@
+use crate::client::GoogleMapsClient;
+use crate::elevation::request::{locations::Locations, Request};
+use serde::{Deserialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+//
+/// The serializable parameters of a `Request`. A `Request` holds a reference to
+/// its client, so it can not be deserialized on its own. The parameters are
+/// deserialized into this struct first, and then bound to a client.
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Parameters {
+    #[serde(default)]
+    locations: Option<Locations>,
+    #[serde(default)]
+    path: Option<Locations>,
+    #[serde(default)]
+    samples: Option<u16>,
+} // struct
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Deserializes a request's parameters, such as those previously saved
+    /// by serializing a `Request`, and binds them to a client.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ The client that the request will be sent with.
+    ///
+    /// * `deserializer` ‧ Any `serde` deserializer, for example a
+    /// `serde_json::Deserializer`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let json = serde_json::to_string(&google_maps_client.elevation()
+    ///     .for_positional_request(ElevationLocations::LatLngs(vec![
+    ///         LatLng::try_from_dec(dec!(39.7391536), dec!(-104.9847034))?,
+    ///     ])))?;
+    ///
+    /// let elevation = ElevationRequest::deserialize_with_client(
+    ///     &google_maps_client,
+    ///     &mut serde_json::Deserializer::from_str(&json),
+    /// )?
+    /// .execute()
+    /// .await?;
+    /// ```
+
+    pub fn deserialize_with_client<'de, D: Deserializer<'de>>(
+        client: &'a GoogleMapsClient,
+        deserializer: D,
+    ) -> Result<Request<'a>, D::Error> {
+
+        let parameters = Parameters::deserialize(deserializer)?;
+
+        Ok(Request {
+            // Required parameters:
+            client,
+            // Optional parameters:
+            locations: parameters.locations,
+            path: parameters.path,
+            samples: parameters.samples,
+            // Internal use only:
+            billing_tag: None,
+            query: None,
+            validated: false,
+        }) // Request
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::LatLng;
+    use rust_decimal_macros::dec;
+
+    fn request(client: &GoogleMapsClient) -> Request<'_> {
+        let mut request = client.elevation();
+        // A request is either positional or a sampled path, but every parameter is
+        // set here so that each is round-tripped:
+        request.locations = Some(Locations::LatLngs(vec![LatLng::try_from_dec(dec!(43.6532), dec!(-79.3832)).unwrap()]));
+        request.path = Some(Locations::Polyline(String::from("gfo}EtohhU")));
+        request.samples = Some(3);
+        request
+    } // fn
+
+    #[test]
+    fn round_trips_every_parameter() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let json = serde_json::to_value(request(&client)).unwrap();
+        // Every parameter is populated, so that a parameter that is missing
+        // from `Parameters` is rejected as an unknown field:
+        assert!(
+            json.as_object().unwrap().values().all(|value| !value.is_null()),
+            "a parameter is not populated: {json}",
+        ); // assert!
+        let restored = Request::deserialize_with_client(&client, json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(restored).unwrap(), json);
+    } // fn
+
+    #[test]
+    fn rejects_unknown_parameters() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let mut json = serde_json::to_value(request(&client)).unwrap();
+        json["unknown"] = serde_json::Value::Bool(true);
+        assert!(Request::deserialize_with_client(&client, json).is_err());
+    } // fn
+
+} // mod
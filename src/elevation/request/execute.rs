@@ -1,8 +1,26 @@
 use crate::error::Error as GoogleMapsError;
 use crate::elevation::{
-    request::Request as ElevationRequest,
+    request::{locations::Locations, MAX_SAMPLES, Request as ElevationRequest},
     response::Response as ElevationResponse,
+    response::point::Point,
+    response::status::Status as ElevationStatus,
 }; // crate::elevation
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+
+/// Google's _Elevation_ service accepts at most 512 locations per positional
+/// request.
+const MAX_LOCATIONS: usize = 512;
+
+/// Google's servers reject URLs longer than about 16,384 characters. The
+/// `locations` parameter of each positional request is kept to half of that to
+/// leave room for the rest of the URL and for percent-encoding.
+const MAX_LOCATIONS_LENGTH: usize = 8_192;
+
+/// A single request that a chunked request is split into: its `locations`, or
+/// its `path` and `samples`.
+type Chunk = (Option<Locations>, Option<Locations>, Option<u16>);
 
 // =============================================================================
 
@@ -18,6 +36,18 @@ impl<'a> ElevationRequest<'a> {
     /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
     /// builder pattern.
     ///
+    /// Requests that exceed Google's limits are split into several requests
+    /// and the results are concatenated, in order, into a single response:
+    ///
+    /// * A positional request with a `Locations::LatLngs` list of more than
+    /// 512 locations, or a list too long to fit in a URL, is sent in chunks.
+    ///
+    /// * A sampled path request with a `Locations::LatLngs` path and more than
+    /// 512 samples is split into consecutive sub-segments of the path, each
+    /// with up to 512 samples. The samples remain equidistant along the whole
+    /// path. The sub-segments' end points are interpolated between the path's
+    /// latitude/longitude pairs.
+    ///
     /// ## Arguments:
     ///
     /// This method accepts no arguments.
@@ -26,8 +56,313 @@ impl<'a> ElevationRequest<'a> {
         &'a mut self
     ) -> Result<ElevationResponse, GoogleMapsError> {
 
-        self.validate()?.build()?.get().await
+        let chunks: Vec<Chunk> = match (&self.locations, &self.path, self.samples) {
+            (Some(Locations::LatLngs(latlngs)), None, _) if latlngs.len() > 1 => {
+                let chunks = chunk_locations(latlngs);
+                if chunks.len() < 2 {
+                    return self.validate()?.build()?.get().await;
+                } // if
+                chunks
+                    .into_iter()
+                    .map(|chunk| (Some(Locations::LatLngs(chunk)), None, None))
+                    .collect()
+            }, // Some
+            (None, Some(Locations::LatLngs(path)), Some(samples)) if samples > MAX_SAMPLES && path.len() > 1 =>
+                split_path(path, samples)?
+                    .into_iter()
+                    .map(|(path, samples)| (None, Some(Locations::LatLngs(path)), Some(samples)))
+                    .collect(),
+            _ => return self.validate()?.build()?.get().await,
+        }; // match
+
+        self.execute_chunks(chunks).await
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Submits each of the chunks that a request has been split into, in turn,
+    /// and concatenates their results.
+    ///
+    /// Each chunk is sent using a copy of the request, so that this request is
+    /// left untouched if a chunk fails or the future is dropped.
+
+    async fn execute_chunks(
+        &self,
+        chunks: Vec<Chunk>,
+    ) -> Result<ElevationResponse, GoogleMapsError> {
+
+        let mut results: Vec<Point> = Vec::new();
+
+        for (chunk_locations, chunk_path, chunk_samples) in chunks {
+            let mut request = self.clone();
+            request.locations = chunk_locations;
+            request.path = chunk_path;
+            request.samples = chunk_samples;
+            let response = request.validate()?.build()?.get().await?;
+            results.extend(response.results.unwrap_or_default());
+        } // for
+
+        Ok(ElevationResponse {
+            error_message: None,
+            results: Some(results),
+            status: ElevationStatus::Ok,
+        }) // ElevationResponse
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, and returns the raw JSON response body
+    /// along with the response.
+    ///
+    /// ## Description:
+    ///
+    /// Works like `execute`, but also returns the response body exactly as
+    /// Google sent it. Fields that this crate does not model yet are dropped
+    /// from the deserialized response, but remain in the raw body. This is
+    /// useful for debugging, or for reading fields that Google has recently
+    /// added.
+    ///
+    /// Unlike `execute`, the request is never split into chunks, since a
+    /// single raw body could not describe the merged response. Requests that
+    /// exceed Google's per-request limits must be split by the caller.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let (response, body) = request.execute_with_raw().await?;
+    /// ```
+
+    pub async fn execute_with_raw(
+        &'a mut self
+    ) -> Result<(ElevationResponse, String), GoogleMapsError> {
+
+        self.validate()?.build()?.get_with_raw().await
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Splits a list of locations into chunks that each respect Google's limits
+/// on the number of locations, and on the length of the `locations` parameter.
+
+fn chunk_locations(latlngs: &[LatLng]) -> Vec<Vec<LatLng>> {
+
+    let mut chunks: Vec<Vec<LatLng>> = Vec::new();
+    let mut chunk: Vec<LatLng> = Vec::new();
+    let mut chunk_length: usize = 0;
+
+    for latlng in latlngs {
+        // Each location is separated from the next by a pipe:
+        let latlng_length = String::from(latlng).len() + 1;
+        if !chunk.is_empty()
+            && (chunk.len() == MAX_LOCATIONS || chunk_length + latlng_length > MAX_LOCATIONS_LENGTH) {
+            chunks.push(std::mem::take(&mut chunk));
+            chunk_length = 0;
+        } // if
+        chunk_length += latlng_length;
+        chunk.push(latlng.clone());
+    } // for
+
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    } // if
+
+    chunks
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Splits a path into consecutive sub-segments, each with no more than
+/// `MAX_SAMPLES` samples. The sub-segments are chosen so that, together, their
+/// samples are the same equidistant points that Google would return for the
+/// whole path.
+
+fn split_path(
+    path: &[LatLng],
+    samples: u16,
+) -> Result<Vec<(Vec<LatLng>, u16)>, GoogleMapsError> {
+
+    let points: Vec<(f64, f64)> = path
+        .iter()
+        .map(|latlng| (latlng.lat_f64(), latlng.lng_f64()))
+        .collect();
+
+    // The distance from the start of the path to each of its points:
+    let mut cumulative: Vec<f64> = Vec::with_capacity(path.len());
+    cumulative.push(0.0);
+    for pair in path.windows(2) {
+        let previous = cumulative.last().copied().unwrap_or_default();
+        cumulative.push(previous + pair[0].haversine_distance_meters(&pair[1]));
+    } // for
+
+    let total = cumulative.last().copied().unwrap_or_default();
+    let sample_distance = |index: u16| total * f64::from(index) / f64::from(samples - 1);
+
+    // Spread the samples as evenly as possible over the fewest sub-segments.
+    // Every sub-segment has at least two samples, its start and end points:
+    let segments = samples.div_ceil(MAX_SAMPLES);
+    let mut first: u16 = 0;
+    let mut sub_paths: Vec<(Vec<LatLng>, u16)> = Vec::with_capacity(segments.into());
+
+    for segment in 0..segments {
+
+        let segment_samples = samples / segments + u16::from(segment < samples % segments);
+        let last = first + segment_samples - 1;
+        let (start, end) = (sample_distance(first), sample_distance(last));
+
+        let mut sub_path: Vec<LatLng> = vec![point_at(&points, &cumulative, start)?];
+        sub_path.extend(
+            path.iter()
+                .zip(&cumulative)
+                .filter(|(_latlng, distance)| **distance > start && **distance < end)
+                .map(|(latlng, _distance)| latlng.clone())
+        ); // extend
+        sub_path.push(point_at(&points, &cumulative, end)?);
+
+        sub_paths.push((sub_path, segment_samples));
+        first = last + 1;
+
+    } // for
+
+    Ok(sub_paths)
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Returns the point that is the specified distance, in meters, along a path.
+/// The point is linearly interpolated between the path's two nearest points.
+
+fn point_at(
+    points: &[(f64, f64)],
+    cumulative: &[f64],
+    distance: f64,
+) -> Result<LatLng, GoogleMapsError> {
+
+    // Find the segment of the path that the distance falls within:
+    let index = cumulative
+        .iter()
+        .rposition(|start| *start <= distance)
+        .unwrap_or_default()
+        .min(points.len() - 2);
+
+    let segment_length = cumulative[index + 1] - cumulative[index];
+    let fraction = if segment_length > 0.0 {
+        ((distance - cumulative[index]) / segment_length).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }; // if
+
+    let (lat_1, lng_1) = points[index];
+    let (lat_2, lng_2) = points[index + 1];
+
+    // Round to 7 decimal places (about 1 cm) to keep the URL short:
+    let round = |degrees: f64| (degrees * 1e7).round() / 1e7;
+
+    LatLng::try_from_f64(
+        round(lat_1 + (lat_2 - lat_1) * fraction),
+        round(lng_1 + (lng_2 - lng_1) * fraction),
+    )
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::client::test_server::{block_on, TestServer};
+    use crate::client::{GoogleMapsClient, RetryPolicy};
+    use crate::request_rate::api::Api;
+
+    /// Returns the specified number of points, heading east along a line of
+    /// latitude.
+    fn latlngs(count: usize) -> Vec<LatLng> {
+        (0..count)
+            .map(|index| LatLng::try_from_f64(45.0, -75.0 + index as f64 / 1_000.0).unwrap())
+            .collect()
+    } // fn
+
+    #[test]
+    fn locations_are_chunked_in_order() {
+        let latlngs = latlngs(1_100);
+        let chunks = chunk_locations(&latlngs);
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks.iter().all(|chunk| chunk.len() <= MAX_LOCATIONS));
+        assert_eq!(chunks.concat(), latlngs);
+    } // fn
+
+    #[test]
+    fn long_locations_are_chunked_by_length() {
+        let latlngs: Vec<LatLng> = (0..500)
+            .map(|index| LatLng::try_from_f64(
+                -45.123_456 - index as f64 / 1_000_000.0,
+                -175.123_456,
+            ).unwrap())
+            .collect();
+        let chunks = chunk_locations(&latlngs);
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            let length: usize = chunk.iter().map(|latlng| String::from(latlng).len() + 1).sum();
+            assert!(length <= MAX_LOCATIONS_LENGTH);
+        } // for
+        assert_eq!(chunks.concat(), latlngs);
+    } // fn
+
+    #[test]
+    fn path_is_split_into_sub_segments() {
+        let path = latlngs(3);
+        let sub_paths = split_path(&path, 1_000).unwrap();
+
+        // 1,000 samples need two sub-segments of 500 samples each:
+        assert_eq!(
+            sub_paths.iter().map(|(_path, samples)| *samples).collect::<Vec<u16>>(),
+            vec![500, 500],
+        );
+
+        // The sub-segments start and end at the path's ends:
+        assert!(sub_paths[0].0[0].approx_eq(&path[0], 0.5));
+        assert!(sub_paths[1].0.last().unwrap().approx_eq(&path[2], 0.5));
+
+        // The second sub-segment starts one sample after the first ends. The
+        // samples are equidistant, about 0.16 m apart over 157 m:
+        let gap = sub_paths[0].0.last().unwrap()
+            .haversine_distance_meters(&sub_paths[1].0[0]);
+        let spacing = path[0].haversine_distance_meters(&path[2]) / 999.0;
+        assert!((gap - spacing).abs() < 0.05);
+    } // fn
+
+    #[test]
+    fn failed_chunk_leaves_request_untouched() {
+        let server = TestServer::start(500, &[], "");
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_endpoint_override(Api::Elevation, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none());
+        let client = client.build();
+
+        let mut request = client.elevation();
+        request.locations = Some(Locations::LatLngs(latlngs(600)));
+        let chunks: Vec<Chunk> = chunk_locations(&latlngs(600))
+            .into_iter()
+            .map(|chunk| (Some(Locations::LatLngs(chunk)), None, None))
+            .collect();
 
+        assert!(block_on(request.execute_chunks(chunks)).is_err());
+        assert_eq!(server.targets().len(), 1);
+        assert_eq!(request.locations, Some(Locations::LatLngs(latlngs(600))));
+        assert_eq!(request.path, None);
+        assert_eq!(request.samples, None);
     } // fn
 
-} // impl
\ No newline at end of file
+} // mod
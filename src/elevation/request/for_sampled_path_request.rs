@@ -1,4 +1,8 @@
 use crate::elevation::request::{locations::Locations, Request};
+#[cfg(feature = "geo")]
+use crate::{elevation::error::Error as ElevationError, types::Error as TypeError, LatLng};
+#[cfg(feature = "geo")]
+use rust_decimal::{Decimal, prelude::FromPrimitive};
 
 // =============================================================================
 
@@ -20,6 +24,12 @@ impl<'a> Request<'a> {
     /// which to return elevation data. The samples parameter divides the given
     /// path into an ordered set of equidistant points along the path.
     ///
+    /// Google accepts at most 512 samples per request. If more samples are
+    /// requested and the path is a `Locations::LatLngs`, the `execute()`
+    /// method splits the path into sub-segments, requests each one
+    /// separately, and concatenates the results in order. Otherwise, more
+    /// than 512 samples will fail validation.
+    ///
     /// ## Examples:
     ///
     /// * 2 elevation samples between two points:
@@ -44,11 +54,21 @@ impl<'a> Request<'a> {
     ///     4
     /// )
     /// ```
+    ///
+    /// * 10 elevation samples along a `geo` crate `LineString` (requires the
+    /// `geo` feature):
+    /// ```rust
+    /// .for_sampled_path_request(
+    ///     Locations::try_from(&line_string)?,
+    ///     // Number of samples
+    ///     10
+    /// )
+    /// ```
 
     pub fn for_sampled_path_request(
         &'a mut self,
         path: Locations,
-        samples: u8
+        samples: u16
     ) -> &'a mut Request {
         // Set the path in Request struct.
         self.path = Some(path);
@@ -84,4 +104,69 @@ impl<'a> Request<'a> {
         Ok(self)
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// Adds the _sampled path request_ parameters to the Elevation API query,
+    /// using a [geo](https://crates.io/crates/geo) crate
+    /// [LineString](https://docs.rs/geo/latest/geo/geometry/struct.LineString.html)
+    /// as the path.
+    ///
+    /// Each coordinate of the line string is converted into a `LatLng`, and
+    /// the path is sent as a `Locations::LatLngs`. This means that requests
+    /// for more than 512 samples can be split automatically by `execute()`.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `line_string` ‧ Defines a path on the earth for which to return
+    /// elevation data. The `x` of each coordinate is its longitude and the `y`
+    /// is its latitude.
+    ///
+    /// * `samples` ‧ Specifies the number of sample points along a path for
+    /// which to return elevation data. The samples parameter divides the given
+    /// path into an ordered set of equidistant points along the path.
+    ///
+    /// ## Errors:
+    ///
+    /// * `EmptyLocations` if the line string has no coordinates.
+    ///
+    /// * `NonFiniteCoordinate` if a coordinate is `NaN` or infinite.
+    ///
+    /// * A type error if a coordinate is not a valid latitude & longitude.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .sampled_path_from_linestring(&trail, 1_000)?
+    /// ```
+
+    #[cfg(feature = "geo")]
+    pub fn sampled_path_from_linestring(
+        &'a mut self,
+        line_string: &geo_types::LineString,
+        samples: u16,
+    ) -> Result<&'a mut Request, crate::error::Error> {
+
+        if line_string.0.is_empty() {
+            return Err(ElevationError::EmptyLocations)?;
+        } // if
+
+        let lat_lngs: Vec<LatLng> = line_string
+            .coords()
+            .enumerate()
+            .map(|(index, coordinate)| {
+                if !coordinate.x.is_finite() || !coordinate.y.is_finite() {
+                    return Err(ElevationError::NonFiniteCoordinate(index, coordinate.y, coordinate.x))?;
+                } // if
+                let lat = Decimal::from_f64(coordinate.y)
+                    .ok_or_else(|| TypeError::FloatToDecimalConversionError(coordinate.y.to_string()))?;
+                let lng = Decimal::from_f64(coordinate.x)
+                    .ok_or_else(|| TypeError::FloatToDecimalConversionError(coordinate.x.to_string()))?;
+                LatLng::try_from_dec(lat, lng)
+            }) // map
+            .collect::<Result<Vec<LatLng>, crate::error::Error>>()?;
+
+        Ok(self.for_sampled_path_request(Locations::LatLngs(lat_lngs), samples))
+
+    } // fn
+
 } // impl
\ No newline at end of file
@@ -1,7 +1,7 @@
 //! Provides some `Locations` conversion methods for the
 //! [geo](https://crates.io/crates/geo) crate.
 
-use crate::elevation::request::locations::Locations;
+use crate::elevation::{error::Error as ElevationError, request::locations::Locations};
 use crate::LatLng;
 use geo_types::geometry::{Line, LineString};
 
@@ -35,9 +35,14 @@ impl TryFrom<&LineString> for Locations {
     type Error = crate::error::Error;
 
     /// Attempts to convert a `geo_types::geometry::LineString` struct to a
-    /// `google_maps::elevation::Locations` struct.
+    /// `google_maps::elevation::Locations` struct. Each coordinate of the line
+    /// string becomes a location. An empty line string returns an error.
     fn try_from(line_string: &LineString) -> Result<Self, Self::Error> {
 
+        if line_string.0.is_empty() {
+            return Err(ElevationError::EmptyLocations)?;
+        } // if
+
         let lat_lngs: Vec<LatLng> = line_string
             .coords()
             .map(LatLng::try_from)
@@ -47,4 +52,59 @@ impl TryFrom<&LineString> for Locations {
 
     } // fn
 
-} // impl
\ No newline at end of file
+} // impl
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::client::GoogleMapsClient;
+    use geo_types::line_string;
+    use percent_encoding::percent_decode_str;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn line_string_to_sampled_path_request() {
+        // From Ottawa, through Kingston, to Toronto:
+        let line_string = line_string![
+            (x: -75.6972, y: 45.4215),
+            (x: -76.4860, y: 44.2312),
+            (x: -79.3832, y: 43.6532),
+        ]; // line_string!
+        let locations = Locations::try_from(&line_string).unwrap();
+        assert_eq!(locations, Locations::LatLngs(vec![
+            LatLng::try_from_dec(dec!(45.4215), dec!(-75.6972)).unwrap(),
+            LatLng::try_from_dec(dec!(44.2312), dec!(-76.4860)).unwrap(),
+            LatLng::try_from_dec(dec!(43.6532), dec!(-79.3832)).unwrap(),
+        ])); // assert_eq!
+
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let url = client
+            .elevation()
+            .for_sampled_path_request(locations, 10)
+            .query_url()
+            .unwrap();
+        let (_path, query) = url.split_once('?').unwrap();
+        let parameter = |name: &str| query
+            .split('&')
+            .find_map(|parameter| parameter.strip_prefix(name)?.strip_prefix('='))
+            .unwrap_or_else(|| panic!("`{name}` is missing from `{url}`"))
+            .to_string();
+        assert_eq!(
+            percent_decode_str(&parameter("path")).decode_utf8().unwrap(),
+            "45.4215,-75.6972|44.2312,-76.486|43.6532,-79.3832",
+        ); // assert_eq!
+        assert_eq!(parameter("samples"), "10");
+    } // fn
+
+    #[test]
+    fn empty_line_string_is_an_error() {
+        let line_string: LineString = LineString::new(Vec::new());
+        assert!(matches!(
+            Locations::try_from(&line_string),
+            Err(crate::error::Error::Elevation(ElevationError::EmptyLocations)),
+        ));
+    } // fn
+
+} // mod
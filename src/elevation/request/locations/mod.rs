@@ -64,7 +64,7 @@ impl std::convert::From<&Locations> for String {
 /// pair, multiple latitude/longitude pairs, or an encoded polyline.
 
 #[cfg(feature = "geo")]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Locations {
     /// A single or multiple
     /// [latitude/longitude](https://developers.google.com/maps/documentation/elevation/intro#Locations)
@@ -107,8 +107,8 @@ impl std::convert::From<&Locations> for String {
                     "{start_lat},{start_lng}|{end_lat},{end_lng}",
                     start_lat=line.start.y,
                     start_lng=line.start.x,
-                    end_lat=line.start.y,
-                    end_lng=line.start.x,
+                    end_lat=line.end.y,
+                    end_lng=line.end.x,
                 ), // format!
 
             Locations::LineString(line_string) =>
@@ -132,4 +132,33 @@ impl std::default::Default for Locations {
     fn default() -> Self {
         Locations::LatLngs(vec![LatLng::try_from_dec(dec!(0.0), dec!(0.0)).unwrap()])
     } // fn
-} // impl
\ No newline at end of file
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Locations {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds an encoded-polyline `Locations` from a list of coordinates. The
+    /// encoded polyline is much shorter than a list of coordinates, so more
+    /// locations fit within the URL length limit.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `points` ‧ The coordinates of the locations, in order.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let locations = Locations::from_points(&[
+    ///     LatLng::try_from_dec(dec!(36.578581), dec!(-118.291994))?,
+    ///     LatLng::try_from_dec(dec!(36.23998), dec!(-116.83171))?,
+    /// ]);
+    /// ```
+
+    pub fn from_points(points: &[LatLng]) -> Locations {
+        Locations::Polyline(crate::types::polyline::encode(points))
+    } // fn
+
+} // impl
@@ -4,6 +4,7 @@
 //! for building your Google Maps Platform request.
 
 mod build;
+mod deserialize_with_client;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
 mod for_positional_request;
@@ -13,19 +14,32 @@ mod get;
 mod new;
 mod query_url;
 mod validate;
+mod with_billing_tag;
 pub mod locations;
 
 // -----------------------------------------------------------------------------
 
 use crate::{client::GoogleMapsClient, elevation::request::locations::Locations};
+use serde::Serialize;
+
+// -----------------------------------------------------------------------------
+
+/// Google's _Elevation_ service accepts at most 512 samples per sampled path
+/// request.
+pub(crate) const MAX_SAMPLES: u16 = 512;
 
 // -----------------------------------------------------------------------------
 //
 /// **Look at this `Request` struct for documentation on how to build your
 /// _Elevation API_ query**. The methods implemented for this struct are what's
 /// used to build your request.
+///
+/// A request's parameters may be serialized, for example to save a search to
+/// disk. The client, and any query string that has already been built, are not
+/// serialized. Use `Request::deserialize_with_client` to load the parameters
+/// again.
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct Request<'a> {
 
     // Required parameters:
@@ -33,6 +47,7 @@ pub struct Request<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
+    #[serde(skip)]
     client: &'a GoogleMapsClient,
 
     // Positional Requests:
@@ -57,15 +72,23 @@ pub struct Request<'a> {
     /// Specifies the number of sample points along a path for which to return
     /// elevation data. The samples parameter divides the given path into an
     /// ordered set of equidistant points along the path.
-    samples: Option<u8>,
+    samples: Option<u16>,
 
     // Internal use only:
     // ------------------
 
+    /// A caller-defined tag, such as a product feature, that is recorded in the
+    /// `billing_tag` field of the request's `tracing` span. It is never sent to
+    /// Google.
+    #[serde(skip)]
+    billing_tag: Option<String>,
+
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
     query: Option<String>,
 
     /// Has the request been validated?
+    #[serde(skip)]
     validated: bool,
 
 } // struct
\ No newline at end of file
@@ -26,6 +26,7 @@ impl<'a> Request<'a> {
             path: None,
             samples: None,
             // Internal use only:
+            billing_tag: None,
             query: None,
             validated: false,
         } // struct
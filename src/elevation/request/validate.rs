@@ -1,4 +1,4 @@
-use crate::elevation::{error::Error, request::Request};
+use crate::elevation::{error::Error, request::{MAX_SAMPLES, Request}};
 
 impl<'a> Request<'a> {
 
@@ -23,6 +23,13 @@ impl<'a> Request<'a> {
             }
         } // if
 
+        // Google will not accept more than 512 samples in a single request:
+        if let Some(samples) = self.samples {
+            if samples > MAX_SAMPLES {
+                return Err(Error::TooManySamples(samples));
+            } // if
+        } // if
+
         // Indicated that the request passed validation.
         self.validated = true;
 
@@ -31,4 +38,63 @@ impl<'a> Request<'a> {
 
     } // fn
 
-} // impl
\ No newline at end of file
+} // impl
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::client::GoogleMapsClient;
+    use crate::elevation::request::locations::Locations;
+    use crate::types::LatLng;
+    use rust_decimal_macros::dec;
+
+    fn path() -> Locations {
+        Locations::LatLngs(vec![
+            LatLng::try_from_dec(dec!(36.578581), dec!(-118.291994)).unwrap(),
+            LatLng::try_from_dec(dec!(36.23998), dec!(-116.83171)).unwrap(),
+        ]) // Locations
+    } // fn
+
+    #[test]
+    fn positional_and_sampled_path_conflict() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let location = LatLng::try_from_dec(dec!(39.7391536), dec!(-104.9847034)).unwrap();
+        assert!(matches!(
+            client
+                .elevation()
+                .for_positional_request(location.clone())
+                .for_sampled_path_request(path(), 10)
+                .validate(),
+            Err(Error::EitherPositionalOrSampledPath),
+        )); // assert!
+        // The conflict is also caught before a query string is built:
+        assert!(matches!(
+            client
+                .elevation()
+                .for_sampled_path_request(path(), 10)
+                .for_positional_request(location)
+                .query_url(),
+            Err(Error::EitherPositionalOrSampledPath),
+        )); // assert!
+    } // fn
+
+    #[test]
+    fn single_mode_is_valid() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        assert!(client.elevation().for_sampled_path_request(path(), 10).validate().is_ok());
+        assert!(client.elevation().for_positional_requests(path()).validate().is_ok());
+    } // fn
+
+    #[test]
+    fn too_many_samples() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        assert!(client.elevation().for_sampled_path_request(path(), MAX_SAMPLES).validate().is_ok());
+        assert!(matches!(
+            client.elevation().for_sampled_path_request(path(), MAX_SAMPLES + 1).validate(),
+            Err(Error::TooManySamples(samples)) if samples == MAX_SAMPLES + 1,
+        )); // assert!
+    } // fn
+
+} // mod
@@ -0,0 +1,35 @@
+use crate::elevation::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Tags the request with your own category, such as a product feature or
+    /// a customer, for accounting purposes.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `billing_tag` ‧ The tag to record with the request.
+    ///
+    /// ## Description:
+    ///
+    /// The tag is never sent to Google. It is recorded in the `billing_tag`
+    /// field of the `tracing` span that wraps the request, alongside the
+    /// `request_id`. A `tracing` layer that collects metrics or costs can then
+    /// group requests by your own categories, independently of Google's SKUs.
+    ///
+    /// ## Example:
+    ///
+    /// * Attribute the request to the checkout feature:
+    /// ```rust
+    /// .with_billing_tag("checkout")
+    /// ```
+
+    pub fn with_billing_tag(&'a mut self, billing_tag: &str) -> &'a mut Request {
+        // Set billing tag in Request struct.
+        self.billing_tag = Some(billing_tag.to_string());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
@@ -3,6 +3,7 @@
 //! data returned from Google's server and how to parse it with your program.
 
 pub mod point;
+mod profile;
 pub mod status;
 
 use crate::elevation::response::{point::Point, status::Status};
@@ -38,4 +39,55 @@ impl std::str::FromStr for Response {
     fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
         serde_json::from_str(s)
     }
-}
\ No newline at end of file
+}
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Response {
+    /// Formats a concise, one-line summary of the Elevation response for
+    /// logging: the status, the number of points, and the lowest & highest
+    /// elevations. Use `{:#?}` to see the entire response.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let points = self.results.as_deref().unwrap_or_default();
+        write!(f, "Elevation {}: {} point(s)", self.status, points.len())?;
+        let lowest = points.iter().map(|point| point.elevation).reduce(f64::min);
+        let highest = points.iter().map(|point| point.elevation).reduce(f64::max);
+        if let (Some(lowest), Some(highest)) = (lowest, highest) {
+            write!(f, ", from {lowest:.1} m to {highest:.1} m")?;
+        } // if
+        Ok(())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn display_shows_the_elevation_range() {
+        let point = |elevation: f64| json!({
+            "elevation": elevation,
+            "location": { "lat": 36.578581, "lng": -118.291994 },
+            "resolution": 19.1,
+        }); // json!
+        let response: Response = serde_json::from_value(json!({
+            "results": [point(4_411.94), point(-85.96), point(1_608.64)],
+            "status": "OK",
+        })).unwrap(); // from_value
+        assert_eq!(response.to_string(), "Elevation OK: 3 point(s), from -86.0 m to 4411.9 m");
+    } // fn
+
+    #[test]
+    fn display_without_results() {
+        let response: Response = serde_json::from_value(json!({
+            "error_message": "The provided API key is invalid.",
+            "status": "REQUEST_DENIED",
+        })).unwrap(); // from_value
+        assert_eq!(response.to_string(), format!("Elevation {}: 0 point(s)", response.status));
+    } // fn
+
+} // mod
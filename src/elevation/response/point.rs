@@ -5,7 +5,9 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Point {
-    /// Elevation of the location in meters.
+    /// Elevation of the location in meters, relative to local mean sea level.
+    /// Locations below sea level, such as the ocean floor, have a negative
+    /// elevation. Negative values are preserved as returned by Google.
     pub elevation: f64,
     /// Position for which elevation data is being computed. Note that for path
     /// requests, the set of `location` elements will contain the sampled points
@@ -18,4 +20,52 @@ pub struct Point {
     /// the most accurate elevation value for a point, it should be queried
     /// independently.
     pub resolution: Option<f64>,
-} // struct
\ No newline at end of file
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Point {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns whether the location is below local mean sea level, for
+    /// example on the ocean floor or in a depression such as the Dead Sea.
+
+    pub fn is_below_sea_level(&self) -> bool {
+        self.elevation < 0.0
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn negative_elevation_is_preserved() {
+        // The Challenger Deep, in the Mariana Trench:
+        let point: Point = serde_json::from_str(r#"{
+            "elevation": -10898.38,
+            "location": { "lat": 11.3733, "lng": 142.5917 },
+            "resolution": 610.8129272460938
+        }"#).unwrap();
+        assert_eq!(point.elevation, -10_898.38);
+        assert!(point.is_below_sea_level());
+    } // fn
+
+    #[test]
+    fn sea_level_and_above() {
+        let point = |elevation: f64| Point {
+            elevation,
+            location: LatLng::try_from_f64(39.7391536, -104.9847034).unwrap(),
+            resolution: None,
+        }; // Point
+        assert!(!point(0.0).is_below_sea_level());
+        assert!(!point(1_608.64).is_below_sea_level());
+        assert!(point(-0.01).is_below_sea_level());
+    } // fn
+
+} // mod
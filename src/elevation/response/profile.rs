@@ -0,0 +1,68 @@
+use crate::elevation::response::Response;
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the elevation profile: the location, elevation (in meters), and
+    /// resolution (in meters) of each sample, in the order that they were
+    /// returned.
+    ///
+    /// ## Description:
+    ///
+    /// Samples are returned in the same order as the requested locations, or
+    /// in order along the requested path. The resolution is the maximum
+    /// distance between the data points that the elevation was interpolated
+    /// from. It is `None` if Google did not return it.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// for (location, elevation, resolution) in elevation.profile() {
+    ///     println!("{location}: {elevation:.1} m (± {resolution:?} m)");
+    /// }
+    /// ```
+
+    pub fn profile(&self) -> Vec<(LatLng, f64, Option<f64>)> {
+        self.results
+            .iter()
+            .flatten()
+            .map(|point| (point.location.clone(), point.elevation, point.resolution))
+            .collect()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the total ascent and total descent, in meters, over the ordered
+    /// samples as a `(gain, loss)` tuple. Both values are zero or positive.
+    ///
+    /// ## Description:
+    ///
+    /// The gain is the sum of every increase in elevation from one sample to
+    /// the next, and the loss is the sum of every decrease. This is only
+    /// meaningful for samples along a path. The result depends on the number
+    /// of samples: sparse sampling will miss smaller climbs and descents.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let (ascent, descent) = elevation.cumulative_gain_loss();
+    /// ```
+
+    pub fn cumulative_gain_loss(&self) -> (f64, f64) {
+        self.results
+            .as_deref()
+            .unwrap_or_default()
+            .windows(2)
+            .map(|pair| pair[1].elevation - pair[0].elevation)
+            .fold((0.0, 0.0), |(gain, loss), change| if change > 0.0 {
+                (gain + change, loss)
+            } else {
+                (gain, loss - change)
+            }) // fold
+    } // fn
+
+} // impl
@@ -14,6 +14,41 @@ use thiserror::Error;
 #[diagnostic(url(docsrs))]
 pub enum Error {
 
+    /// The Google Maps API key is missing or empty. For example, the
+    /// environment variable read by `GoogleMapsClient::from_env` was not set,
+    /// or a request was attempted with a client that has an empty key.
+    #[error("Google Maps Platform API client: \
+        The API key is missing or empty. \
+        Check that the key is set, for example in the `GOOGLE_MAPS_API_KEY` environment variable.")]
+    #[diagnostic(code(google_maps::missing_api_key))]
+    MissingApiKey,
+
+    /// Too many recent requests to the API have failed, so the client's
+    /// circuit breaker is rejecting requests to it until its cooldown has
+    /// elapsed. See `GoogleMapsClient::with_circuit_breaker`.
+    #[cfg(feature = "enable-reqwest")]
+    #[error("Google Maps Platform API client: \
+        Too many recent requests to the {api} API have failed. \
+        Requests to it are being rejected until the circuit breaker's cooldown has elapsed.")]
+    #[diagnostic(code(google_maps::circuit_open))]
+    CircuitOpen {
+        /// The API whose circuit is open.
+        api: crate::request_rate::api::Api,
+    },
+
+    /// Google responded `404 Not Found` with an empty body, rather than with an
+    /// error object. This means that the API's endpoint itself could not be
+    /// found. The Geolocation API currently responds this way to all requests.
+    #[cfg(feature = "enable-reqwest")]
+    #[error("Google Maps Platform API client: \
+        The {api} API is unavailable. \
+        Google responded `404 Not Found` with an empty body, which means that the service itself could not be found.")]
+    #[diagnostic(code(google_maps::service_unavailable))]
+    ServiceUnavailable {
+        /// The API that is unavailable.
+        api: crate::request_rate::api::Api,
+    },
+
     /// Error originating from the types and structs in the `google_maps` crate.
     #[error(transparent)]
     #[diagnostic(code(google_maps::types))]
@@ -47,6 +82,13 @@ pub enum Error {
     #[diagnostic(code(google_maps::geocoding))]
     Geocoding(#[from] crate::geocoding::error::Error),
 
+    /// Error originating from the `geolocation` module in the `google_maps`
+    /// crate.
+    #[cfg(feature = "geolocation")]
+    #[error(transparent)]
+    #[diagnostic(code(google_maps::geolocation))]
+    Geolocation(#[from] crate::geolocation::error::Error),
+
     /// Error originating from the `places` module in the `google_maps` crate.
     #[cfg(feature = "places")]
     #[error(transparent)]
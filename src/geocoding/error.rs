@@ -24,6 +24,15 @@ pub enum Error {
     /// API client library attempted to parse a string that contained an invalid
     /// status code.
     InvalidStatusCode(String),
+    /// Google Maps Geocoding API server reported that the daily usage limit
+    /// has been exceeded, or that billing is not set up for the API key. This
+    /// error is never retried, as retrying will not help until the quota is
+    /// reset or the billing and quota settings are fixed.
+    OverDailyLimit(Option<String>),
+    /// Google Maps Geocoding API server did not return a result for the
+    /// postal code passed to `GoogleMapsClient::postal_code_bounds`, so no
+    /// bounding box is available.
+    PostalCodeNotFound(String),
     /// The query string must be built before the request may be sent to the
     /// Google Maps Geocoding API server.
     QueryNotBuilt,
@@ -38,6 +47,12 @@ pub enum Error {
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
     SerdeJson(serde_json::error::Error),
+    /// The HTTP request to the Google Maps Geocoding API server timed out. See
+    /// `GoogleMapsClient::with_timeout` and
+    /// `GoogleMapsClient::with_connect_timeout`. This error is eligible for
+    /// retries.
+    #[cfg(feature = "enable-reqwest")]
+    Timeout(reqwest::Error),
 } // enum
 
 impl std::fmt::Display for Error {
@@ -89,6 +104,19 @@ impl std::fmt::Display for Error {
                 Valid codes are `INVALID_REQUEST`, `OK`, `OVER_DAILY_LIMIT`, \
                 `OVER_QUERY_LIMIT`, `REQUEST_DENIED`, `UNKNOWN_ERROR`, and \
                 `ZERO_RESULTS`."),
+            Error::OverDailyLimit(error_message) => match error_message {
+                Some(error_message) => write!(f, "Google Maps Geocoding API service: \
+                    Over daily limit. {error_message} \
+                    Check the billing and quota settings for your API key in the Google Cloud Console."),
+                None => write!(f, "Google Maps Geocoding API service: \
+                    Over daily limit. \
+                    Usage cap has been exceeded, API key is invalid, billing has not been enabled, or method of payment is no longer valid. \
+                    Check the billing and quota settings for your API key in the Google Cloud Console."),
+            }, // match
+            Error::PostalCodeNotFound(postal_code) => write!(f,
+                "Google Maps Geocoding API client: \
+                No result was returned for the `{postal_code}` postal code, so neither its bounds nor its viewport are available. \
+                Ensure that the postal code and country are correct."),
             Error::QueryNotBuilt => write!(f,
                 "Google Maps Geocoding API client: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
@@ -102,6 +130,9 @@ impl std::fmt::Display for Error {
             #[cfg(feature = "enable-reqwest")]
             Error::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
             Error::SerdeJson(error) => write!(f, "Google Maps Geocoding API client in the Serde JSON library: {error}"),
+            #[cfg(feature = "enable-reqwest")]
+            Error::Timeout(error) => write!(f, "Google Maps Geocoding API client: \
+                The request timed out: {error}"),
         } // match
     } // fn
 } // impl
@@ -130,3 +161,24 @@ impl From<serde_json::error::Error> for Error {
         Error::SerdeJson(error)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::client::ApiError for Error {
+    /// Wraps an unsuccessful HTTP status in a Google Maps Geocoding API
+    /// error type. This function is required by the shared `get` method.
+    fn http_unsuccessful(status: String) -> Error {
+        Error::HttpUnsuccessful(status)
+    } // fn
+    /// Wraps a HTTP client error message in a Google Maps Geocoding API
+    /// error type. This function is required by the shared `get` method.
+    fn reqwest_message(message: String) -> Error {
+        Error::ReqwestMessage(message)
+    } // fn
+    /// Wraps a HTTP client timeout in a Google Maps Geocoding API
+    /// error type. This function is required by the shared `get` method.
+    fn timeout(error: reqwest::Error) -> Error {
+        Error::Timeout(error)
+    } // fn
+} // impl
\ No newline at end of file
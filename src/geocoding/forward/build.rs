@@ -1,5 +1,5 @@
 use crate::{geocoding::error::Error, geocoding::forward::ForwardRequest};
-use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use crate::query_string::QueryString;
 
 impl<'a> ForwardRequest<'a> {
 
@@ -19,64 +19,43 @@ impl<'a> ForwardRequest<'a> {
         // This section builds the "required parameters" portion of the query
         // string:
 
-        let mut query = format!("key={}", self.client.key);
+        let mut query = QueryString::new(&self.client.key);
 
         // This section builds the "optional parameters" portion of the query
         // string:
 
         // Address key/value pair:
         if let Some(address) = &self.address {
-            query.push_str("&address=");
-            query.push_str(
-                &utf8_percent_encode(&String::from(address), NON_ALPHANUMERIC).to_string(),
-            )
+            query.push_encoded("address", address)
         }
 
         // Place Id key/value pair:
         if let Some(place_id) = &self.place_id {
-            query.push_str("&place_id=");
-            query.push_str(
-                &utf8_percent_encode(&String::from(place_id), NON_ALPHANUMERIC).to_string(),
-            )
+            query.push_encoded("place_id", place_id)
         }
 
         // Bounds key/value pair:
         if let Some(bounds) = &self.bounds {
-            query.push_str("&bounds=");
-            query.push_str(
-                &utf8_percent_encode(&String::from(bounds), NON_ALPHANUMERIC).to_string(),
-            )
+            query.push_encoded_display("bounds", bounds)
         }
 
         // Components key/value pair:
         if let Some(components) = &self.components {
-            query.push_str("&components=");
-            query.push_str(
-                &utf8_percent_encode(
-                    &components
-                        .iter()
-                        .map(String::from)
-                        .collect::<Vec<String>>()
-                        .join("|"),
-                    NON_ALPHANUMERIC,
-                ).to_string(),
-            ) // push_str
+            query.push_encoded_list("components", components.iter().map(String::from))
         }
 
         // Language key/value pair:
         if let Some(language) = &self.language {
-            query.push_str("&language=");
-            query.push_str(&String::from(language))
+            query.push("language", language.into())
         }
 
         // Region key/value pair:
         if let Some(region) = &self.region {
-            query.push_str("&region=");
-            query.push_str(&String::from(region))
+            query.push("region", region.into())
         }
 
         // Set query string in ForwardRequest struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified ForwardRequest struct to caller.
         Ok(self)
@@ -0,0 +1,157 @@
+use crate::client::GoogleMapsClient;
+use crate::geocoding::forward::{component::Component, ForwardRequest};
+use crate::types::{Bounds, Language, Region};
+use serde::{Deserialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+//
+/// The serializable parameters of a `ForwardRequest`. A `ForwardRequest` holds a
+/// reference to its client, so it can not be deserialized on its own. The
+/// parameters are deserialized into this struct first, and then bound to a
+/// client.
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Parameters {
+    #[serde(default)]
+    address: Option<String>,
+    #[serde(default)]
+    place_id: Option<String>,
+    #[serde(default)]
+    bounds: Option<Bounds>,
+    #[serde(default)]
+    components: Option<Vec<Component>>,
+    #[serde(default)]
+    language: Option<Language>,
+    #[serde(default)]
+    region: Option<Region>,
+} // struct
+
+// =============================================================================
+
+impl<'a> ForwardRequest<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Deserializes a request's parameters, such as those previously saved
+    /// by serializing a `ForwardRequest`, and binds them to a client.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ The client that the request will be sent with.
+    ///
+    /// * `deserializer` ‧ Any `serde` deserializer, for example a
+    /// `serde_json::Deserializer`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let json = serde_json::to_string(&google_maps_client.geocoding()
+    ///     .with_address("10 Downing Street London")
+    ///     .with_region(Region::UnitedKingdom))?;
+    ///
+    /// let location = GeocodingRequest::deserialize_with_client(
+    ///     &google_maps_client,
+    ///     &mut serde_json::Deserializer::from_str(&json),
+    /// )?
+    /// .execute()
+    /// .await?;
+    /// ```
+
+    pub fn deserialize_with_client<'de, D: Deserializer<'de>>(
+        client: &'a GoogleMapsClient,
+        deserializer: D,
+    ) -> Result<ForwardRequest<'a>, D::Error> {
+
+        let parameters = Parameters::deserialize(deserializer)?;
+
+        Ok(ForwardRequest {
+            // Required parameters:
+            client,
+            // Optional parameters:
+            address: parameters.address,
+            place_id: parameters.place_id,
+            bounds: parameters.bounds,
+            components: parameters.components,
+            language: parameters.language.or_else(|| client.default_language.clone()),
+            region: parameters.region.or_else(|| client.default_region.clone()),
+            // Internal use only:
+            billing_tag: None,
+            query: None,
+            validated: false,
+        }) // ForwardRequest
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::{Country, LatLng};
+    use rust_decimal_macros::dec;
+
+    fn request(client: &GoogleMapsClient) -> ForwardRequest<'_> {
+        let mut request = client.geocoding();
+        // A request has either an address or a place ID, but both are set here so
+        // that each is round-tripped:
+        request.address = Some(String::from("10 Downing Street London"));
+        request.place_id = Some(String::from("ChIJdd4hrwug2EcRmSrV3Vo6llI"));
+        request.bounds = Some(Bounds {
+            southwest: LatLng::try_from_dec(dec!(43.6532), dec!(-79.3832)).unwrap(),
+            northeast: LatLng::try_from_dec(dec!(45.5019), dec!(-73.5674)).unwrap(),
+        }); // Bounds
+        request.components = Some(vec![
+            Component::Country(Country::UnitedKingdom),
+            Component::PostalCode(String::from("SW1A 2AA")),
+        ]); // vec!
+        request.language = Some(Language::English);
+        request.region = Some(Region::UnitedKingdom);
+        request
+    } // fn
+
+    #[test]
+    fn round_trips_every_parameter() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let json = serde_json::to_value(request(&client)).unwrap();
+        // Every parameter is populated, so that a parameter that is missing
+        // from `Parameters` is rejected as an unknown field:
+        assert!(
+            json.as_object().unwrap().values().all(|value| !value.is_null()),
+            "a parameter is not populated: {json}",
+        ); // assert!
+        let restored = ForwardRequest::deserialize_with_client(&client, json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(restored).unwrap(), json);
+    } // fn
+
+    #[test]
+    fn rejects_unknown_parameters() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let mut json = serde_json::to_value(request(&client)).unwrap();
+        json["unknown"] = serde_json::Value::Bool(true);
+        assert!(ForwardRequest::deserialize_with_client(&client, json).is_err());
+    } // fn
+
+    #[test]
+    fn falls_back_to_the_client_defaults() {
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client.with_default_language(Language::German).with_default_region(Region::Germany);
+        let client = client.build();
+        let saved = request(&client);
+        let mut json = serde_json::to_value(&saved).unwrap();
+        // A saved language and region take precedence over the defaults:
+        let restored = ForwardRequest::deserialize_with_client(&client, json.clone()).unwrap();
+        assert_eq!((restored.language, restored.region), (saved.language, saved.region));
+        // A request saved without them gets the client's defaults, just like a
+        // new request:
+        json.as_object_mut().unwrap().remove("language");
+        json.as_object_mut().unwrap().remove("region");
+        let restored = ForwardRequest::deserialize_with_client(&client, json).unwrap();
+        assert_eq!(restored.language, Some(Language::German));
+        assert_eq!(restored.region, Some(Region::Germany));
+    } // fn
+
+} // mod
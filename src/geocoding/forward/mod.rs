@@ -5,6 +5,7 @@
 //! geocoding converts a street address to latitude & longitude coordinates.
 
 mod build;
+mod deserialize_with_client;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
 #[cfg(feature = "enable-reqwest")]
@@ -13,6 +14,7 @@ mod new;
 mod query_url;
 mod validate;
 mod with_address;
+mod with_billing_tag;
 mod with_place_id;
 mod with_bounds;
 mod with_components;
@@ -25,6 +27,7 @@ pub mod component;
 use crate::client::GoogleMapsClient;
 use crate::geocoding::forward::component::Component;
 use crate::types::{Bounds, Language, Region};
+use serde::Serialize;
 
 // -----------------------------------------------------------------------------
 
@@ -32,8 +35,13 @@ use crate::types::{Bounds, Language, Region};
 /// _Geocoding API_ query**. The methods implemented for this struct are what's
 /// used to build your request. Forward geocoding looks up a longitude &
 /// latitude coordinates from a street address.
+///
+/// A request's parameters may be serialized, for example to save a search to
+/// disk. The client, and any query string that has already been built, are not
+/// serialized. Use `ForwardRequest::deserialize_with_client` to load the
+/// parameters again.
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct ForwardRequest<'a> {
 
     // Required parameters:
@@ -41,6 +49,7 @@ pub struct ForwardRequest<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
+    #[serde(skip)]
     client: &'a GoogleMapsClient,
 
     // Optional parameters:
@@ -90,10 +99,18 @@ pub struct ForwardRequest<'a> {
     // Internal use only:
     // ------------------
 
+    /// A caller-defined tag, such as a product feature, that is recorded in the
+    /// `billing_tag` field of the request's `tracing` span. It is never sent to
+    /// Google.
+    #[serde(skip)]
+    billing_tag: Option<String>,
+
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
     query: Option<String>,
 
     /// Has the request been validated?
+    #[serde(skip)]
     validated: bool,
 
 } // struct
\ No newline at end of file
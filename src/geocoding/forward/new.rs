@@ -24,10 +24,11 @@ impl<'a> ForwardRequest<'a> {
             place_id: None,
             bounds: None,
             components: None,
-            language: None,
-            region: None,
+            language: client.default_language.clone(),
+            region: client.default_region.clone(),
             // Internal use only:
             validated: false,
+            billing_tag: None,
             query: None,
         } // struct
 
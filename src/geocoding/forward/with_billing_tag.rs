@@ -0,0 +1,94 @@
+use crate::geocoding::forward::ForwardRequest;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> ForwardRequest<'a> {
+
+    /// Tags the request with your own category, such as a product feature or
+    /// a customer, for accounting purposes.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `billing_tag` ‧ The tag to record with the request.
+    ///
+    /// ## Description:
+    ///
+    /// The tag is never sent to Google. It is recorded in the `billing_tag`
+    /// field of the `tracing` span that wraps the request, alongside the
+    /// `request_id`. A `tracing` layer that collects metrics or costs can then
+    /// group requests by your own categories, independently of Google's SKUs.
+    ///
+    /// ## Example:
+    ///
+    /// * Attribute the request to the checkout feature:
+    /// ```rust
+    /// .with_billing_tag("checkout")
+    /// ```
+
+    pub fn with_billing_tag(&'a mut self, billing_tag: &str) -> &'a mut ForwardRequest {
+        // Set billing tag in ForwardRequest struct.
+        self.billing_tag = Some(billing_tag.to_string());
+        // Return modified ForwardRequest struct to caller.
+        self
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "enable-reqwest"))]
+mod tests {
+
+    use crate::client::test_server::{block_on, Spans, TestServer};
+    use crate::client::{GoogleMapsClient, RetryPolicy};
+    use crate::request_rate::api::Api;
+
+    /// Geocodes an address against a test server, optionally with a billing
+    /// tag. Returns the `billing_tag` field of each span that recorded one, and
+    /// the targets that the server received.
+    fn geocode(billing_tag: Option<&str>) -> (Vec<String>, Vec<String>) {
+        let server = TestServer::start(200, &[], r#"{"results":[],"status":"ZERO_RESULTS"}"#);
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_endpoint_override(Api::Geocoding, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none());
+        let client = client.build();
+
+        let subscriber = Spans::default();
+        let spans = subscriber.0.clone();
+        tracing::subscriber::with_default(subscriber, || {
+            let mut request = client.geocoding();
+            let request = request.with_address("Ottawa");
+            let request = match billing_tag {
+                Some(billing_tag) => request.with_billing_tag(billing_tag),
+                None => request,
+            }; // match
+            let _ = block_on(request.execute());
+        }); // with_default
+
+        let tags = spans
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|fields| fields.get("billing_tag").cloned())
+            .collect();
+        (tags, server.targets())
+    } // fn
+
+    #[test]
+    fn billing_tag_is_recorded_on_the_request_span() {
+        let (tags, targets) = geocode(Some("checkout"));
+        assert_eq!(tags, ["checkout"]);
+        // The tag is never sent to Google:
+        assert_eq!(targets.len(), 1);
+        assert!(!targets[0].contains("checkout"), "{}", targets[0]);
+    } // fn
+
+    #[test]
+    fn untagged_request_records_no_billing_tag() {
+        let (tags, targets) = geocode(None);
+        assert!(tags.is_empty(), "{tags:?}");
+        assert_eq!(targets.len(), 1);
+    } // fn
+
+} // mod
@@ -1,4 +1,5 @@
 use crate::geocoding::forward::{component::Component, ForwardRequest};
+use crate::types::Country;
 
 impl<'a> ForwardRequest<'a> {
 
@@ -111,4 +112,61 @@ impl<'a> ForwardRequest<'a> {
         self
     } // fn
 
-} // impl
\ No newline at end of file
+    /// Restricts the results from the geocoder to a country. This is a
+    /// shortcut for `with_component(GeocodingComponent::Country(country))`.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `country` ‧ The only country that results may be in.
+    ///
+    /// ## Description:
+    ///
+    /// A country restriction is _hard_: results outside of the country are
+    /// never returned, even if they are more relevant. It is sent as a
+    /// `components=country:` filter.
+    ///
+    /// To merely _prefer_ results in a region, use `bias_region` (or
+    /// `with_region`) instead. It is sent as the `region` parameter.
+    ///
+    /// ## Example:
+    ///
+    /// * Only return results in Spain:
+    /// ```rust
+    /// .restrict_to_country(Country::Spain)
+    /// ```
+
+    pub fn restrict_to_country(&'a mut self, country: Country) -> &'a mut ForwardRequest {
+        self.with_component(Component::Country(country))
+    } // fn
+
+} // impl
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::GoogleMapsClient;
+    use crate::geocoding::forward::component::Component;
+    use crate::types::Country;
+
+    #[test]
+    fn restrict_to_country_sends_country_component() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let url = client.geocoding().with_address("Toledo").restrict_to_country(Country::Spain).query_url().unwrap();
+        assert!(url.contains("&components=country%3AES"), "{url}");
+        assert!(!url.contains("region="), "{url}");
+    } // fn
+
+    #[test]
+    fn restrict_to_country_keeps_other_components() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let url = client
+            .geocoding()
+            .with_component(Component::PostalCode(String::from("45001")))
+            .restrict_to_country(Country::Spain)
+            .query_url()
+            .unwrap();
+        assert!(url.ends_with("&components=postal%5Fcode%3A45001%7Ccountry%3AES"), "{url}");
+    } // fn
+
+} // mod
@@ -14,6 +14,7 @@ impl<'a> ForwardRequest<'a> {
     /// get the address for a snapped point.
     /// For more information about place IDs, see the place [ID
     /// overview](https://developers.google.com/maps/documentation/places/web-service/place-id)..
+    /// Either a string slice or a validated `PlaceId` may be passed.
     ///
     /// ## Example:
     ///
@@ -23,9 +24,9 @@ impl<'a> ForwardRequest<'a> {
     /// )
     /// ```
 
-    pub fn with_place_id(&'a mut self, place_id: &str) -> &'a mut ForwardRequest {
+    pub fn with_place_id(&'a mut self, place_id: impl AsRef<str>) -> &'a mut ForwardRequest {
         // Set address in ForwardRequest struct.
-        self.place_id = Some(place_id.to_string());
+        self.place_id = Some(place_id.as_ref().to_string());
         // Return modified ForwardRequest struct to caller.
         self
     } // fn
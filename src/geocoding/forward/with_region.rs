@@ -46,4 +46,66 @@ impl<'a> ForwardRequest<'a> {
         self
     } // fn
 
-} // impl
\ No newline at end of file
+    /// Specifies the region bias. This is an alias for `with_region`.
+    ///
+    /// ## Example:
+    ///
+    /// * Bias region to Canada:
+    /// ```rust
+    /// .with_region_bias(Region::Canada)
+    /// ```
+
+    pub fn with_region_bias(&'a mut self, region: Region) -> &'a mut ForwardRequest {
+        self.with_region(region)
+    } // fn
+
+    /// Biases results towards a region. This is an alias for `with_region`,
+    /// named to make its behaviour clear.
+    ///
+    /// ## Description:
+    ///
+    /// A region bias is _soft_: results in the region are preferred, but
+    /// results elsewhere are still returned if they are more relevant. It is
+    /// sent as the `region` parameter.
+    ///
+    /// To _restrict_ results to a country, so that results elsewhere are never
+    /// returned, use `restrict_to_country` instead. It is sent as a
+    /// `components=country:` filter.
+    ///
+    /// ## Example:
+    ///
+    /// * "Toledo" is biased towards the Spanish city, but the Ohio city may
+    /// still be returned. Restricting to Spain never returns the Ohio city:
+    /// ```rust
+    /// // Sends `region=es`:
+    /// google_maps_client.geocoding().with_address("Toledo").bias_region(Region::Spain);
+    /// // Sends `components=country:ES`:
+    /// google_maps_client.geocoding().with_address("Toledo").restrict_to_country(Country::Spain);
+    /// ```
+
+    pub fn bias_region(&'a mut self, region: Region) -> &'a mut ForwardRequest {
+        self.with_region(region)
+    } // fn
+
+} // impl
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::GoogleMapsClient;
+    use crate::types::Region;
+
+    #[test]
+    fn bias_region_sends_region() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let url = client.geocoding().with_address("Toledo").bias_region(Region::Spain).query_url().unwrap();
+        assert!(url.contains("&region=es"), "{url}");
+        assert!(!url.contains("components="), "{url}");
+        assert_eq!(
+            url,
+            client.geocoding().with_address("Toledo").with_region(Region::Spain).query_url().unwrap(),
+        ); // assert_eq!
+    } // fn
+
+} // mod
@@ -75,6 +75,16 @@ pub struct Geocoding {
 
 impl Geocoding {
 
+    /// A helper function for borrowing the optional `plus_code` field. Returns
+    /// the result's plus code, or `None` if Google did not return one (as it
+    /// does for some rural and remote results).
+    /// ```rust
+    /// let plus_code = geocoding.plus_code().map(|plus_code| &plus_code.global_code);
+    /// ```
+    pub fn plus_code(&self) -> Option<&PlusCode> {
+        self.plus_code.as_ref()
+    } // fn
+
     /// A helper function for destructuring the optional `plus_code` field. If
     /// the _plus_code_ field is populated, this function will return the
     /// global plus code. If the _plus_code_ field is empty, this function
@@ -100,4 +110,31 @@ impl Geocoding {
         } // match
     } // fn
 
+    /// A helper function for finding an address component of a specific type.
+    /// Returns the first address component whose `types` contains the
+    /// specified place type, or `None` if there is no such component.
+    /// ```rust
+    /// let postal_code = geocoding
+    ///     .get_component(PlaceType::PostalCode)
+    ///     .map(|component| component.long_name.as_str());
+    /// ```
+    pub fn get_component(&self, place_type: PlaceType) -> Option<&AddressComponent> {
+        self.address_components
+            .iter()
+            .find(|component| component.types.contains(&place_type))
+    } // fn
+
+    /// A helper function for finding all address components of a specific
+    /// type. Returns every address component whose `types` contains the
+    /// specified place type, in the order returned by Google.
+    /// ```rust
+    /// let political_components = geocoding.get_components(PlaceType::Political);
+    /// ```
+    pub fn get_components(&self, place_type: PlaceType) -> Vec<&AddressComponent> {
+        self.address_components
+            .iter()
+            .filter(|component| component.types.contains(&place_type))
+            .collect()
+    } // fn
+
 } // impl
\ No newline at end of file
@@ -0,0 +1,97 @@
+//! Provides [GeoJSON](https://geojson.org/) conversions for the _Geocoding
+//! API_ response types.
+
+use crate::geocoding::response::{geocoding::Geocoding, Response};
+use crate::types::geojson::{Feature, FeatureCollection};
+use serde_json::json;
+
+// -----------------------------------------------------------------------------
+
+impl Geocoding {
+    /// Converts a geocoding result into a GeoJSON `Feature`. The result's
+    /// location becomes a `Point` geometry. The formatted address, place ID,
+    /// types, location type and partial match flag are copied into the
+    /// feature's `properties`.
+    /// ```rust
+    /// let feature = geocoding.to_feature();
+    /// ```
+    pub fn to_feature(&self) -> Feature {
+        Feature {
+            geometry: Some(self.geometry.location.to_geojson_point()),
+            properties: [
+                ("formatted_address", json!(self.formatted_address)),
+                ("place_id", json!(self.place_id)),
+                ("types", json!(self.types)),
+                ("location_type", json!(self.geometry.location_type)),
+                ("partial_match", json!(self.partial_match)),
+            ]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+        } // Feature
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Converts all of the geocoding results in the response into a GeoJSON
+    /// `FeatureCollection`. See `Geocoding::to_feature` for how each result is
+    /// converted.
+    /// ```rust
+    /// let feature_collection = geocoding_response.to_feature_collection();
+    /// ```
+    pub fn to_feature_collection(&self) -> FeatureCollection {
+        self.results.iter().map(Geocoding::to_feature).collect()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn to_feature_collection_converts_each_result() {
+        let response: Response = serde_json::from_value(json!({
+            "results": [{
+                "address_components": [],
+                "formatted_address": "Toronto, ON, Canada",
+                "geometry": {
+                    "location": { "lat": 43.6532, "lng": -79.3832 },
+                    "location_type": "APPROXIMATE",
+                    "viewport": {
+                        "northeast": { "lat": 43.86, "lng": -79.12 },
+                        "southwest": { "lat": 43.58, "lng": -79.64 },
+                    }, // viewport
+                }, // geometry
+                "place_id": "ChIJpTvG15DL1IkRd8S0KlBVNTI",
+                "types": [ "locality", "political" ],
+            }], // results
+            "status": "OK",
+        })).unwrap();
+
+        let feature_collection = response.to_feature_collection();
+
+        assert_eq!(
+            serde_json::to_value(&feature_collection).unwrap(),
+            json!({
+                "type": "FeatureCollection",
+                "features": [{
+                    "type": "Feature",
+                    "geometry": { "type": "Point", "coordinates": [-79.3832, 43.6532] },
+                    "properties": {
+                        "formatted_address": "Toronto, ON, Canada",
+                        "place_id": "ChIJpTvG15DL1IkRd8S0KlBVNTI",
+                        "types": [ "locality", "political" ],
+                        "location_type": "APPROXIMATE",
+                        "partial_match": null,
+                    }, // properties
+                }], // features
+            }), // json!
+        ); // assert_eq!
+    } // fn
+
+} // mod
@@ -2,6 +2,8 @@
 //! from the Google Maps Platform. Look in here for more information about the
 //! data returned from Google's server and how to parse it with your program.
 
+#[cfg(feature = "geojson")]
+mod geojson;
 pub mod geocoding;
 pub mod plus_code;
 pub mod status;
@@ -47,4 +49,62 @@ impl std::str::FromStr for Response {
     fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
         serde_json::from_str(s)
     }
-}
\ No newline at end of file
+}
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Response {
+    /// Formats a concise, one-line summary of the Geocoding response for
+    /// logging: the status, the number of results, and the address & location
+    /// of the first result. Use `{:#?}` to see the entire response.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Geocoding {}: {} result(s)", self.status, self.results.len())?;
+        if let Some(result) = self.results.first() {
+            write!(f,
+                ". First: {} ({})",
+                result.formatted_address,
+                result.geometry.location,
+            )?; // write!
+        } // if
+        Ok(())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn display_shows_the_first_result() {
+        let result = |address: &str, lat: f64, lng: f64| json!({
+            "address_components": [],
+            "formatted_address": address,
+            "geometry": {
+                "location": { "lat": lat, "lng": lng },
+                "viewport": {
+                    "northeast": { "lat": lat + 0.1, "lng": lng + 0.1 },
+                    "southwest": { "lat": lat - 0.1, "lng": lng - 0.1 },
+                },
+            },
+            "place_id": "",
+            "types": [],
+        }); // json!
+        let response: Response = serde_json::from_value(json!({
+            "results": [
+                result("Ottawa, ON, Canada", 45.4215296, -75.6971931),
+                result("Ottawa, IL, USA", 41.3455892, -88.8425769),
+            ],
+            "status": "OK",
+        })).unwrap(); // from_value
+        let summary = response.to_string();
+        assert!(summary.starts_with("Geocoding OK: 2 result(s). First: Ottawa, ON, Canada ("), "{summary}");
+        assert!(summary.contains("45.4215296"), "{summary}");
+        assert!(summary.contains("-75.6971931"), "{summary}");
+        assert!(!summary.contains("Ottawa, IL"), "{summary}");
+    } // fn
+
+} // mod
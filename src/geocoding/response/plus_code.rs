@@ -1,3 +1,4 @@
+use crate::serde::empty_string_as_none::empty_string_as_none;
 use serde::{Deserialize, Serialize};
 
 /// (See [Open Location Code](https://en.wikipedia.org/wiki/Open_Location_Code)
@@ -20,5 +21,6 @@ pub struct PlusCode {
 
     /// A 6 character or longer local code with an explicit location (`CWC8+R9,
     /// Mountain View, CA, USA`).
+    #[serde(default, deserialize_with = "empty_string_as_none")]
     pub compound_code: Option<String>,
 } // struct
\ No newline at end of file
@@ -1,5 +1,5 @@
 use crate::geocoding::reverse::ReverseRequest;
-use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use crate::query_string::QueryString;
 
 impl<'a> ReverseRequest<'a> {
 
@@ -15,53 +15,34 @@ impl<'a> ReverseRequest<'a> {
         // This section builds the "required parameters" portion of the query
         // string:
 
-        let mut query = format!(
-            "key={}&latlng={}",
-            self.client.key,
-            String::from(&self.latlng),
-        ); // format!
+        let mut query = QueryString::new(&self.client.key);
+        query.push_display("latlng", &self.latlng);
 
         // This section builds the "optional parameters" portion of the query
         // string:
 
+        // Components key/value pair:
+        if let Some(components) = &self.components {
+            query.push_encoded_list("components", components.iter().map(String::from))
+        } // if
+
         // Language key/value pair:
         if let Some(language) = &self.language {
-            query.push_str("&language=");
-            query.push_str(&String::from(language))
+            query.push("language", language.into())
         } // if
 
         // Location type(s) key/value pair:
         if let Some(location_types) = &self.location_types {
-            query.push_str("&location_type=");
-            query.push_str(
-                &utf8_percent_encode(
-                    &location_types
-                        .iter()
-                        .map(String::from)
-                        .collect::<Vec<String>>()
-                        .join("|"),
-                    NON_ALPHANUMERIC,
-                ).to_string(),
-            ) // push_str
+            query.push_encoded_list("location_type", location_types.iter().map(<&str>::from))
         } // if
 
         // Result type(s) key/value pair:
         if let Some(result_types) = &self.result_types {
-            query.push_str("&result_type=");
-            query.push_str(
-                &utf8_percent_encode(
-                    &result_types
-                        .iter()
-                        .map(String::from)
-                        .collect::<Vec<String>>()
-                        .join("|"),
-                    NON_ALPHANUMERIC,
-                ).to_string(),
-            ) // push_str
+            query.push_encoded_list("result_type", result_types.iter().map(<&str>::from))
         } // if
 
         // Set query string in ReverseRequest struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified ReverseRequest struct to caller.
         self
@@ -0,0 +1,140 @@
+use crate::client::GoogleMapsClient;
+use crate::geocoding::{forward::component::Component, reverse::ReverseRequest};
+use crate::types::{Language, LatLng, LocationType, PlaceType};
+use serde::{Deserialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+//
+/// The serializable parameters of a `ReverseRequest`. A `ReverseRequest` holds a
+/// reference to its client, so it can not be deserialized on its own. The
+/// parameters are deserialized into this struct first, and then bound to a
+/// client.
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Parameters {
+    latlng: LatLng,
+    #[serde(default)]
+    components: Option<Vec<Component>>,
+    #[serde(default)]
+    language: Option<Language>,
+    #[serde(default)]
+    location_types: Option<Vec<LocationType>>,
+    #[serde(default)]
+    result_types: Option<Vec<PlaceType>>,
+} // struct
+
+// =============================================================================
+
+impl<'a> ReverseRequest<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Deserializes a request's parameters, such as those previously saved
+    /// by serializing a `ReverseRequest`, and binds them to a client.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ The client that the request will be sent with.
+    ///
+    /// * `deserializer` ‧ Any `serde` deserializer, for example a
+    /// `serde_json::Deserializer`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let json = serde_json::to_string(&google_maps_client.reverse_geocoding(
+    ///     LatLng::try_from_dec(dec!(40.714_224), dec!(-73.961_452))?,
+    /// ).with_result_type(PlaceType::StreetAddress))?;
+    ///
+    /// let location = GeocodingReverseRequest::deserialize_with_client(
+    ///     &google_maps_client,
+    ///     &mut serde_json::Deserializer::from_str(&json),
+    /// )?
+    /// .execute()
+    /// .await?;
+    /// ```
+
+    pub fn deserialize_with_client<'de, D: Deserializer<'de>>(
+        client: &'a GoogleMapsClient,
+        deserializer: D,
+    ) -> Result<ReverseRequest<'a>, D::Error> {
+
+        let parameters = Parameters::deserialize(deserializer)?;
+
+        Ok(ReverseRequest {
+            // Required parameters:
+            client,
+            latlng: parameters.latlng,
+            // Optional parameters:
+            components: parameters.components,
+            language: parameters.language.or_else(|| client.default_language.clone()),
+            location_types: parameters.location_types,
+            result_types: parameters.result_types,
+            // Internal use only:
+            billing_tag: None,
+            query: None,
+        }) // ReverseRequest
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::Country;
+    use rust_decimal_macros::dec;
+
+    fn request(client: &GoogleMapsClient) -> ReverseRequest<'_> {
+        let mut request = client.reverse_geocoding(LatLng::try_from_dec(dec!(43.6532), dec!(-79.3832)).unwrap());
+        request.components = Some(vec![Component::Country(Country::Canada)]);
+        request.language = Some(Language::French);
+        request.location_types = Some(vec![LocationType::RoofTop]);
+        request.result_types = Some(vec![PlaceType::StreetAddress, PlaceType::Restaurant]);
+        request
+    } // fn
+
+    #[test]
+    fn round_trips_every_parameter() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let json = serde_json::to_value(request(&client)).unwrap();
+        // Every parameter is populated, so that a parameter that is missing
+        // from `Parameters` is rejected as an unknown field:
+        assert!(
+            json.as_object().unwrap().values().all(|value| !value.is_null()),
+            "a parameter is not populated: {json}",
+        ); // assert!
+        let restored = ReverseRequest::deserialize_with_client(&client, json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(restored).unwrap(), json);
+    } // fn
+
+    #[test]
+    fn rejects_unknown_parameters() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let mut json = serde_json::to_value(request(&client)).unwrap();
+        json["unknown"] = serde_json::Value::Bool(true);
+        assert!(ReverseRequest::deserialize_with_client(&client, json).is_err());
+    } // fn
+
+    #[test]
+    fn falls_back_to_the_client_default() {
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client.with_default_language(Language::German);
+        let client = client.build();
+        let saved = request(&client);
+        let mut json = serde_json::to_value(&saved).unwrap();
+        // A saved language takes precedence over the default:
+        let restored = ReverseRequest::deserialize_with_client(&client, json.clone()).unwrap();
+        assert_eq!(restored.language, saved.language);
+        // A request saved without one gets the client's default, just like a
+        // new request:
+        json.as_object_mut().unwrap().remove("language");
+        let restored = ReverseRequest::deserialize_with_client(&client, json).unwrap();
+        assert_eq!(restored.language, Some(Language::German));
+    } // fn
+
+} // mod
@@ -30,4 +30,35 @@ impl<'a> GeocodingReverseRequest<'a> {
 
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, and returns the raw JSON response body
+    /// along with the response.
+    ///
+    /// ## Description:
+    ///
+    /// Works like `execute`, but also returns the response body exactly as
+    /// Google sent it. Fields that this crate does not model yet are dropped
+    /// from the deserialized response, but remain in the raw body. This is
+    /// useful for debugging, or for reading fields that Google has recently
+    /// added.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let (response, body) = request.execute_with_raw().await?;
+    /// ```
+
+    pub async fn execute_with_raw(
+        &'a mut self
+    ) -> Result<(GeocodingResponse, String), GoogleMapsError> {
+
+        self.build().get_with_raw().await
+
+    } // fn
+
 } // impl
\ No newline at end of file
@@ -5,12 +5,15 @@
 //! geocoding converts latitude & longitude coordinates to a street address.
 
 mod build;
+mod deserialize_with_client;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
 #[cfg(feature = "enable-reqwest")]
 mod get;
 mod new;
 mod query_url;
+mod with_billing_tag;
+mod with_components;
 mod with_language;
 mod with_location_types;
 mod with_result_types;
@@ -18,7 +21,9 @@ mod with_result_types;
 // -----------------------------------------------------------------------------
 
 use crate::client::GoogleMapsClient;
+use crate::geocoding::forward::component::Component;
 use crate::types::{Language, LatLng, LocationType, PlaceType};
+use serde::Serialize;
 
 // -----------------------------------------------------------------------------
 //
@@ -26,8 +31,13 @@ use crate::types::{Language, LatLng, LocationType, PlaceType};
 /// _Geocoding API_ query**. The methods implemented for this struct are what's
 /// used to build your request. Reverse geocoding looks up a street address
 /// from latitude & longitude coorindates.
+///
+/// A request's parameters may be serialized, for example to save a search to
+/// disk. The client, and any query string that has already been built, are not
+/// serialized. Use `ReverseRequest::deserialize_with_client` to load the
+/// parameters again.
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct ReverseRequest<'a> {
 
     // Required parameters:
@@ -35,6 +45,7 @@ pub struct ReverseRequest<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
+    #[serde(skip)]
     client: &'a GoogleMapsClient,
 
     /// The latitude and longitude values specifying the location for which you
@@ -44,6 +55,12 @@ pub struct ReverseRequest<'a> {
     // Optional parameters:
     // --------------------
 
+    /// A components filter with elements separated by a pipe (|). Only the
+    /// `country` component is meaningful for reverse geocoding. If the
+    /// parameter contains multiple components, the API evaluates them as an
+    /// AND, not an OR.
+    components: Option<Vec<Component>>,
+
     /// The language in which to return results.
     language: Option<Language>,
 
@@ -68,7 +85,14 @@ pub struct ReverseRequest<'a> {
     // Internal use only:
     // ------------------
 
+    /// A caller-defined tag, such as a product feature, that is recorded in the
+    /// `billing_tag` field of the request's `tracing` span. It is never sent to
+    /// Google.
+    #[serde(skip)]
+    billing_tag: Option<String>,
+
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
     query: Option<String>,
 
 } // impl
\ No newline at end of file
@@ -29,10 +29,12 @@ impl<'a> ReverseRequest<'a> {
             client,
             latlng,
             // Optional parameters:
-            language: None,
+            components: None,
+            language: client.default_language.clone(),
             location_types: None,
             result_types: None,
             // Internal use only:
+            billing_tag: None,
             query: None,
         } // struct
 
@@ -65,10 +67,12 @@ impl<'a> ReverseRequest<'a> {
             client,
             latlng: LatLng::try_from(coordinate)?,
             // Optional parameters:
-            language: None,
+            components: None,
+            language: client.default_language.clone(),
             location_types: None,
             result_types: None,
             // Internal use only:
+            billing_tag: None,
             query: None,
         }) // struct
 
@@ -101,10 +105,12 @@ impl<'a> ReverseRequest<'a> {
             client,
             latlng: LatLng::try_from(point)?,
             // Optional parameters:
-            language: None,
+            components: None,
+            language: client.default_language.clone(),
             location_types: None,
             result_types: None,
             // Internal use only:
+            billing_tag: None,
             query: None,
         }) // struct
 
@@ -0,0 +1,35 @@
+use crate::geocoding::reverse::ReverseRequest;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> ReverseRequest<'a> {
+
+    /// Tags the request with your own category, such as a product feature or
+    /// a customer, for accounting purposes.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `billing_tag` ‧ The tag to record with the request.
+    ///
+    /// ## Description:
+    ///
+    /// The tag is never sent to Google. It is recorded in the `billing_tag`
+    /// field of the `tracing` span that wraps the request, alongside the
+    /// `request_id`. A `tracing` layer that collects metrics or costs can then
+    /// group requests by your own categories, independently of Google's SKUs.
+    ///
+    /// ## Example:
+    ///
+    /// * Attribute the request to the checkout feature:
+    /// ```rust
+    /// .with_billing_tag("checkout")
+    /// ```
+
+    pub fn with_billing_tag(&'a mut self, billing_tag: &str) -> &'a mut ReverseRequest {
+        // Set billing tag in ReverseRequest struct.
+        self.billing_tag = Some(billing_tag.to_string());
+        // Return modified ReverseRequest struct to caller.
+        self
+    } // fn
+
+} // impl
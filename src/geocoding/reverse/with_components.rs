@@ -0,0 +1,150 @@
+use crate::geocoding::{forward::component::Component, reverse::ReverseRequest};
+
+impl<'a> ReverseRequest<'a> {
+
+    /// Restricts the results from the reverse geocoder to the specified
+    /// component(s).
+    ///
+    /// # Arguments:
+    ///
+    /// * `component` - A single component filter of `Component` type.
+    ///
+    /// # Description:
+    ///
+    /// [Component
+    /// Filtering](https://developers.google.com/maps/documentation/geocoding/requests-reverse-geocoding#optional-parameters)
+    ///
+    /// A reverse geocoding request may be narrowed with a `components` filter.
+    /// This is most useful with the `Component::Country` filter: a coordinate
+    /// near a border can be made to resolve within the intended country.
+    ///
+    /// Notes about component filtering:
+    ///
+    /// * If the request contains multiple component filters, the API evaluates
+    /// them as an AND, not an OR.
+    ///
+    /// * The `components` filter is applied together with any `result_type`
+    /// and `location_type` filters. The API only returns results that match
+    /// all of the filters. If none of the results match, the API returns
+    /// `ZERO_RESULTS`.
+    ///
+    /// # Examples:
+    ///
+    /// * A single component filter. This example restricts results to
+    /// Switzerland:
+    ///
+    /// ```rust
+    /// .with_component(GeocodingComponent::Country(Country::Switzerland))
+    /// ```
+
+    pub fn with_component(
+        &'a mut self,
+        component: Component
+    ) -> &'a mut ReverseRequest {
+        // Add component to ReverseRequest struct.
+        match &mut self.components {
+            // If there are no components in the request struct, initialize:
+            None => self.components = Some(vec![component]),
+            // If there are already components, append to them:
+            Some(components) => components.push(component),
+        } // match
+        // Return modified ReverseRequest struct to caller.
+        self
+    } // fn
+
+    /// Restricts the results from the reverse geocoder to the specified
+    /// component(s).
+    ///
+    /// # Description
+    ///
+    /// Multiple component filters are evaluated as an AND, not an OR. They are
+    /// also applied together with any `result_type` and `location_type`
+    /// filters.
+    ///
+    /// # Example:
+    ///
+    /// * Alternatively, multiple component filters may be passed in a single
+    /// method call by passing a Vec:
+    ///
+    /// ```rust
+    /// .with_components(&vec![
+    ///     GeocodingComponent::Country(Country::France),
+    /// ])
+    /// ```
+
+    pub fn with_components(
+        &'a mut self,
+        components_slice: &[Component]
+    ) -> &'a mut ReverseRequest {
+        // Add components to ReverseRequest struct.
+        match &mut self.components {
+            // If there are no filters in the request struct, initialize field:
+            None => self.components = Some(components_slice.to_vec()),
+            // If there are already filters, append to them:
+            Some(components) => components_slice.iter().for_each(|component|
+                components.push(component.to_owned())
+            ), // iter
+        } // match
+        // Return modified ReverseRequest struct to caller.
+        self
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::GoogleMapsClient;
+    use crate::geocoding::forward::component::Component;
+    use crate::types::{Country, LatLng, LocationType};
+    use percent_encoding::percent_decode_str;
+    use rust_decimal_macros::dec;
+
+    /// Returns the raw value of the named parameter in a URL's query string.
+    fn parameter<'a>(url: &'a str, name: &str) -> &'a str {
+        let (_path, query) = url.split_once('?').unwrap();
+        query
+            .split('&')
+            .find_map(|parameter| parameter.strip_prefix(name)?.strip_prefix('='))
+            .unwrap_or_else(|| panic!("`{name}` is missing from `{url}`"))
+    } // fn
+
+    #[test]
+    fn components_are_encoded() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        // The Ambassador Bridge, between Windsor, Ontario and Detroit,
+        // Michigan:
+        let url = client
+            .reverse_geocoding(LatLng::try_from_dec(dec!(42.3118), dec!(-83.0740)).unwrap())
+            .with_component(Component::Country(Country::Canada))
+            .with_components(&[Component::AdministrativeArea(String::from("Ontario & Québec"))])
+            .with_location_type(LocationType::RoofTop)
+            .query_url();
+
+        let components = parameter(&url, "components");
+        assert!(
+            components.chars().all(|character| character.is_ascii_alphanumeric() || character == '%'),
+            "`{components}` is not fully percent-encoded",
+        ); // assert!
+        assert_eq!(
+            percent_decode_str(components).decode_utf8().unwrap(),
+            "country:CA|administrative_area:Ontario & Québec",
+        ); // assert_eq!
+
+        // The components filter is sent alongside the other filters, which
+        // Google combines with it:
+        assert_eq!(parameter(&url, "location_type"), "ROOFTOP");
+    } // fn
+
+    #[test]
+    fn components_are_omitted_by_default() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let url = client
+            .reverse_geocoding(LatLng::try_from_dec(dec!(42.3118), dec!(-83.0740)).unwrap())
+            .query_url();
+        assert!(!url.contains("components="), "{url}");
+    } // fn
+
+} // mod
@@ -0,0 +1,75 @@
+//! Geolocation API error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use crate::geolocation::response::error_object::ErrorObject;
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced by the Google Maps Geolocation API client.
+
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(code(google_maps::geolocation::error), url(docsrs))]
+pub enum Error {
+    /// Google Maps Geolocation API server generated an error. For example, a
+    /// `notFound` error is returned when the cell towers and WiFi access points
+    /// could not be geolocated. See the `ErrorObject` struct for more
+    /// information.
+    GoogleMapsService(ErrorObject),
+    /// The HTTP request was unsuccessful.
+    HttpUnsuccessful(String),
+    /// The dependency library Reqwest generated an error.
+    #[cfg(feature = "enable-reqwest")]
+    Reqwest(reqwest::Error),
+    /// The dependency library Serde JSON generated an error.
+    SerdeJson(serde_json::error::Error),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::GoogleMapsService(error_object) => write!(f,
+                "Google Maps Geolocation API service: {} ({})",
+                error_object.message,
+                error_object.code),
+            Error::HttpUnsuccessful(status) => write!(f,
+                "Google Maps Geolocation API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status."),
+            #[cfg(feature = "enable-reqwest")]
+            Error::Reqwest(error) => write!(f, "Google Maps Geolocation API client in the Reqwest library: {error}"),
+            Error::SerdeJson(error) => write!(f, "Google Maps Geolocation API client in the Serde JSON library: {error}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl From<reqwest::Error> for Error {
+    /// This trait converts from an Reqwest error type (`reqwest::Error`) into a
+    /// Google Maps Geolocation API error type
+    /// (`google_maps::geolocation::error::Error`) by wrapping it inside. This
+    /// function is required to use the `?` operator.
+    fn from(error: reqwest::Error) -> Error {
+        Error::Reqwest(error)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<serde_json::error::Error> for Error {
+    /// This trait converts from an Serde JSON (`serde_json::error::Error`)
+    /// error type into a Google Maps Geolocation API error type
+    /// (`google_maps::geolocation::error::Error`) by wrapping it inside. This
+    /// function is required to use the `?` operator.
+    fn from(error: serde_json::error::Error) -> Error {
+        Error::SerdeJson(error)
+    } // fn
+} // impl
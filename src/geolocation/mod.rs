@@ -29,5 +29,19 @@
 //! request. If the response is a `404`, you've confirmed that your
 //! `wifiAccessPoints` and `cellTowers` objects could not be geolocated.
 
+pub mod error;
 pub mod request;
-pub mod response;
\ No newline at end of file
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+const SERVICE_URL: &str = "https://www.googleapis.com/geolocation/v1/geolocate";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::geolocation::{
+    error::Error as GeolocationError,
+    request::Request as GeolocationRequest,
+    response::Response as GeolocationResponse,
+}; // crate::geolocation
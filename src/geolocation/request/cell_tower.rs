@@ -10,21 +10,21 @@ pub struct CellTower {
     /// UTRAN/GERAN Cell Identity (UC-Id), which is a 32-bit value concatenating
     /// the Radio Network Controller (RNC) and Cell ID. Specifying only the
     /// 16-bit Cell ID value in WCDMA networks may return inaccurate results.
-    #[serde(alias = "cellId")]
+    #[serde(rename = "cellId")]
     pub cell_id: u32,
 
     /// The Location Area Code (LAC) for GSM and WCDMA networks. The Network ID
     /// (NID) for CDMA networks.
-    #[serde(alias = "locationAreaCode")]
+    #[serde(rename = "locationAreaCode")]
     pub location_area_code: u16,
 
     /// The cell tower's Mobile Country Code (MCC).
-    #[serde(alias = "mobileCountryCode")]
+    #[serde(rename = "mobileCountryCode")]
     pub mobile_country_code: u16,
 
     /// The cell tower's Mobile Network Code. This is the MNC for GSM and WCDMA;
     /// CDMA uses the System ID (SID).
-    #[serde(alias = "mobileNetworkCode")]
+    #[serde(rename = "mobileNetworkCode")]
     pub mobile_network_code: u16,
 
     // Optional parameters:
@@ -35,11 +35,11 @@ pub struct CellTower {
     pub age: Option<u16>,
 
     /// Radio signal strength measured in dBm.
-    #[serde(alias = "signalStrength")]
+    #[serde(rename = "signalStrength")]
     pub signal_strength: Option<i16>,
 
     /// The [timing advance](https://en.wikipedia.org/wiki/Timing_advance)
     /// value.
-    #[serde(alias = "timingAdvance")]
+    #[serde(rename = "timingAdvance")]
     pub timing_advance: Option<u16>,
 } // struct
\ No newline at end of file
@@ -0,0 +1,153 @@
+use crate::client::GoogleMapsClient;
+use crate::geolocation::request::{
+    cell_tower::CellTower,
+    radio_type::RadioType,
+    wifi_access_point::WiFiAccessPoint,
+    Request,
+}; // crate::geolocation::request
+use serde::{Deserialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+//
+/// The serializable parameters of a `Request`. A `Request` holds a reference to
+/// its client, so it can not be deserialized on its own. The parameters are
+/// deserialized into this struct first, and then bound to a client.
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Parameters {
+    #[serde(default)]
+    carrier: Option<String>,
+    #[serde(rename = "cellTowers")]
+    #[serde(default)]
+    cell_towers: Option<Vec<CellTower>>,
+    #[serde(rename = "considerIp")]
+    #[serde(default)]
+    consider_ip: Option<bool>,
+    #[serde(rename = "homeMobileCountryCode")]
+    #[serde(default)]
+    home_mobile_country_code: Option<u16>,
+    #[serde(rename = "homeMobileNetworkCode")]
+    #[serde(default)]
+    home_mobile_network_code: Option<u16>,
+    #[serde(rename = "radioType")]
+    #[serde(default)]
+    radio_type: Option<RadioType>,
+    #[serde(rename = "wifiAccessPoints")]
+    #[serde(default)]
+    wifi_access_points: Option<Vec<WiFiAccessPoint>>,
+} // struct
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Deserializes a request's parameters, such as those previously saved
+    /// by serializing a `Request`, and binds them to a client.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ The client that the request will be sent with.
+    ///
+    /// * `deserializer` ‧ Any `serde` deserializer, for example a
+    /// `serde_json::Deserializer`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let mut request = google_maps_client.geolocation();
+    /// request.consider_ip = Some(true);
+    /// let json = serde_json::to_string(&request)?;
+    ///
+    /// let location = GeolocationRequest::deserialize_with_client(
+    ///     &google_maps_client,
+    ///     &mut serde_json::Deserializer::from_str(&json),
+    /// )?
+    /// .execute()
+    /// .await?;
+    /// ```
+
+    pub fn deserialize_with_client<'de, D: Deserializer<'de>>(
+        client: &'a GoogleMapsClient,
+        deserializer: D,
+    ) -> Result<Request<'a>, D::Error> {
+
+        let parameters = Parameters::deserialize(deserializer)?;
+
+        Ok(Request {
+            // Required parameters:
+            client,
+            // Optional parameters:
+            carrier: parameters.carrier,
+            cell_towers: parameters.cell_towers,
+            consider_ip: parameters.consider_ip,
+            home_mobile_country_code: parameters.home_mobile_country_code,
+            home_mobile_network_code: parameters.home_mobile_network_code,
+            radio_type: parameters.radio_type,
+            wifi_access_points: parameters.wifi_access_points,
+            // Internal use only:
+            billing_tag: None,
+        }) // Request
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn request(client: &GoogleMapsClient) -> Request<'_> {
+        let mut request = client.geolocation();
+        request.carrier = Some(String::from("Vodafone"));
+        request.cell_towers = Some(vec![CellTower {
+            cell_id: 42,
+            location_area_code: 415,
+            mobile_country_code: 310,
+            mobile_network_code: 410,
+            age: Some(0),
+            signal_strength: Some(-60),
+            timing_advance: Some(15),
+        }]); // vec!
+        request.consider_ip = Some(false);
+        request.home_mobile_country_code = Some(310);
+        request.home_mobile_network_code = Some(410);
+        request.radio_type = Some(RadioType::Gsm);
+        request.wifi_access_points = Some(vec![WiFiAccessPoint {
+            mac_address: String::from("00:25:9c:cf:1c:ac"),
+            signal_strength: Some(-43),
+            age: Some(0),
+            channel: Some(11),
+            signal_to_noise_ratio: Some(0),
+        }]); // vec!
+        request
+    } // fn
+
+    #[test]
+    fn round_trips_every_parameter() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let json = serde_json::to_value(request(&client)).unwrap();
+        // Every parameter is populated, so that a parameter that is missing
+        // from `Parameters` is rejected as an unknown field:
+        assert!(
+            json.as_object().unwrap().values().all(|value| !value.is_null()),
+            "a parameter is not populated: {json}",
+        ); // assert!
+        let restored = Request::deserialize_with_client(&client, json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(restored).unwrap(), json);
+    } // fn
+
+    #[test]
+    fn rejects_unknown_parameters() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let mut json = serde_json::to_value(request(&client)).unwrap();
+        json["unknown"] = serde_json::Value::Bool(true);
+        assert!(Request::deserialize_with_client(&client, json).is_err());
+    } // fn
+
+} // mod
@@ -0,0 +1,164 @@
+use crate::error::Error as GoogleMapsError;
+use crate::geolocation::{
+    SERVICE_URL,
+    error::Error as GeolocationError,
+    request::Request as GeolocationRequest,
+    response::Response as GeolocationResponse,
+}; // crate::geolocation
+use crate::request_rate::api::Api;
+
+// =============================================================================
+
+impl<'a> GeolocationRequest<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description:
+    ///
+    /// The request is sent to Google as a JSON body in an HTTP POST request.
+    /// Any rate limiting for `Api::Geolocation` is observed, and any endpoint
+    /// override is honoured. Unlike the other APIs, Geolocation requests are
+    /// not cached or retried.
+    ///
+    /// Google's Geolocation API currently responds `404 Not Found` with an
+    /// empty body to every request. When this happens,
+    /// `google_maps::Error::ServiceUnavailable` is returned. A `404 Not Found`
+    /// that does contain an error object, such as when the cell towers and
+    /// WiFi access points could not be geolocated, is returned as
+    /// `google_maps::geolocation::error::Error::GoogleMapsService`. If Google
+    /// restores the service, successful responses are deserialized as usual.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "debug", name = "Google Maps Geolocation", skip(self), fields(billing_tag = self.billing_tag.as_deref()))]
+    pub async fn execute(
+        &'a mut self
+    ) -> Result<GeolocationResponse, GoogleMapsError> {
+
+        let client = self.client;
+
+        // Without an API key, Google will deny every request:
+        let key = match &client.key_source {
+            Some(key_source) => key_source.next_key(&Api::Geolocation),
+            None => client.key.clone(),
+        }; // match
+        if key.trim().is_empty() {
+            tracing::error!("{}", GoogleMapsError::MissingApiKey);
+            return Err(GoogleMapsError::MissingApiKey);
+        } // if
+
+        // Send the request to the API's overridden endpoint, if there is one:
+        let url = client.endpoint_url(&Api::Geolocation, &format!("{SERVICE_URL}?key={key}"));
+        let body = serde_json::to_string(self).map_err(GeolocationError::from)?;
+
+        // Emit debug message so client can monitor activity:
+        tracing::debug!("Making HTTP POST request to Google Maps Geolocation API: `{body}`");
+
+        // Observe any rate limiting before executing request:
+        client.rate_limit.limit_apis(vec![&Api::All, &Api::Geolocation]).await;
+
+        let mut request = client.reqwest_client
+            .post(url)
+            .headers(client.headers.clone())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body);
+
+        if let Some(timeout) = client.timeout {
+            request = request.timeout(timeout);
+        } // if
+
+        let response = request.send().await.map_err(GeolocationError::from)?;
+        let status = response.status();
+        let text = response.text().await.map_err(GeolocationError::from)?;
+
+        // A `404 Not Found` with an empty body means that the service itself
+        // could not be found, rather than the device's location:
+        if status == reqwest::StatusCode::NOT_FOUND && text.trim().is_empty() {
+            let error = GoogleMapsError::ServiceUnavailable { api: Api::Geolocation };
+            tracing::error!("{}", error);
+            return Err(error);
+        } // if
+
+        match serde_json::from_str::<GeolocationResponse>(&text) {
+            // Google reported an error in the response payload:
+            Ok(GeolocationResponse { error: Some(error_object), .. }) => {
+                let error = GeolocationError::GoogleMapsService(error_object);
+                tracing::error!("{}", error);
+                Err(error)?
+            }, // Ok
+            // The HTTP request was unsuccessful, and Google did not explain
+            // why:
+            _ if !status.is_success() => {
+                tracing::error!("HTTP client returned: {}", status);
+                Err(GeolocationError::HttpUnsuccessful(status.to_string()))?
+            }, // _
+            Ok(response) => Ok(response),
+            Err(error) => {
+                tracing::error!("JSON parsing error: {}", error);
+                Err(GeolocationError::SerdeJson(error))?
+            }, // Err
+        } // match
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::test_server::{block_on, TestServer};
+    use crate::client::{GoogleMapsClient, RetryPolicy};
+    use crate::error::Error as GoogleMapsError;
+    use crate::geolocation::error::Error as GeolocationError;
+    use crate::request_rate::api::Api;
+
+    /// Sends a Geolocation request to a test server that answers with the
+    /// specified status and body.
+    fn geolocate(status: u16, body: &str) -> Result<crate::geolocation::response::Response, GoogleMapsError> {
+        let server = TestServer::start(status, &[], body);
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_endpoint_override(Api::Geolocation, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none());
+        let client = client.build();
+        let result = block_on(client.geolocation().execute());
+        assert_eq!(server.targets().len(), 1);
+        result
+    } // fn
+
+    #[test]
+    fn not_found_with_an_empty_body_is_service_unavailable() {
+        assert!(matches!(
+            geolocate(404, ""),
+            Err(GoogleMapsError::ServiceUnavailable { api: Api::Geolocation }),
+        )); // assert!
+    } // fn
+
+    #[test]
+    fn not_found_with_an_error_object_is_a_service_error() {
+        let body = r#"{"error":{"errors":[{"domain":"geolocation","reason":"notFound",
+            "message":"Not Found"}],"code":404,"message":"Not Found"}}"#;
+        match geolocate(404, body) {
+            Err(GoogleMapsError::Geolocation(GeolocationError::GoogleMapsService(error_object))) => {
+                assert_eq!(error_object.code, 404);
+                assert_eq!(error_object.errors[0].reason, "notFound");
+            }, // Err
+            other => panic!("unexpected result: {other:?}"),
+        } // match
+    } // fn
+
+    #[test]
+    fn successful_response_is_deserialized() {
+        let response = geolocate(200, r#"{"location":{"lat":51.0,"lng":-114.1},"accuracy":1200.4}"#)
+            .unwrap();
+        assert!(response.location.is_some());
+        assert_eq!(response.accuracy, Some(1200.4));
+    } // fn
+
+} // mod
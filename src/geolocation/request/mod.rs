@@ -1,23 +1,43 @@
-mod cell_tower;
-mod radio_type;
-mod wifi_access_point;
-
+//! **Look in this module for documentation on building your _Geolocation API_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+mod deserialize_with_client;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+mod new;
+mod with_billing_tag;
+pub mod cell_tower;
+pub mod radio_type;
+pub mod wifi_access_point;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
 use crate::geolocation::request::cell_tower::CellTower;
 use crate::geolocation::request::radio_type::RadioType;
 use crate::geolocation::request::wifi_access_point::WiFiAccessPoint;
+use serde::Serialize;
 
-/// The request body must be formatted as JSON. All fields are optional.
+// -----------------------------------------------------------------------------
+//
+/// The request body is sent to Google as JSON. All fields are optional.
+///
+/// The same JSON may be saved, for example to disk, and loaded again with
+/// `Request::deserialize_with_client`. The client is not serialized.
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Request {
+#[derive(Debug, Serialize)]
+pub struct Request<'a> {
     // Required parameters:
     // --------------------
 
-    /// Your application's API key. This key identifies your application for
-    /// purposes of quota management. Learn how to
-    /// [get a key](https://developers.google.com/maps/documentation/timezone/get-api-key).
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries." It is only read when
+    /// the request is sent, which requires the `enable-reqwest` feature.
     #[serde(skip)]
-    key: String,
+    #[cfg_attr(not(feature = "enable-reqwest"), allow(dead_code))]
+    client: &'a GoogleMapsClient,
 
     // Optional parameters:
     // --------------------
@@ -66,15 +86,9 @@ pub struct Request {
     // Internal use only:
     // ------------------
 
-    /// Request body that is to be submitted to the Google Cloud Maps Platform.
-    #[serde(skip)]
-    body: Option<String>,
-
-    /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    #[serde(skip)]
-    query: Option<String>,
-
-    /// Has the request been validated?
+    /// A caller-defined tag, such as a product feature, that is recorded in the
+    /// `billing_tag` field of the request's `tracing` span. It is never sent to
+    /// Google.
     #[serde(skip)]
-    validated: bool,
-} // struct
\ No newline at end of file
+    billing_tag: Option<String>,
+} // struct
@@ -0,0 +1,39 @@
+use crate::client::GoogleMapsClient;
+use crate::geolocation::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Geolocation API query. All of the
+    /// request's fields are optional, and may be set directly.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+
+    pub fn new(
+        client: &GoogleMapsClient,
+    ) -> Request {
+
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            // Optional parameters:
+            carrier: None,
+            cell_towers: None,
+            consider_ip: None,
+            home_mobile_country_code: None,
+            home_mobile_network_code: None,
+            radio_type: None,
+            wifi_access_points: None,
+            // Internal use only:
+            billing_tag: None,
+        } // struct
+
+    } // fn
+
+} // impl
@@ -5,15 +5,15 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub enum RadioType {
     /// Code-Division Multiple Access
-    #[serde(alias = "cdma")]
+    #[serde(rename = "cdma")]
     Cdma,
     /// Global System for Mobile communications
-    #[serde(alias = "gsm")]
+    #[serde(rename = "gsm")]
     Gsm,
     /// Long-Term Evolution
-    #[serde(alias = "lte")]
+    #[serde(rename = "lte")]
     Lte,
     /// Wideband Code-Division Multiple Access
-    #[serde(alias = "wcdma")]
+    #[serde(rename = "wcdma")]
     Wcdma,
 } // enum
\ No newline at end of file
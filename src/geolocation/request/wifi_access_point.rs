@@ -7,25 +7,25 @@ pub struct WiFiAccessPoint {
 
     /// (required) The MAC address of the WiFi node. It's typically called a
     /// BSS, BSSID or MAC address. Separators must be `:` (colon).
-    #[serde(alias = "macAddress")]
+    #[serde(rename = "macAddress")]
     pub mac_address: String,
 
     // Optional parameters:
     // --------------------
 
     /// The current signal strength measured in dBm.
-    #[serde(alias = "signalStrength")]
+    #[serde(rename = "signalStrength")]
     pub signal_strength: Option<i16>,
 
     /// The number of milliseconds since this access point was detected.
-    #[serde(alias = "age")]
+    #[serde(rename = "age")]
     pub age: Option<u16>,
 
     /// The channel over which the client is communicating with the access point.
-    #[serde(alias = "channel")]
+    #[serde(rename = "channel")]
     pub channel: Option<u16>,
 
     /// The current signal to noise ratio measured in dB.
-    #[serde(alias = "signalToNoiseRatio")]
+    #[serde(rename = "signalToNoiseRatio")]
     pub signal_to_noise_ratio: Option<i16>,
 } // struct
\ No newline at end of file
@@ -2,12 +2,12 @@
 //! from the Google Maps Platform. Look in here for more information about the
 //! data returned from Google's server and how to parse it with your program.
 
-mod error_object;
-mod error;
+pub mod error;
+pub mod error_object;
 
 use crate::{
     geolocation::response::error_object::ErrorObject,
-    latlng::LatLng,
+    types::LatLng,
 }; // use
 use serde::{Deserialize, Serialize};
 
@@ -0,0 +1,88 @@
+//! Helpers for converting the HTML snippets returned by Google into plain
+//! text.
+
+// -----------------------------------------------------------------------------
+
+/// Converts HTML returned by Google, such as a step's `html_instructions` or a
+/// photo's `html_attributions`, into plain text: tags are removed, block
+/// elements (such as `<div>`) are separated from the preceding text by a space,
+/// and common named & numeric HTML entities are decoded.
+
+pub(crate) fn html_to_plain_text(html: &str) -> String {
+
+    // Remove tags:
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    let mut tag = String::new();
+
+    for character in html.chars() {
+        match (in_tag, character) {
+            (false, '<') => { in_tag = true; tag.clear(); },
+            (true, '>') => {
+                in_tag = false;
+                // Google places some notes, such as "Destination will be on
+                // the right", in a `<div>`. Keep them apart from the text that
+                // precedes them:
+                let name = tag.trim_start_matches('/').to_ascii_lowercase();
+                if (name.starts_with("div") || name.starts_with("br")) && !text.ends_with(' ') {
+                    text.push(' ');
+                } // if
+            },
+            (true, character) => tag.push(character),
+            (false, character) => text.push(character),
+        } // match
+    } // for
+
+    // Decode entities:
+    let mut decoded = String::with_capacity(text.len());
+    let mut remainder = text.as_str();
+
+    while let Some(start) = remainder.find('&') {
+        decoded.push_str(&remainder[..start]);
+        remainder = &remainder[start..];
+        let entity = remainder
+            .find(';')
+            .map(|end| (&remainder[1..end], end))
+            .and_then(|(name, end)| decode_entity(name).map(|character| (character, end)));
+        match entity {
+            Some((character, end)) => {
+                decoded.push(character);
+                remainder = &remainder[end + 1..];
+            }, // Some
+            None => {
+                decoded.push('&');
+                remainder = &remainder[1..];
+            }, // None
+        } // match
+    } // while
+
+    decoded.push_str(remainder);
+
+    // Collapse runs of whitespace:
+    decoded.split_whitespace().collect::<Vec<&str>>().join(" ")
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Decodes the name of an HTML entity (the text between `&` and `;`) into a
+/// character. Returns `None` if the entity is not recognized.
+
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = name.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse::<u32>().ok()?,
+            }; // match
+            char::from_u32(code)
+        }, // _
+    } // match
+} // fn
@@ -248,8 +248,11 @@
 //! Google's Geolocation API seems to be offline. While the online documentation
 //! is still available and the API appears configurable through the Google Cloud
 //! Platform console, the Geolocation API responds Status code `404 Not Found`
-//! with an empty body to all requests. This API cannot be implemented until the
-//! server responds as expected.
+//! with an empty body to all requests.
+//!
+//! The request and response are implemented behind the `geolocation` feature
+//! flag. Until the server responds as expected, executing a request returns
+//! `Error::ServiceUnavailable`, rather than a generic HTTP error.
 //!
 //! ## Example Client Settings
 //!
@@ -281,12 +284,15 @@
 //! * distance_matrix
 //! * elevation
 //! * geocoding
+//! * geolocation (not enabled by default, see above)
 //! * places
 //! * roads
 //! * time_zone
 //! * enable-reqwest (uses [reqwest](https://crates.io/crates/reqwest) for
 //! querying Google Maps API).
 //! * geo (support for [geo](https://crates.io/crates/geo-types) crate types)
+//! * geojson (conversion of geocoding & place search results into
+//! [GeoJSON](https://geojson.org/) features)
 //!
 //! Note: The Places autocomplete APIs have been put in the `autocomplete`
 //! feature flag. The rest of the Places APIs will be put under the `places`
@@ -348,12 +354,11 @@
 //! # To do
 //!
 //! 1. Track both _requests_ and request _elements_ for rate limiting.
-//! 2. Make a generic get() function for that can be used by all APIs.
-//! 3. Convert explicit query validation to session types wherever reasonable.
-//! 4. [Places API](https://developers.google.com/places/web-service/intro).
+//! 2. Convert explicit query validation to session types wherever reasonable.
+//! 3. [Places API](https://developers.google.com/places/web-service/intro).
 //! Only partly implemented. If you would like to have any missing pieces
 //! implemented, please contact me.
-//! 5. [Roads API](https://developers.google.com/maps/documentation/roads/intro).
+//! 4. [Roads API](https://developers.google.com/maps/documentation/roads/intro).
 //! Only partly implemented. If you would like to have any missing pieces
 //! implemented, please contact me.
 
@@ -365,6 +370,10 @@
 // Common / global modules:
 
 mod client;
+#[cfg(any(feature = "directions", feature = "distance_matrix", feature = "places"))]
+mod html;
+#[cfg(feature = "geocoding")]
+mod query_string;
 mod serde;
 pub mod error;
 pub mod prelude;
@@ -381,6 +390,8 @@ pub mod distance_matrix;
 pub mod elevation;
 #[cfg(feature = "geocoding")]
 pub mod geocoding;
+#[cfg(feature = "geolocation")]
+pub mod geolocation;
 #[cfg(feature = "time_zone")]
 pub mod time_zone;
 pub mod places;
@@ -397,6 +408,9 @@ pub use crate::{
     types::error::Error as TypeError,
 }; // crate
 
+#[cfg(feature = "enable-reqwest")]
+pub use crate::client::{CircuitBreaker, CircuitState, EnvKeys, KeySource, MemoryCache, ResponseCache, RetryPolicy, RoundRobinKeys, TapEvent, Timer, TokioTimer};
+
 #[cfg(any(feature = "geocoding", feature = "places", feature = "geocoding"))]
 pub use crate::types::address_component::AddressComponent;
 #[cfg(any(feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
@@ -407,11 +421,13 @@ pub use crate::types::country::Country;
 pub use crate::types::geometry::Geometry;
 #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places", feature = "time_zone"))]
 pub use crate::types::language::Language;
-#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "elevation", feature = "geocoding", feature = "places", feature = "roads", feature = "time_zone"))]
+#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "elevation", feature = "geocoding", feature = "geolocation", feature = "places", feature = "roads", feature = "time_zone"))]
 pub use crate::types::latlng::LatLng;
 #[cfg(any(feature = "geocoding", feature = "places", feature = "geocoding"))]
 pub use crate::types::location_type::LocationType;
 #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
+pub use crate::types::place_id::PlaceId;
+#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
 pub use crate::types::place_type::PlaceType;
 #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
 pub use crate::types::region::Region;
@@ -422,4 +438,7 @@ pub use crate::types::region::Region;
 mod request_rate;
 
 #[cfg(feature = "enable-reqwest")]
-pub use crate::request_rate::api::Api;
\ No newline at end of file
+pub use crate::request_rate::api::Api;
+
+#[cfg(feature = "enable-reqwest")]
+pub use crate::request_rate::rate_limit_status::RateLimitStatus;
\ No newline at end of file
@@ -43,6 +43,23 @@ pub enum Error {
     /// sort order type code. See
     /// `google_maps\src\places\place_details\sort_order.rs` for more information.
     InvalidSortOrderCode(String),
+    /// A price level outside of Google's range of 0 (most affordable) to 4
+    /// (most expensive) was specified.
+    InvalidPriceLevel(u8),
+    /// The minimum price level that was specified is greater than the maximum
+    /// price level. The values are the minimum and maximum price levels.
+    MinPriceAboveMaxPrice(u8, u8),
+    /// The specified radius, in meters, is greater than Google's maximum of
+    /// 50,000 meters.
+    RadiusTooLarge(u32),
+    /// Results were ranked by distance, but neither a keyword nor a place type
+    /// was specified. Google requires at least one of them.
+    RankByDistanceWithoutKeywordOrType,
+    /// Google Maps Places API server reported that the daily usage limit
+    /// has been exceeded, or that billing is not set up for the API key. This
+    /// error is never retried, as retrying will not help until the quota is
+    /// reset or the billing and quota settings are fixed.
+    OverDailyLimit(Option<String>),
     /// The query string must be built before the request may be sent to the
     /// Google Maps Places API server.
     QueryNotBuilt,
@@ -55,6 +72,12 @@ pub enum Error {
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
     SerdeJson(serde_json::error::Error),
+    /// The HTTP request to the Google Maps Places API server timed out. See
+    /// `GoogleMapsClient::with_timeout` and
+    /// `GoogleMapsClient::with_connect_timeout`. This error is eligible for
+    /// retries.
+    #[cfg(feature = "enable-reqwest")]
+    Timeout(reqwest::Error),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -78,6 +101,9 @@ impl std::fmt::Display for Error {
                     Status::Ok => write!(f, "Google Maps Time Zone service: \
                         Ok. \
                         The request was successful."),
+                    Status::OverDailyLimit => write!(f, "Google Maps Places API service: \
+                        Over daily limit. \
+                        Usage cap has been exceeded, API key is invalid, billing has not been enabled, or method of payment is no longer valid."),
                     Status::OverQueryLimit => write!(f, "Google Maps Places API service: \
                         Over query limit. \
                         Requestor has exceeded quota."),
@@ -123,6 +149,26 @@ impl std::fmt::Display for Error {
             Error::InvalidSortOrderCode(sort_order_code) => write!(f, "Google Maps Places API client: \
                 `{sort_order_code}` is not a valid sort order code. \
                 Valid codes are `most_relevant` and `newest`."),
+            Error::InvalidPriceLevel(price_level) => write!(f, "Google Maps Places API client: \
+                `{price_level}` is not a valid price level. \
+                Valid price levels range from 0 (most affordable) to 4 (most expensive), inclusive."),
+            Error::MinPriceAboveMaxPrice(minprice, maxprice) => write!(f, "Google Maps Places API client: \
+                The minimum price level `{minprice}` is greater than the maximum price level `{maxprice}`. \
+                No places would be returned."),
+            Error::RadiusTooLarge(radius) => write!(f, "Google Maps Places API client: \
+                A radius of `{radius}` meters is greater than the maximum of 50,000 meters."),
+            Error::RankByDistanceWithoutKeywordOrType => write!(f, "Google Maps Places API client: \
+                Ranking results by distance requires a keyword or a place type. \
+                Use the `with_keyword` or `with_type` methods, or rank by prominence instead."),
+            Error::OverDailyLimit(error_message) => match error_message {
+                Some(error_message) => write!(f, "Google Maps Places API service: \
+                    Over daily limit. {error_message} \
+                    Check the billing and quota settings for your API key in the Google Cloud Console."),
+                None => write!(f, "Google Maps Places API service: \
+                    Over daily limit. \
+                    Usage cap has been exceeded, API key is invalid, billing has not been enabled, or method of payment is no longer valid. \
+                    Check the billing and quota settings for your API key in the Google Cloud Console."),
+            }, // match
             Error::QueryNotBuilt => write!(f, "Google Maps Places API client library: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
                 Ensure the build() method is called before run()."),
@@ -131,12 +177,28 @@ impl std::fmt::Display for Error {
             #[cfg(feature = "enable-reqwest")]
             Error::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
             Error::SerdeJson(error) => write!(f, "Google Maps Places API client in the Serde JSON library: {error}"),
+            #[cfg(feature = "enable-reqwest")]
+            Error::Timeout(error) => write!(f, "Google Maps Places API client: \
+                The request timed out: {error}"),
         } // match
     } // fn
 } // impl
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "enable-reqwest")]
+impl From<reqwest::Error> for Error {
+    /// This trait converts from an Reqwest error type (`reqwest::Error`) into a
+    /// Google Maps Places API error type (`google_maps::places::error::Error`)
+    /// by wrapping it inside. This function is required to use the `?`
+    /// operator.
+    fn from(error: reqwest::Error) -> Error {
+        Error::Reqwest(error)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl From<serde_json::error::Error> for Error {
     /// This trait converts from an Serde JSON (`serde_json::error::Error`)
     /// error type into a Google Maps Places API error type
@@ -145,4 +207,25 @@ impl From<serde_json::error::Error> for Error {
     fn from(error: serde_json::error::Error) -> Error {
         Error::SerdeJson(error)
     } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::client::ApiError for Error {
+    /// Wraps an unsuccessful HTTP status in a Google Maps Places API
+    /// error type. This function is required by the shared `get` method.
+    fn http_unsuccessful(status: String) -> Error {
+        Error::HttpUnsuccessful(status)
+    } // fn
+    /// Wraps a HTTP client error message in a Google Maps Places API
+    /// error type. This function is required by the shared `get` method.
+    fn reqwest_message(message: String) -> Error {
+        Error::ReqwestMessage(message)
+    } // fn
+    /// Wraps a HTTP client timeout in a Google Maps Places API
+    /// error type. This function is required by the shared `get` method.
+    fn timeout(error: reqwest::Error) -> Error {
+        Error::Timeout(error)
+    } // fn
 } // impl
\ No newline at end of file
@@ -0,0 +1,37 @@
+//! Provides a [GeoJSON](https://geojson.org/) conversion for the `Place` type.
+
+use crate::places::place::Place;
+use crate::types::geojson::Feature;
+use rust_decimal::prelude::ToPrimitive;
+use serde_json::json;
+
+// -----------------------------------------------------------------------------
+
+impl Place {
+    /// Converts a place into a GeoJSON `Feature`. The place's location becomes
+    /// a `Point` geometry, or `null` if the place has no geometry. The name,
+    /// formatted address, vicinity, place ID, types, rating and business status
+    /// are copied into the feature's `properties`.
+    /// ```rust
+    /// let feature = place.to_feature();
+    /// ```
+    pub fn to_feature(&self) -> Feature {
+        Feature {
+            geometry: self.geometry
+                .as_ref()
+                .map(|geometry| geometry.location.to_geojson_point()),
+            properties: [
+                ("name", json!(self.name)),
+                ("formatted_address", json!(self.formatted_address)),
+                ("vicinity", json!(self.vicinity)),
+                ("place_id", json!(self.place_id)),
+                ("types", json!(self.types)),
+                ("rating", json!(self.rating.and_then(|rating| rating.to_f64()))),
+                ("business_status", json!(self.business_status)),
+            ]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+        } // Feature
+    } // fn
+} // impl
@@ -70,6 +70,13 @@
 
 // -----------------------------------------------------------------------------
 
+/// Google caps the `radius` parameter of the Places API's searches and
+/// autocomplete services at 50,000 meters.
+#[cfg(any(feature = "autocomplete", feature = "places"))]
+pub(crate) const MAX_RADIUS: u32 = 50_000;
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "autocomplete")]
 pub mod place_autocomplete;
 #[cfg(feature = "autocomplete")]
@@ -105,6 +112,8 @@ pub use crate::places::place_autocomplete::{
 pub mod business_status;
 #[cfg(feature = "places")]
 pub mod error;
+#[cfg(all(feature = "places", feature = "geojson"))]
+mod geojson;
 #[cfg(feature = "places")]
 pub mod place;
 #[cfg(feature = "places")]
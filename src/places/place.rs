@@ -3,6 +3,7 @@
 
 use crate::types::{AddressComponent, Geometry, PlaceType};
 use crate::places::{BusinessStatus, PlaceEditorialSummary, PlaceOpeningHours, PlacePhoto, PlaceReview};
+use crate::serde::empty_string_as_none::empty_string_as_none;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +22,7 @@ pub struct Place {
 
     /// A representation of the place's address in the
     /// [adr microformat](http://microformats.org/wiki/adr).
+    #[serde(default, deserialize_with = "empty_string_as_none")]
     pub adr_address: Option<String>,
 
     /// Indicates the operational status of the place, if it is a business. If
@@ -71,10 +73,12 @@ pub struct Place {
     /// Do not parse the formatted address programmatically. Instead you should
     /// use the individual address components, which the API response includes
     /// in addition to the formatted address field.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
     pub formatted_address: Option<String>,
 
     /// Contains the place's phone number in its
     /// [local format](http://en.wikipedia.org/wiki/Local_conventions_for_writing_telephone_numbers).
+    #[serde(default, deserialize_with = "empty_string_as_none")]
     pub formatted_phone_number: Option<String>,
 
     /// Contains the location and viewport for the location.
@@ -84,19 +88,23 @@ pub struct Place {
 
     /// Contains the URL of a suggested icon which may be displayed to the user
     /// when indicating this result on a map.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
     pub icon: Option<String>,
 
     /// Contains the default HEX color code for the place's category.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
     pub icon_background_color: Option<String>,
 
     /// Contains the URL of a recommended icon, minus the `.svg` or `.png` file
     /// type extension.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
     pub icon_mask_base_uri: Option<String>,
 
     /// Contains the place's phone number in international format. International
     /// format includes the country code, and is prefixed with the plus, +,
     /// sign. For example, the international_phone_number for Google's Sydney,
     /// Australia office is `+61 2 9374 4000`.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
     pub international_phone_number: Option<String>,
 
     /// Contains the human-readable name for the returned result. For
@@ -208,6 +216,7 @@ pub struct Place {
     /// be the Google-owned page that contains the best available information
     /// about the place. Applications must link to or embed this page on any
     /// screen that shows detailed results about the place to the user.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
     pub url: Option<String>,
 
     /// The total number of reviews, with or without text, for this place.
@@ -231,9 +240,11 @@ pub struct Place {
     ///
     /// This content is meant to be read as-is. Do not programmatically parse
     /// the formatted address.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
     pub vicinity: Option<String>,
 
     /// The authoritative website for this place, such as a business' homepage.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
     pub website: Option<String>,
 
     /// Specifies if the place has an entrance that is wheelchair-accessible.
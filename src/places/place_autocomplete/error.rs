@@ -28,9 +28,17 @@ pub enum Error {
     /// `google_maps\src\places\place_autocomplete\request\autocomplete_type.rs`
     /// for more information.
     InvalidAutocompleteType(String),
+    /// Google Maps Places API server reported that the daily usage limit
+    /// has been exceeded, or that billing is not set up for the API key. This
+    /// error is never retried, as retrying will not help until the quota is
+    /// reset or the billing and quota settings are fixed.
+    OverDailyLimit(Option<String>),
     /// The query string must be built before the request may be sent to the
     /// Google Maps Places API server.
     QueryNotBuilt,
+    /// The specified radius, in meters, is greater than Google's maximum of
+    /// 50,000 meters.
+    RadiusTooLarge(u32),
     /// The dependency library Reqwest generated an error.
     #[cfg(feature = "enable-reqwest")]
     Reqwest(reqwest::Error),
@@ -40,6 +48,15 @@ pub enum Error {
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
     SerdeJson(serde_json::error::Error),
+    /// Strict bounds were requested, but the location and radius that define
+    /// the bounds were not both specified.
+    StrictBoundsWithoutLocationAndRadius,
+    /// The HTTP request to the Google Maps Places API server timed out. See
+    /// `GoogleMapsClient::with_timeout` and
+    /// `GoogleMapsClient::with_connect_timeout`. This error is eligible for
+    /// retries.
+    #[cfg(feature = "enable-reqwest")]
+    Timeout(reqwest::Error),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -63,6 +80,9 @@ impl std::fmt::Display for Error {
                     Status::Ok => write!(f, "Google Maps Time Zone service: \
                         Ok. \
                         The request was successful."),
+                    Status::OverDailyLimit => write!(f, "Google Maps Places API service: \
+                        Over daily limit. \
+                        Usage cap has been exceeded, API key is invalid, billing has not been enabled, or method of payment is no longer valid."),
                     Status::OverQueryLimit => write!(f, "Google Maps Places API service: \
                         Over query limit. \
                         Requestor has exceeded quota."),
@@ -89,14 +109,31 @@ impl std::fmt::Display for Error {
                 `{autocomplete_type}` is not a valid autocomplete type. \
                 Valid types are `geocode`, `address`, `establishment`, \
                 `(regions)`, `(cities)`."),
+            Error::OverDailyLimit(error_message) => match error_message {
+                Some(error_message) => write!(f, "Google Maps Places API service: \
+                    Over daily limit. {error_message} \
+                    Check the billing and quota settings for your API key in the Google Cloud Console."),
+                None => write!(f, "Google Maps Places API service: \
+                    Over daily limit. \
+                    Usage cap has been exceeded, API key is invalid, billing has not been enabled, or method of payment is no longer valid. \
+                    Check the billing and quota settings for your API key in the Google Cloud Console."),
+            }, // match
             Error::QueryNotBuilt => write!(f, "Google Maps Places API client library: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
                 Ensure the build() method is called before run()."),
+            Error::RadiusTooLarge(radius) => write!(f, "Google Maps Places API client: \
+                A radius of `{radius}` meters is greater than the maximum of 50,000 meters."),
             #[cfg(feature = "enable-reqwest")]
             Error::Reqwest(error) => write!(f, "Google Maps Places API client in the Reqwest library: {error}"),
             #[cfg(feature = "enable-reqwest")]
             Error::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
             Error::SerdeJson(error) => write!(f, "Google Maps Places API client in the Serde JSON library: {error}"),
+            Error::StrictBoundsWithoutLocationAndRadius => write!(f, "Google Maps Places API client: \
+                Strict bounds require both a location and a radius. \
+                Use the `with_location` and `with_radius` methods, or remove `with_strict_bounds`."),
+            #[cfg(feature = "enable-reqwest")]
+            Error::Timeout(error) => write!(f, "Google Maps Places API client: \
+                The request timed out: {error}"),
         } // match
     } // fn
 } // impl
@@ -124,4 +161,27 @@ impl From<serde_json::error::Error> for Error {
     fn from(error: serde_json::error::Error) -> Error {
         Error::SerdeJson(error)
     } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::client::ApiError for Error {
+    /// Wraps an unsuccessful HTTP status in a Google Maps Place
+    /// Autocomplete API error type. This function is required by the shared
+    /// `get` method.
+    fn http_unsuccessful(status: String) -> Error {
+        Error::HttpUnsuccessful(status)
+    } // fn
+    /// Wraps a HTTP client error message in a Google Maps Place
+    /// Autocomplete API error type. This function is required by the shared
+    /// `get` method.
+    fn reqwest_message(message: String) -> Error {
+        Error::ReqwestMessage(message)
+    } // fn
+    /// Wraps a HTTP client timeout in a Google Maps Places API
+    /// error type. This function is required by the shared `get` method.
+    fn timeout(error: reqwest::Error) -> Error {
+        Error::Timeout(error)
+    } // fn
 } // impl
\ No newline at end of file
@@ -18,6 +18,9 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// general only a single type is allowed. The exception is that you can safely
 /// mix the geocode and establishment types, but note that this will have the
 /// same effect as specifying no types.
+///
+/// The type collections `Regions` and `Cities` are sent to Google with their
+/// parentheses, as `(regions)` and `(cities)`.
 
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(u8)]
@@ -30,7 +33,6 @@ pub enum AutocompleteType {
     /// Instructs the Place Autocomplete service to return only geocoding
     /// results with a precise address. Generally, you use this request when you
     /// know the user will be looking for a fully specified address.
-    /// indeterminate.
     #[default] Address = 1,
     /// Instructs the Place Autocomplete service to return only business
     /// results.
@@ -1,5 +1,5 @@
 use crate::places::place_autocomplete::request::Request;
-use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use crate::query_string::QueryString;
 
 // -----------------------------------------------------------------------------
 
@@ -17,87 +17,68 @@ impl<'a> Request<'a> {
         // This section builds the "required parameters" portion of the query
         // string:
 
-        let mut query = format!(
-            "key={}&input={}",
-            self.client.key,
-            utf8_percent_encode(&self.input, NON_ALPHANUMERIC),
-        );
+        let mut query = QueryString::new(&self.client.key);
+        query.push_encoded("input", &self.input);
+
 
         // This section builds the "optional parameters" portion of the query
         // string:
 
         // Components key/value pair:
         if !self.components.is_empty() {
-            query.push_str("&components=");
             let components = self.components
                 .iter()
-                .map(|component| format!("country:{}", String::from(component).to_lowercase()))
-                .collect::<Vec<String>>()
-                .join("|");
-            query.push_str(&utf8_percent_encode(&components, NON_ALPHANUMERIC).to_string())
+                .map(|component| format!("country:{}", String::from(component).to_lowercase()));
+            query.push_encoded_list("components", components)
         } // if
 
         // Language key/value pair:
         if let Some(language) = &self.language {
-            query.push_str("&language=");
-            query.push_str(&String::from(language))
+            query.push("language", language.into())
         }
 
         // Location key/value pair:
         if let Some(location) = &self.location {
-            query.push_str("&location=");
-            query.push_str(&String::from(location))
+            query.push_display("location", location)
         }
 
         // Offset key/value pair:
         if let Some(offset) = &self.offset {
-            query.push_str("&offset=");
-            query.push_str(&offset.to_string())
+            query.push_display("offset", offset)
         }
 
         // Origin key/value pair:
         if let Some(origin) = &self.origin {
-            query.push_str("&origin=");
-            query.push_str(&String::from(origin))
+            query.push_display("origin", origin)
         }
 
         // Radius key/value pair:
         if let Some(radius) = &self.radius {
-            query.push_str("&radius=");
-            query.push_str(&radius.to_string())
+            query.push_display("radius", radius)
         }
 
         // Region key/value pair:
         if let Some(region) = &self.region {
-            query.push_str("&region=");
-            query.push_str(&String::from(region))
+            query.push("region", region.into())
         }
 
         // Session Token key/value pair:
         if let Some(sessiontoken) = &self.sessiontoken {
-            query.push_str("&sessiontoken=");
-            query.push_str(&utf8_percent_encode(sessiontoken, NON_ALPHANUMERIC).to_string())
+            query.push_encoded("sessiontoken", sessiontoken)
         }
 
         // Strict Bounds key/value pair:
         if let Some(strictbounds) = &self.strictbounds {
-            query.push_str("&strictbounds=");
-            query.push_str(&strictbounds.to_string())
+            query.push_display("strictbounds", strictbounds)
         }
 
         // Types key/value pair:
         if !self.types.is_empty() {
-            query.push_str("&types=");
-            let types = self.types
-                .iter()
-                .map(String::from)
-                .collect::<Vec<String>>()
-                .join("|");
-            query.push_str(&types);
+            query.push_encoded_list("types", self.types.iter().map(<&str>::from))
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
@@ -26,7 +26,38 @@ impl<'a> PlaceAutocompleteRequest<'a> {
         &'a mut self
     ) -> Result<PlaceAutocompleteResponse, GoogleMapsError> {
 
-        self.build().get().await
+        self.validate()?.build().get().await
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, and returns the raw JSON response body
+    /// along with the response.
+    ///
+    /// ## Description:
+    ///
+    /// Works like `execute`, but also returns the response body exactly as
+    /// Google sent it. Fields that this crate does not model yet are dropped
+    /// from the deserialized response, but remain in the raw body. This is
+    /// useful for debugging, or for reading fields that Google has recently
+    /// added.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let (response, body) = request.execute_with_raw().await?;
+    /// ```
+
+    pub async fn execute_with_raw(
+        &'a mut self
+    ) -> Result<(PlaceAutocompleteResponse, String), GoogleMapsError> {
+
+        self.validate()?.build().get_with_raw().await
 
     } // fn
 
@@ -4,18 +4,23 @@
 //! structs, methods) for building your Google Maps Platform request.
 
 mod build;
+mod deserialize_with_client;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
 #[cfg(feature = "enable-reqwest")]
 mod get;
 mod new;
 mod query_url;
+mod validate;
+mod with_billing_tag;
 mod with_language;
 mod with_location;
 mod with_offset;
 mod with_origin;
+mod with_radius;
 mod with_region;
 mod with_sessiontoken;
+mod with_strict_bounds;
 mod with_components;
 mod with_types;
 pub mod autocomplete_type;
@@ -25,20 +30,27 @@ pub mod autocomplete_type;
 use crate::client::GoogleMapsClient;
 use crate::places::place_autocomplete::request::autocomplete_type::AutocompleteType;
 use crate::types::{Country, Language, LatLng, Region};
+use serde::Serialize;
 
 // -----------------------------------------------------------------------------
 
 /// **Look at this `Request` struct for documentation on how to build your
 /// _Place Autocomplete_ query**. The methods implemented for this struct are
 /// what's used to build your request.
+///
+/// A request's parameters may be serialized, for example to save a search to
+/// disk. The client, and any query string that has already been built, are not
+/// serialized. Use `Request::deserialize_with_client` to load the parameters
+/// again.
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Request<'a> {
     // Required parameters:
     // --------------------
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
+    #[serde(skip)]
     client: &'a GoogleMapsClient,
 
     /// The text string on which to search. The Place Autocomplete service will
@@ -186,7 +198,14 @@ pub struct Request<'a> {
     // Internal use only:
     // ------------------
 
+    /// A caller-defined tag, such as a product feature, that is recorded in the
+    /// `billing_tag` field of the request's `tracing` span. It is never sent to
+    /// Google.
+    #[serde(skip)]
+    billing_tag: Option<String>,
+
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
     query: Option<String>,
 
 } // struct
\ No newline at end of file
@@ -28,16 +28,17 @@ impl<'a> Request<'a> {
             input,
             // Optional parameters:
             components: vec![],
-            language: None,
+            language: client.default_language.clone(),
             location: None,
             offset: None,
             origin: None,
             radius: None,
-            region: None,
+            region: client.default_region.clone(),
             sessiontoken: None,
             strictbounds: None,
             types: vec![],
             // Internal use only:
+            billing_tag: None,
             query: None,
         } // struct
 
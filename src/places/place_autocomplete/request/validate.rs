@@ -0,0 +1,39 @@
+use crate::places::place_autocomplete::{
+    error::Error,
+    request::Request,
+}; // crate::places::place_autocomplete
+use crate::places::MAX_RADIUS;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Ensures the built query is valid. This function checks the combination
+    /// of parameters to ensure that they make sense together and that Google
+    /// Maps Places API will accept them - i.e. it will not allow a radius over
+    /// 50,000 meters, or strict bounds without both a location and a radius.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&'a mut self) -> Result<&'a mut Request, Error> {
+
+        // The radius cannot be more than 50,000 meters:
+        if let Some(radius) = self.radius.filter(|radius| *radius > MAX_RADIUS) {
+            return Err(Error::RadiusTooLarge(radius));
+        } // if
+
+        // Strict bounds restrict results to the circle defined by the location
+        // and radius, so both must be present:
+        if self.strictbounds == Some(true) && (self.location.is_none() || self.radius.is_none()) {
+            return Err(Error::StrictBoundsWithoutLocationAndRadius);
+        } // if
+
+        // If we've gotten to this point, all tests passed. Return `Ok` to
+        // caller:
+        Ok(self)
+
+    } // fn
+
+} // impl
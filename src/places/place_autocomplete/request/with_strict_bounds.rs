@@ -0,0 +1,38 @@
+use crate::places::place_autocomplete::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Adds the strict bounds parameter to the Place API _Place Autocomplete_
+    /// query.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `strictbounds` ‧ Returns only those places that are strictly within
+    /// the region defined by `location` and `radius`. This is a restriction,
+    /// rather than a bias, meaning that results outside this region will not
+    /// be returned even if they match the user input. Google requires both a
+    /// `location` and a `radius` when this is set, so `validate` will return
+    /// an error if either is missing.
+    ///
+    /// ## Example:
+    ///
+    /// * Only suggest addresses within 10 km of downtown Edmonton:
+    /// ```rust
+    /// .with_location(LatLng::try_from_dec(dec!(53.5461), dec!(-113.4938))?)
+    /// .with_radius(10_000)
+    /// .with_strict_bounds(true)
+    /// ```
+
+    pub fn with_strict_bounds(
+        &'a mut self,
+        strictbounds: bool,
+    ) -> &'a mut Request {
+        // Set strictbounds in Request struct.
+        self.strictbounds = Some(strictbounds);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
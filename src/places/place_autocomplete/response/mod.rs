@@ -69,4 +69,54 @@ impl std::str::FromStr for Response {
     fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
         serde_json::from_str(s)
     } // fn from_str
-} // impl FromStr
\ No newline at end of file
+} // impl FromStr
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Response {
+    /// Formats a concise, one-line summary of the Place Autocomplete response
+    /// for logging: the status, the number of predictions, and the description
+    /// of the first prediction. Use `{:#?}` to see the entire response.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Place Autocomplete {}: {} prediction(s)", self.status, self.predictions.len())?;
+        if let Some(prediction) = self.predictions.first() {
+            write!(f, ". First: {}", prediction.description)?;
+        } // if
+        Ok(())
+    } // fn fmt
+} // impl Display
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn display_shows_the_first_prediction() {
+        let prediction = |description: &str| json!({
+            "description": description,
+            "matched_substrings": [],
+            "structured_formatting": {
+                "main_text": "",
+                "main_text_matched_substrings": [],
+                "secondary_text": "",
+            },
+            "terms": [],
+        }); // json!
+        let response: Response = serde_json::from_value(json!({
+            "predictions": [
+                prediction("Paris, France"),
+                prediction("Paris, TX, USA"),
+            ],
+            "status": "OK",
+        })).unwrap(); // from_value
+        assert_eq!(
+            response.to_string(),
+            "Place Autocomplete OK: 2 prediction(s). First: Paris, France",
+        ); // assert_eq!
+    } // fn
+
+} // mod
@@ -1,6 +1,6 @@
 use crate::places::place_details::Field;
 use crate::places::place_details::request::Request;
-use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use crate::query_string::QueryString;
 
 // -----------------------------------------------------------------------------
 
@@ -18,53 +18,45 @@ impl<'a> Request<'a> {
         // This section builds the "required parameters" portion of the query
         // string:
 
-        let mut query = format!(
-            "key={}&place_id={}",
-            self.client.key,
-            utf8_percent_encode(&self.place_id, NON_ALPHANUMERIC),
-        );
+        let mut query = QueryString::new(&self.client.key);
+        query.push_encoded("place_id", &self.place_id);
+
 
         // This section builds the "optional parameters" portion of the query
         // string:
 
         // Fields key/value pair:
         if let Some(fields) = &self.fields {
-            query.push_str("&fields=");
-            query.push_str(&Field::vec_to_csv(fields))
+            query.push("fields", &Field::vec_to_csv(fields))
         }
 
         // Language key/value pair:
         if let Some(language) = &self.language {
-            query.push_str("&language=");
-            query.push_str(&String::from(language))
+            query.push("language", language.into())
         }
 
         // Region key/value pair:
         if let Some(region) = &self.region {
-            query.push_str("&region=");
-            query.push_str(&String::from(region))
+            query.push("region", region.into())
         }
 
         // `reviews_no_translations` key/value pair:
         if let Some(reviews_no_translations) = &self.reviews_no_translations {
-            query.push_str("&reviews_no_translations=");
-            query.push_str(&reviews_no_translations.to_string())
+            query.push_display("reviews_no_translations", reviews_no_translations)
         }
 
         // Reviews Sort key/value pair:
         if let Some(reviews_sort) = &self.reviews_sort {
-            query.push_str("&reviews_sort=");
-            query.push_str(&String::from(reviews_sort))
+            query.push("reviews_sort", reviews_sort.into())
         }
 
         // Session Token key/value pair:
         if let Some(sessiontoken) = &self.sessiontoken {
-            query.push_str("&sessiontoken=");
-            query.push_str(&String::from(sessiontoken))
+            query.push_encoded("sessiontoken", sessiontoken)
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
@@ -18,6 +18,9 @@ impl<'a> PlaceDetailsRequest<'a> {
     /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
     /// builder pattern.
     ///
+    /// The fields that were requested using `with_fields` are recorded in the
+    /// response. See `Response::requested_fields`.
+    ///
     /// ## Arguments:
     ///
     /// This method accepts no arguments.
@@ -26,7 +29,44 @@ impl<'a> PlaceDetailsRequest<'a> {
         &'a mut self
     ) -> Result<PlaceDetailsResponse, GoogleMapsError> {
 
-        self.build().get().await
+        let requested_fields = self.fields.clone();
+        let mut response = self.build().get().await?;
+        response.requested_fields = requested_fields;
+        Ok(response)
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, and returns the raw JSON response body
+    /// along with the response.
+    ///
+    /// ## Description:
+    ///
+    /// Works like `execute`, but also returns the response body exactly as
+    /// Google sent it. Fields that this crate does not model yet are dropped
+    /// from the deserialized response, but remain in the raw body. This is
+    /// useful for debugging, or for reading fields that Google has recently
+    /// added.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let (response, body) = request.execute_with_raw().await?;
+    /// ```
+
+    pub async fn execute_with_raw(
+        &'a mut self
+    ) -> Result<(PlaceDetailsResponse, String), GoogleMapsError> {
+
+        let requested_fields = self.fields.clone();
+        let (mut response, body) = self.build().get_with_raw().await?;
+        response.requested_fields = requested_fields;
+        Ok((response, body))
 
     } // fn
 
@@ -19,25 +19,27 @@ impl<'a> Request<'a> {
     /// [Place Search](https://developers.google.com/maps/documentation/places/web-service/search).
     /// For more information about place IDs, see the
     /// [place ID overview](https://developers.google.com/maps/documentation/places/web-service/place-id).
+    /// Either a `String` or a validated `PlaceId` may be passed.
 
     pub fn new(
         client: &GoogleMapsClient,
-        place_id: String,
+        place_id: impl Into<String>,
     ) -> Request {
 
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
             client,
-            place_id,
+            place_id: place_id.into(),
             // Optional parameters:
             fields: None,
-            language: None,
-            region: None,
+            language: client.default_language.clone(),
+            region: client.default_region.clone(),
             reviews_no_translations: None,
             reviews_sort: None,
             sessiontoken: None,
             // Internal use only:
+            billing_tag: None,
             query: None,
         } // struct
 
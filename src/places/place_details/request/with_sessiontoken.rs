@@ -8,7 +8,7 @@ impl<'a> Request<'a> {
     ///
     /// ## Arguments:
     ///
-    /// * `sessiontoken` ‧ A random string which identifies an autocomplete
+    /// * `session_token` ‧ A random string which identifies an autocomplete
     /// [session](https://developers.google.com/maps/documentation/places/web-service/details#session_tokens)
     /// for billing purposes.
     ///
@@ -37,11 +37,18 @@ impl<'a> Request<'a> {
     /// same token for more than one session will result in each request being
     /// billed individually.
 
-    pub fn with_sessiontoken(&'a mut self, sessiontoken: String) -> &'a mut Request {
+    pub fn with_session_token(&'a mut self, session_token: String) -> &'a mut Request {
         // Set session token in Request struct.
-        self.sessiontoken = Some(sessiontoken);
+        self.sessiontoken = Some(session_token);
         // Return modified Request struct to caller.
         self
     } // fn
 
+    /// Deprecated alias for `with_session_token`.
+
+    #[deprecated(note = "use `with_session_token` instead")]
+    pub fn with_sessiontoken(&'a mut self, sessiontoken: String) -> &'a mut Request {
+        self.with_session_token(sessiontoken)
+    } // fn
+
 } // impl
\ No newline at end of file
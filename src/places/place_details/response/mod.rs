@@ -6,6 +6,7 @@
 // -----------------------------------------------------------------------------
 
 use crate::places::Place;
+use crate::places::place_details::Field;
 use crate::places::status::Status;
 use serde::{Deserialize, Serialize};
 
@@ -42,6 +43,12 @@ pub struct Response {
     /// change.
     pub info_messages: Option<Vec<String>>,
 
+    /// The fields that were requested using `with_fields`. This is not part of
+    /// Google's response: it is copied from the request by `execute`. See the
+    /// `requested_fields` method for more information.
+    #[serde(skip)]
+    pub(crate) requested_fields: Option<Vec<Field>>,
+
 } // struct
 
 // -----------------------------------------------------------------------------
@@ -53,4 +60,136 @@ impl std::str::FromStr for Response {
     fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
         serde_json::from_str(s)
     } // fn from_str
-} // impl FromStr
\ No newline at end of file
+} // impl FromStr
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the fields that were requested using `with_fields`, or `None`
+    /// if no fields were specified, in which case Google returns every field.
+    ///
+    /// Google omits fields that have no data for the place, so an empty `Place`
+    /// field may either not have been requested, or be absent. This, and the
+    /// `was_requested` method, tell the two apart.
+
+    pub fn requested_fields(&self) -> Option<&[Field]> {
+        self.requested_fields.as_deref()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns whether the specified field was requested. If no fields were
+    /// specified using `with_fields`, every field was requested. If a field
+    /// was requested but its `Place` field is `None`, Google has no data for
+    /// it.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `field` ‧ The field to check.
+
+    pub fn was_requested(&self, field: &Field) -> bool {
+        self.requested_fields
+            .as_ref()
+            .is_none_or(|requested_fields| requested_fields.contains(field))
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Response {
+    /// Formats a concise, one-line summary of the Place Details response for
+    /// logging: the status, and the name & address of the place. Use `{:#?}`
+    /// to see the entire response.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Place Details {}", self.status)?;
+        if let Some(place) = &self.result {
+            if let Some(name) = &place.name {
+                write!(f, ": {name}")?;
+            } // if
+            if let Some(formatted_address) = &place.formatted_address {
+                write!(f, " ({formatted_address})")?;
+            } // if
+        } // if
+        Ok(())
+    } // fn fmt
+} // impl Display
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn display_shows_the_place() {
+        let response: Response = r#"{
+            "html_attributions": [],
+            "result": {
+                "formatted_address": "48 Pirrama Rd, Pyrmont NSW 2009, Australia",
+                "name": "Google Workplace 6"
+            },
+            "status": "OK"
+        }"#.parse().unwrap();
+        assert_eq!(
+            response.to_string(),
+            "Place Details OK: Google Workplace 6 (48 Pirrama Rd, Pyrmont NSW 2009, Australia)",
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn all_fields_are_requested_by_default() {
+        let response: Response = r#"{ "html_attributions": [], "status": "OK" }"#.parse().unwrap();
+        assert_eq!(response.requested_fields(), None);
+        assert!(response.was_requested(&Field::Website));
+    } // fn
+
+    #[cfg(feature = "enable-reqwest")]
+    #[test]
+    fn non_requested_fields_are_none() {
+        use crate::client::test_server::{block_on, TestServer};
+        use crate::client::{GoogleMapsClient, RetryPolicy};
+        use crate::request_rate::api::Api;
+
+        let server = TestServer::start(200, &[], r#"{
+            "html_attributions": [],
+            "result": {
+                "geometry": {
+                    "location": { "lat": -33.866489, "lng": 151.1958561 },
+                    "viewport": {
+                        "northeast": { "lat": -33.8655, "lng": 151.1971 },
+                        "southwest": { "lat": -33.8682, "lng": 151.1944 }
+                    }
+                },
+                "place_id": "ChIJN1t_tDeuEmsRUsoyG83frY4"
+            },
+            "status": "OK"
+        }"#); // start
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_endpoint_override(Api::Places, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none());
+        let client = client.build();
+
+        let response = block_on(client
+            .place_details("ChIJN1t_tDeuEmsRUsoyG83frY4")
+            .with_fields(vec![Field::PlaceId, Field::Geometry])
+            .execute()
+        ).unwrap(); // block_on
+
+        assert!(server.targets()[0].contains("&fields=place_id,geometry"), "{:?}", server.targets());
+        assert_eq!(response.requested_fields(), Some(&[Field::PlaceId, Field::Geometry][..]));
+        assert!(response.was_requested(&Field::PlaceId));
+        assert!(!response.was_requested(&Field::Website));
+        let place = response.result.unwrap();
+        assert_eq!(place.place_id.as_deref(), Some("ChIJN1t_tDeuEmsRUsoyG83frY4"));
+        assert!(place.geometry.is_some());
+        assert_eq!(place.website, None);
+        assert_eq!(place.formatted_phone_number, None);
+    } // fn
+
+} // mod
@@ -1,6 +1,7 @@
 //! An object describing the opening hours of a place.
 
-use chrono::NaiveDate;
+use chrono::{DateTime, Datelike, NaiveDate, Timelike};
+use chrono_tz::Tz;
 use crate::places::{PlaceOpeningHoursPeriod, PlaceSpecialDay, SecondaryHoursType};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
@@ -13,6 +14,10 @@ use std::collections::HashSet;
 pub struct PlaceOpeningHours {
 
     /// A boolean value indicating if the place is open at the current time.
+    ///
+    /// Google caches this value, so it may be stale. Use the
+    /// `is_open_at` method to determine whether the place is open from
+    /// its `periods`, or the `reconcile` method to compare the two.
     pub open_now: Option<bool>,
 
     /// An array of opening periods covering seven days, starting from Sunday,
@@ -68,4 +73,205 @@ impl PlaceOpeningHours {
                     .collect::<HashSet<NaiveDate>>()
             }) // map
     } // fn
-} // impl
\ No newline at end of file
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// The number of minutes in a day.
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// The number of minutes in a week.
+const MINUTES_PER_WEEK: u32 = 7 * MINUTES_PER_DAY;
+
+impl PlaceOpeningHours {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Determines whether the place is open at the specified time, using the
+    /// opening hours' `periods` rather than Google's cached `open_now` value.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `time` ‧ The time to check. This should be expressed in the place's
+    /// local time zone, since Google reports `periods` in local time. For
+    /// example, `Utc::now().with_timezone(&chrono_tz::America::Edmonton)`.
+    ///
+    /// ## Description:
+    ///
+    /// Only the day of the week and the time of day are considered. Periods
+    /// that close on a later day than they open, such as a bar that is open
+    /// from Friday 18:00 to Saturday 02:00, are supported. Google reports a
+    /// place that is open 24 hours as a period without a `close` time, which
+    /// means that the place is always open.
+    ///
+    /// Returns `None` if the opening hours have no `periods`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let saturday_night = chrono_tz::America::Edmonton
+    ///     .with_ymd_and_hms(2024, 3, 9, 23, 30, 0)
+    ///     .unwrap();
+    /// let is_open = place.opening_hours?.is_open_at(saturday_night);
+    /// ```
+
+    pub fn is_open_at(&self, time: DateTime<Tz>) -> Option<bool> {
+
+        let periods = self.periods.as_ref().filter(|periods| !periods.is_empty())?;
+
+        // Express times as minutes since the start of the week (Sunday at
+        // midnight), which is how Google numbers days:
+        let minute_of_week = |day: chrono::Weekday, hour: u32, minute: u32| -> u32 {
+            day.num_days_from_sunday() * MINUTES_PER_DAY + hour * 60 + minute
+        }; // closure
+
+        let now = minute_of_week(time.weekday(), time.hour(), time.minute());
+
+        let is_open = periods.iter().any(|period| {
+            let open = minute_of_week(
+                period.open.day,
+                period.open.time.hour(),
+                period.open.time.minute(),
+            ); // minute_of_week
+            match &period.close {
+                // No close time means that the place is always open:
+                None => true,
+                Some(close) => {
+                    let close = minute_of_week(
+                        close.day,
+                        close.time.hour(),
+                        close.time.minute(),
+                    ) % MINUTES_PER_WEEK;
+                    if open < close {
+                        open <= now && now < close
+                    } else {
+                        // The period wraps around the end of the week, for
+                        // example from Saturday evening to Sunday morning:
+                        open <= now || now < close
+                    } // if
+                }, // Some
+            } // match
+        }); // any
+
+        Some(is_open)
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Determines whether the place is open at the specified time, using the
+    /// opening hours' `periods` rather than Google's cached `open_now` value.
+    /// This is the same as `is_open_at`.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `now` ‧ The time to check, expressed in the place's local time zone.
+    /// See `is_open_at` for more information.
+
+    pub fn open_now_computed(&self, now: DateTime<Tz>) -> Option<bool> {
+        self.is_open_at(now)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Compares Google's cached `open_now` value with the value computed from
+    /// the opening hours' `periods` by `is_open_at`. This is useful for
+    /// detecting a stale `open_now` value, or time zone and daylight saving
+    /// time bugs in the calling code.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `now` ‧ The time to check, expressed in the place's local time zone.
+    /// See `is_open_at` for more information.
+    ///
+    /// ## Description:
+    ///
+    /// Returns `true` if Google's `open_now` disagrees with the computed
+    /// value. A warning is also emitted using `tracing`. Returns `false` if the
+    /// two values agree, or if either value is unavailable.
+
+    pub fn reconcile(&self, now: DateTime<Tz>) -> bool {
+
+        match (self.open_now, self.is_open_at(now)) {
+            (Some(open_now), Some(computed)) if open_now != computed => {
+                tracing::warn!(
+                    "Google reports that the place is {}, but its opening hours \
+                    indicate that it is {} at {now}",
+                    if open_now { "open" } else { "closed" },
+                    if computed { "open" } else { "closed" },
+                ); // warn!
+                true
+            }, // Some
+            _ => false,
+        } // match
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use chrono::TimeZone;
+    use chrono_tz::America::Edmonton;
+
+    /// A bar that is open Friday and Saturday from 18:00 until 02:00 the next
+    /// morning, with the specified `open_now` value.
+    fn bar(open_now: bool) -> PlaceOpeningHours {
+        format!(r#"{{
+            "open_now": {open_now},
+            "periods": [
+                {{ "open": {{ "day": 5, "time": "1800" }}, "close": {{ "day": 6, "time": "0200" }} }},
+                {{ "open": {{ "day": 6, "time": "1800" }}, "close": {{ "day": 0, "time": "0200" }} }}
+            ]
+        }}"#).parse().unwrap()
+    } // fn
+
+    /// Returns the specified time in March 2024, when the 9th was a Saturday.
+    fn march_2024(day: u32, hour: u32, minute: u32) -> DateTime<Tz> {
+        Edmonton.with_ymd_and_hms(2024, 3, day, hour, minute, 0).unwrap()
+    } // fn
+
+    #[test]
+    fn open_now_computed_uses_periods() {
+        let hours = bar(true);
+        assert_eq!(hours.open_now_computed(march_2024(8, 17, 59)), Some(false));
+        assert_eq!(hours.open_now_computed(march_2024(8, 18, 0)), Some(true));
+        assert_eq!(hours.open_now_computed(march_2024(9, 1, 59)), Some(true));
+        assert_eq!(hours.open_now_computed(march_2024(9, 2, 0)), Some(false));
+        // The Saturday period closes early on Sunday, wrapping around the end
+        // of the week:
+        assert_eq!(hours.open_now_computed(march_2024(10, 1, 30)), Some(true));
+        assert_eq!(hours.open_now_computed(march_2024(11, 12, 0)), Some(false));
+    } // fn
+
+    #[test]
+    fn open_now_computed_without_periods() {
+        let hours: PlaceOpeningHours = r#"{ "open_now": true }"#.parse().unwrap();
+        assert_eq!(hours.open_now_computed(march_2024(9, 12, 0)), None);
+        assert!(!hours.reconcile(march_2024(9, 12, 0)));
+    } // fn
+
+    #[test]
+    fn always_open_without_close_time() {
+        let hours: PlaceOpeningHours =
+            r#"{ "periods": [ { "open": { "day": 0, "time": "0000" } } ] }"#.parse().unwrap();
+        assert_eq!(hours.open_now_computed(march_2024(13, 3, 0)), Some(true));
+    } // fn
+
+    #[test]
+    fn reconcile_when_values_agree() {
+        assert!(!bar(true).reconcile(march_2024(9, 23, 30)));
+        assert!(!bar(false).reconcile(march_2024(9, 12, 0)));
+    } // fn
+
+    #[test]
+    fn reconcile_when_values_disagree() {
+        assert!(bar(false).reconcile(march_2024(9, 23, 30)));
+        assert!(bar(true).reconcile(march_2024(9, 12, 0)));
+    } // fn
+
+} // mod
@@ -2,6 +2,7 @@
 /// [Place Photo](https://developers.google.com/places/web-service/photos) API
 /// using a URL.
 
+use crate::html::html_to_plain_text;
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
@@ -37,4 +38,62 @@ impl std::str::FromStr for PlacePhoto {
     fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
         serde_json::from_str(s)
     } // fn from_str
-}  // impl FromStr
\ No newline at end of file
+}  // impl FromStr
+
+// -----------------------------------------------------------------------------
+
+impl PlacePhoto {
+
+    /// Returns the photo's attributions as plain text, for display where HTML
+    /// cannot be rendered. Google requires these attributions to be shown
+    /// alongside the photo. HTML tags, such as the `<a href>` link to the
+    /// photographer's profile, are removed and HTML entities are decoded.
+    /// Multiple attributions are separated by `; `. The original HTML remains
+    /// available in the `html_attributions` field.
+    /// ```rust
+    /// let attribution = photo.attribution_text();
+    /// ```
+
+    pub fn attribution_text(&self) -> String {
+        self.html_attributions
+            .iter()
+            .map(|attribution| html_to_plain_text(attribution))
+            .filter(|attribution| !attribution.is_empty())
+            .collect::<Vec<String>>()
+            .join("; ")
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn photo(html_attributions: &[&str]) -> PlacePhoto {
+        PlacePhoto {
+            html_attributions: html_attributions.iter().map(ToString::to_string).collect(),
+            ..PlacePhoto::default()
+        } // PlacePhoto
+    } // fn
+
+    #[test]
+    fn attribution_text_strips_links() {
+        let photo = photo(&[
+            r#"<a href="https://maps.google.com/maps/contrib/106222166168758671498">Jean-Fran&#231;ois L&#39;Heureux</a>"#,
+        ]); // photo
+        assert_eq!(photo.attribution_text(), "Jean-François L'Heureux");
+        // The original HTML is kept:
+        assert!(photo.html_attributions[0].starts_with("<a href="));
+    } // fn
+
+    #[test]
+    fn attribution_text_joins_attributions() {
+        let attributions = [r#"<a href="https://example.com/a">Alice</a>"#, "", "Bob &amp; Carol"];
+        assert_eq!(photo(&attributions).attribution_text(), "Alice; Bob & Carol");
+        assert_eq!(photo(&[]).attribution_text(), "");
+    } // fn
+
+} // mod
@@ -45,4 +45,5 @@
 //! (e.g. `minprice`, `maxprice`, `opennow`, and `type`).
 
 pub mod text_search;
-pub mod nearby_search;
\ No newline at end of file
+pub mod nearby_search;
+mod results;
\ No newline at end of file
@@ -1,5 +1,6 @@
 use crate::places::place_search::nearby_search::request::Request;
-use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use crate::places::RankBy;
+use crate::query_string::QueryString;
 
 // -----------------------------------------------------------------------------
 
@@ -17,57 +18,53 @@ impl<'a> Request<'a> {
         // This section builds the "required parameters" portion of the query
         // string:
 
-        let mut query = format!(
-            "key={}&location={}&radius={}",
-            self.client.key,
-            String::from(&self.location),
-            self.radius,
-        );
+        let mut query = QueryString::new(&self.client.key);
+        query.push_display("location", &self.location);
+
+
+        // Google rejects a radius when ranking by distance, so it is only sent
+        // when ranking by prominence:
+        if self.rankby != Some(RankBy::Distance) {
+            query.push_display("radius", &self.radius)
+        }
 
         // This section builds the "optional parameters" portion of the query
         // string:
 
         if let Some(keyword) = &self.keyword {
-            query.push_str("&keyword={}");
-            query.push_str(&utf8_percent_encode(&keyword, NON_ALPHANUMERIC).to_string())
+            query.push_encoded("keyword", keyword)
         }
 
         if let Some(language) = &self.language {
-            query.push_str("&language=");
-            query.push_str(&String::from(language))
+            query.push("language", language.into())
         }
 
         if let Some(maxprice) = &self.maxprice {
-            query.push_str("&maxprice=");
-            query.push_str(&maxprice.to_string())
+            query.push_display("maxprice", maxprice)
         }
 
         if let Some(minprice) = &self.minprice {
-            query.push_str("&minprice=");
-            query.push_str(&minprice.to_string())
+            query.push_display("minprice", minprice)
         }
 
         if let Some(opennow) = &self.opennow {
-            if *opennow { query.push_str("&opennow") }
+            if *opennow { query.push_flag("opennow") }
         }
 
         if let Some(pagetoken) = &self.pagetoken {
-            query.push_str("&pagetoken=");
-            query.push_str(pagetoken)
+            query.push_encoded("pagetoken", pagetoken)
         }
 
         if let Some(rankby) = &self.rankby {
-            query.push_str("&rankby=");
-            query.push_str(&String::from(rankby))
+            query.push("rankby", rankby.into())
         }
 
         if let Some(place_type) = &self.place_type {
-            query.push_str("&type=");
-            query.push_str(&String::from(place_type))
+            query.push("type", place_type.into())
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
@@ -20,4 +20,27 @@ impl<'a> Request<'a> {
         self
     } // fn
 
+    /// Adds "open now" filter to the Places API _Nearby Search_ query. This is
+    /// an alias of `is_open_now`.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `opennow` ‧ Returns only those places that are open for business at
+    /// the time the query is sent. Places that do not specify opening hours in
+    /// the Google Places database will not be returned if you include this
+    /// parameter in your query.
+    ///
+    /// ## Example:
+    ///
+    /// * Cheap restaurants that are open now:
+    /// ```rust
+    /// .with_type(PlaceType::Restaurant)
+    /// .with_max_price(1)
+    /// .with_open_now(true)
+    /// ```
+
+    pub fn with_open_now(&'a mut self, opennow: bool) -> &'a mut Request {
+        self.is_open_now(opennow)
+    } // fn
+
 } // impl
\ No newline at end of file
@@ -4,6 +4,7 @@
 //! structs, methods) for building your Google Maps Platform request.
 
 mod build;
+mod deserialize_with_client;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
 #[cfg(feature = "enable-reqwest")]
@@ -11,6 +12,8 @@ mod get;
 mod is_open_now;
 mod new;
 mod query_url;
+mod validate;
+mod with_billing_tag;
 mod with_keyword;
 mod with_language;
 mod with_max_price;
@@ -24,20 +27,27 @@ mod with_type;
 use crate::LatLng;
 use crate::places::RankBy;
 use crate::{client::GoogleMapsClient, types::Language, types::PlaceType};
+use serde::Serialize;
 
 // -----------------------------------------------------------------------------
 
 /// **Look at this `Request` struct for documentation on how to build your
 /// _Nearby Search_ query**. The methods implemented for this struct are
 /// what's used to build your request.
+///
+/// A request's parameters may be serialized, for example to save a search to
+/// disk. The client, and any query string that has already been built, are not
+/// serialized. Use `Request::deserialize_with_client` to load the parameters
+/// again.
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Request<'a> {
     // Required parameters:
     // --------------------
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
+    #[serde(skip)]
     client: &'a GoogleMapsClient,
 
     /// The point around which to retrieve place information. This must be
@@ -165,7 +175,14 @@ pub struct Request<'a> {
     // Internal use only:
     // ------------------
 
+    /// A caller-defined tag, such as a product feature, that is recorded in the
+    /// `billing_tag` field of the request's `tracing` span. It is never sent to
+    /// Google.
+    #[serde(skip)]
+    billing_tag: Option<String>,
+
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
     query: Option<String>,
 
 } // struct
\ No newline at end of file
@@ -52,7 +52,7 @@ impl<'a> Request<'a> {
             radius,
             // Optional parameters:
             keyword: None,
-            language: None,
+            language: client.default_language.clone(),
             maxprice: None,
             minprice: None,
             opennow: None,
@@ -60,6 +60,7 @@ impl<'a> Request<'a> {
             rankby: None,
             place_type: None,
             // Internal use only:
+            billing_tag: None,
             query: None,
         } // struct
 
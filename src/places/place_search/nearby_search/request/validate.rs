@@ -0,0 +1,63 @@
+use crate::places::{
+    error::Error,
+    place_search::nearby_search::request::Request,
+    RankBy,
+    MAX_RADIUS,
+}; // crate::places
+
+// -----------------------------------------------------------------------------
+
+/// The most expensive price level that Google recognizes. Price levels range
+/// from 0 (most affordable) to 4 (most expensive), inclusive.
+const MAX_PRICE_LEVEL: u8 = 4;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Ensures the built query is valid. This function checks the combination
+    /// of parameters to ensure that they make sense together and that Google
+    /// Maps Places API will accept them - i.e. it will not allow a price level
+    /// outside of 0 to 4, a minimum price above the maximum price, a radius
+    /// over 50,000 meters, or ranking by distance without a keyword or type.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&'a mut self) -> Result<&'a mut Request, Error> {
+
+        // Price levels must be between 0 and 4:
+        for price_level in [self.minprice, self.maxprice].into_iter().flatten() {
+            if price_level > MAX_PRICE_LEVEL {
+                return Err(Error::InvalidPriceLevel(price_level));
+            } // if
+        } // for
+
+        // The minimum price cannot be more than the maximum price:
+        if let (Some(minprice), Some(maxprice)) = (self.minprice, self.maxprice) {
+            if minprice > maxprice {
+                return Err(Error::MinPriceAboveMaxPrice(minprice, maxprice));
+            } // if
+        } // if
+
+        // The radius cannot be more than 50,000 meters. It is not sent when
+        // ranking by distance:
+        if self.rankby != Some(RankBy::Distance) && self.radius > MAX_RADIUS {
+            return Err(Error::RadiusTooLarge(self.radius));
+        } // if
+
+        // Ranking by distance requires a keyword or type:
+        if self.rankby == Some(RankBy::Distance)
+            && self.keyword.is_none()
+            && self.place_type.is_none() {
+            return Err(Error::RankByDistanceWithoutKeywordOrType);
+        } // if
+
+        // If we've gotten to this point, all tests passed. Return `Ok` to
+        // caller:
+        Ok(self)
+
+    } // fn
+
+} // impl
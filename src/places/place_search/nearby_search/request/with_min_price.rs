@@ -11,7 +11,8 @@ impl<'a> Request<'a> {
     /// * `minprice` ‧ Restricts results to only those places within the
     /// specified range. Valid values range between 0 (most affordable) to 4
     /// (most expensive), inclusive. The exact amount indicated by a specific
-    /// value will vary from region to region.
+    /// value will vary from region to region. `validate` will return an error
+    /// for a value above 4.
 
     pub fn with_min_price(&'a mut self, minprice: u8) -> &'a mut Request {
         // Set minimum price in Request struct.
@@ -8,16 +8,23 @@ impl<'a> Request<'a> {
     ///
     /// ## Arguments:
     ///
-    /// * `pagetoken` ‧ Returns up to 20 results from a previously run search.
+    /// * `page_token` ‧ Returns up to 20 results from a previously run search.
     /// Setting a `pagetoken` parameter will execute a search with the same
     /// parameters used previously — all parameters other than pagetoken will be
     /// ignored.
 
-    pub fn with_pagetoken(&'a mut self, pagetoken: String) -> &'a mut Request {
+    pub fn with_page_token(&'a mut self, page_token: String) -> &'a mut Request {
         // Set page token in Request struct.
-        self.pagetoken = Some(pagetoken);
+        self.pagetoken = Some(page_token);
         // Return modified Request struct to caller.
         self
     } // fn
 
+    /// Deprecated alias for `with_page_token`.
+
+    #[deprecated(note = "use `with_page_token` instead")]
+    pub fn with_pagetoken(&'a mut self, pagetoken: String) -> &'a mut Request {
+        self.with_page_token(pagetoken)
+    } // fn
+
 } // impl
\ No newline at end of file
@@ -10,7 +10,7 @@ impl<'a> Request<'a> {
     ///
     /// ## Arguments:
     ///
-    /// * `rankby` ‧ Specifies the order in which results are listed. Possible
+    /// * `rank_by` ‧ Specifies the order in which results are listed. Possible
     /// values are:
     ///
     /// * `prominence` (default). This option sorts results based on their
@@ -24,12 +24,31 @@ impl<'a> Request<'a> {
     /// their distance from the specified location. When `distance` is
     /// specified, one or more of `keyword`, `name`, or `type` is required and
     /// radius is disallowed.
+    ///
+    /// When ranking by distance, the radius passed to `nearby_search` is not
+    /// sent to Google, and `validate` returns an error unless `with_keyword` or
+    /// `with_type` has been used.
+    ///
+    /// ## Example:
+    ///
+    /// * The closest pharmacies:
+    /// ```rust
+    /// .with_rank_by(RankBy::Distance)
+    /// .with_type(PlaceType::Pharmacy)
+    /// ```
 
-    pub fn with_rankby(&'a mut self, rankby: RankBy) -> &'a mut Request {
+    pub fn with_rank_by(&'a mut self, rank_by: RankBy) -> &'a mut Request {
         // Set rannk by order in Request struct.
-        self.rankby = Some(rankby);
+        self.rankby = Some(rank_by);
         // Return modified Request struct to caller.
         self
     } // fn
 
+    /// Deprecated alias for `with_rank_by`.
+
+    #[deprecated(note = "use `with_rank_by` instead")]
+    pub fn with_rankby(&'a mut self, rankby: RankBy) -> &'a mut Request {
+        self.with_rank_by(rankby)
+    } // fn
+
 } // impl
\ No newline at end of file
@@ -0,0 +1,19 @@
+//! Provides a [GeoJSON](https://geojson.org/) conversion for the _Nearby
+//! Search_ response.
+
+use crate::places::place_search::nearby_search::response::Response;
+use crate::types::geojson::FeatureCollection;
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Converts all of the places in the _Nearby Search_ response into a
+    /// GeoJSON `FeatureCollection`. See `Place::to_feature` for how each place
+    /// is converted.
+    /// ```rust
+    /// let feature_collection = search_results.to_feature_collection();
+    /// ```
+    pub fn to_feature_collection(&self) -> FeatureCollection {
+        crate::places::place_search::results::to_feature_collection(&self.results)
+    } // fn
+} // impl
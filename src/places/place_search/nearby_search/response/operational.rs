@@ -0,0 +1,32 @@
+use crate::places::{place::Place, place_search::nearby_search::response::Response};
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns an iterator over the places in the _Nearby Search_ response that
+    /// are operational. Places that are temporarily or permanently closed are
+    /// skipped.
+    ///
+    /// ## Description:
+    ///
+    /// Places that have no `business_status` are kept, since Google omits the
+    /// field for some places (such as geographic features) that are not
+    /// businesses and cannot be closed. Google's ranking of the results is
+    /// preserved.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// for place in search_results.operational() {
+    ///     println!("{:?}", place.name);
+    /// }
+    /// ```
+
+    pub fn operational(&self) -> impl Iterator<Item = &Place> {
+        crate::places::place_search::results::operational(&self.results)
+    } // fn
+
+} // impl
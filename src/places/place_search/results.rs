@@ -0,0 +1,151 @@
+//! Helpers over the `results` of a _Nearby Search_ or _Text Search_ response.
+//! Both responses carry the same list of places, so their filtering,
+//! sorting and conversion methods are implemented once here.
+
+use crate::places::{business_status::BusinessStatus, place::Place};
+#[cfg(feature = "geojson")]
+use crate::types::geojson::FeatureCollection;
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+//
+/// Returns an iterator over the places that are operational. Places that are
+/// temporarily or permanently closed are skipped. Places that have no
+/// `business_status` are kept.
+
+pub(crate) fn operational(places: &[Place]) -> impl Iterator<Item = &Place> {
+    places
+        .iter()
+        .filter(|place| !matches!(
+            place.business_status,
+            Some(BusinessStatus::ClosedTemporarily | BusinessStatus::ClosedPermanently)
+        )) // filter
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the places sorted by their distance from the specified point,
+/// nearest first. Places that have no coordinates are skipped.
+
+pub(crate) fn sorted_by_distance<'a>(places: &'a [Place], from: &LatLng) -> Vec<&'a Place> {
+
+    let mut places: Vec<(f64, &Place)> = places
+        .iter()
+        .filter_map(|place| place.geometry
+            .as_ref()
+            .map(|geometry| (from.haversine_distance_meters(&geometry.location), place))
+        ) // filter_map
+        .collect();
+
+    places.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    places
+        .into_iter()
+        .map(|(_distance, place)| place)
+        .collect()
+
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Converts the places into a GeoJSON `FeatureCollection`, in order. See
+/// `Place::to_feature` for how each place is converted.
+
+#[cfg(feature = "geojson")]
+pub(crate) fn to_feature_collection(places: &[Place]) -> FeatureCollection {
+    places.iter().map(Place::to_feature).collect()
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn place(name: &str, business_status: Option<&str>) -> Place {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "business_status": business_status,
+        })).unwrap()
+    } // fn
+
+    fn names<'a>(places: impl Iterator<Item = &'a Place>) -> Vec<&'a str> {
+        places.filter_map(|place| place.name.as_deref()).collect()
+    } // fn
+
+    #[test]
+    fn operational_skips_closed_places() {
+        let places = vec![
+            place("open", Some("OPERATIONAL")),
+            place("temporarily closed", Some("CLOSED_TEMPORARILY")),
+            place("no status", None),
+            place("permanently closed", Some("CLOSED_PERMANENTLY")),
+            place("also open", Some("OPERATIONAL")),
+        ]; // vec!
+        assert_eq!(names(operational(&places)), ["open", "no status", "also open"]);
+    } // fn
+
+    #[test]
+    fn operational_of_no_places_is_empty() {
+        assert_eq!(operational(&[]).count(), 0);
+    } // fn
+
+    fn located_place(name: &str, lat: f64, lng: f64) -> Place {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "geometry": {
+                "location": { "lat": lat, "lng": lng },
+                "viewport": {
+                    "northeast": { "lat": lat, "lng": lng },
+                    "southwest": { "lat": lat, "lng": lng },
+                }, // viewport
+            }, // geometry
+        })).unwrap()
+    } // fn
+
+    #[test]
+    fn sorted_by_distance_is_nearest_first() {
+        // From Toronto, Hamilton is nearer than Montreal, which is nearer than
+        // Vancouver:
+        let places = vec![
+            located_place("Montreal", 45.5019, -73.5674),
+            place("unlocated", None),
+            located_place("Vancouver", 49.2827, -123.1207),
+            located_place("Hamilton", 43.2557, -79.8711),
+        ]; // vec!
+        let toronto = LatLng::try_from_f64(43.6532, -79.3832).unwrap();
+        assert_eq!(
+            names(sorted_by_distance(&places, &toronto).into_iter()),
+            ["Hamilton", "Montreal", "Vancouver"],
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn sorted_by_distance_of_no_located_places_is_empty() {
+        let places = vec![place("unlocated", None)];
+        let toronto = LatLng::try_from_f64(43.6532, -79.3832).unwrap();
+        assert!(sorted_by_distance(&places, &toronto).is_empty());
+    } // fn
+
+    #[cfg(feature = "geojson")]
+    #[test]
+    fn to_feature_collection_keeps_places_without_a_location() {
+        let places = vec![
+            located_place("located", 43.6532, -79.3832),
+            place("unlocated", None),
+        ]; // vec!
+
+        let feature_collection = to_feature_collection(&places);
+
+        assert_eq!(feature_collection.features.len(), 2);
+        assert_eq!(
+            feature_collection.features[0].geometry.as_ref().map(|point| point.coordinates),
+            Some([-79.3832, 43.6532]),
+        ); // assert_eq!
+        assert_eq!(feature_collection.features[0].properties["name"], "located");
+        assert_eq!(feature_collection.features[1].geometry, None);
+        assert_eq!(feature_collection.features[1].properties["name"], "unlocated");
+    } // fn
+
+} // mod
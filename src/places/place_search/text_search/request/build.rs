@@ -1,5 +1,5 @@
 use crate::places::place_search::text_search::request::Request;
-use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use crate::query_string::QueryString;
 
 // -----------------------------------------------------------------------------
 
@@ -13,68 +13,64 @@ impl<'a> Request<'a> {
     /// This method accepts no arguments.
 
     pub fn build(&mut self) -> &'a mut Request {
+        self.build_query();
+        self
+    } // fn
+
+    /// Performs the work of `build` without consuming the mutable borrow, so
+    /// that `into_stream` can build each page's request.
+
+    pub(super) fn build_query(&mut self) {
 
         // This section builds the "required parameters" portion of the query
         // string:
 
-        let mut query = format!(
-            "key={}&query={}&radius={}",
-            self.client.key,
-            utf8_percent_encode(&self.input, NON_ALPHANUMERIC),
-            self.radius,
-        );
+        let mut query = QueryString::new(&self.client.key);
+        query.push_encoded("query", &self.input);
+        query.push_display("radius", &self.radius);
+
         // This section builds the "optional parameters" portion of the query
         // string:
 
         // Language key/value pair:
         if let Some(language) = &self.language {
-            query.push_str("&language=");
-            query.push_str(&String::from(language))
+            query.push("language", language.into())
         }
 
         // Location key/value pair:
         if let Some(location) = &self.location {
-            query.push_str("&location=");
-            query.push_str(&String::from(location))
+            query.push_display("location", location)
         }
 
         if let Some(maxprice) = &self.maxprice {
-            query.push_str("&maxprice=");
-            query.push_str(&maxprice.to_string())
+            query.push_display("maxprice", maxprice)
         }
 
         if let Some(minprice) = &self.minprice {
-            query.push_str("&minprice=");
-            query.push_str(&minprice.to_string())
+            query.push_display("minprice", minprice)
         }
 
         if let Some(opennow) = &self.opennow {
-            if *opennow { query.push_str("&opennow") }
+            if *opennow { query.push_flag("opennow") }
         }
 
         // Page Token key/value pair:
         if let Some(pagetoken) = &self.pagetoken {
-            query.push_str("&pagetoken=");
-            query.push_str(pagetoken)
+            query.push_encoded("pagetoken", pagetoken)
         }
 
         // Region key/value pair:
         if let Some(region) = &self.region {
-            query.push_str("&region=");
-            query.push_str(&String::from(region))
+            query.push("region", region.into())
         }
 
         // Place Type key/value pair:
         if let Some(place_type) = &self.place_type {
-            query.push_str("&type=");
-            query.push_str(&String::from(place_type))
+            query.push("type", place_type.into())
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
-
-        // Return modified Request struct to caller.
-        self
+        self.query = Some(query.into());
 
     } // fn
 
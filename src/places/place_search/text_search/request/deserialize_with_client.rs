@@ -0,0 +1,159 @@
+use crate::client::GoogleMapsClient;
+use crate::places::place_search::text_search::request::Request;
+use crate::types::{Language, LatLng, PlaceType, Region};
+use serde::{Deserialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+//
+/// The serializable parameters of a `Request`. A `Request` holds a reference to
+/// its client, so it can not be deserialized on its own. The parameters are
+/// deserialized into this struct first, and then bound to a client.
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Parameters {
+    input: String,
+    radius: u32,
+    #[serde(default)]
+    language: Option<Language>,
+    #[serde(default)]
+    location: Option<LatLng>,
+    #[serde(default)]
+    maxprice: Option<u8>,
+    #[serde(default)]
+    minprice: Option<u8>,
+    #[serde(default)]
+    opennow: Option<bool>,
+    #[serde(default)]
+    pagetoken: Option<String>,
+    #[serde(default)]
+    region: Option<Region>,
+    #[serde(default)]
+    place_type: Option<PlaceType>,
+} // struct
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Deserializes a request's parameters, such as those previously saved
+    /// by serializing a `Request`, and binds them to a client.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ The client that the request will be sent with.
+    ///
+    /// * `deserializer` ‧ Any `serde` deserializer, for example a
+    /// `serde_json::Deserializer`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let json = serde_json::to_string(&google_maps_client.text_search(
+    ///     String::from("123 Main Street"),
+    ///     50_000,
+    /// ).with_type(PlaceType::Restaurant))?;
+    ///
+    /// let search_results = TextSearchRequest::deserialize_with_client(
+    ///     &google_maps_client,
+    ///     &mut serde_json::Deserializer::from_str(&json),
+    /// )?
+    /// .execute()
+    /// .await?;
+    /// ```
+
+    pub fn deserialize_with_client<'de, D: Deserializer<'de>>(
+        client: &'a GoogleMapsClient,
+        deserializer: D,
+    ) -> Result<Request<'a>, D::Error> {
+
+        let parameters = Parameters::deserialize(deserializer)?;
+
+        Ok(Request {
+            // Required parameters:
+            client,
+            input: parameters.input,
+            radius: parameters.radius,
+            // Optional parameters:
+            language: parameters.language.or_else(|| client.default_language.clone()),
+            location: parameters.location,
+            maxprice: parameters.maxprice,
+            minprice: parameters.minprice,
+            opennow: parameters.opennow,
+            pagetoken: parameters.pagetoken,
+            region: parameters.region.or_else(|| client.default_region.clone()),
+            place_type: parameters.place_type,
+            // Internal use only:
+            billing_tag: None,
+            query: None,
+        }) // Request
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn request(client: &GoogleMapsClient) -> Request<'_> {
+        let mut request = client.text_search(String::from("pizza"), 1_000);
+        request.language = Some(Language::French);
+        request.location = Some(LatLng::try_from_dec(dec!(43.6532), dec!(-79.3832)).unwrap());
+        request.maxprice = Some(3);
+        request.minprice = Some(1);
+        request.opennow = Some(true);
+        request.pagetoken = Some(String::from("next-page"));
+        request.region = Some(Region::Canada);
+        request.place_type = Some(PlaceType::Restaurant);
+        request
+    } // fn
+
+    #[test]
+    fn round_trips_every_parameter() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let json = serde_json::to_value(request(&client)).unwrap();
+        // Every parameter is populated, so that a parameter that is missing
+        // from `Parameters` is rejected as an unknown field:
+        assert!(
+            json.as_object().unwrap().values().all(|value| !value.is_null()),
+            "a parameter is not populated: {json}",
+        ); // assert!
+        let restored = Request::deserialize_with_client(&client, json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(restored).unwrap(), json);
+    } // fn
+
+    #[test]
+    fn rejects_unknown_parameters() {
+        let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        let mut json = serde_json::to_value(request(&client)).unwrap();
+        json["unknown"] = serde_json::Value::Bool(true);
+        assert!(Request::deserialize_with_client(&client, json).is_err());
+    } // fn
+
+    #[test]
+    fn falls_back_to_the_client_defaults() {
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client.with_default_language(Language::German).with_default_region(Region::Germany);
+        let client = client.build();
+        let saved = request(&client);
+        let mut json = serde_json::to_value(&saved).unwrap();
+        // A saved language and region take precedence over the defaults:
+        let restored = Request::deserialize_with_client(&client, json.clone()).unwrap();
+        assert_eq!((restored.language, restored.region), (saved.language, saved.region));
+        // A request saved without them gets the client's defaults, just like a
+        // new request:
+        json.as_object_mut().unwrap().remove("language");
+        json.as_object_mut().unwrap().remove("region");
+        let restored = Request::deserialize_with_client(&client, json).unwrap();
+        assert_eq!(restored.language, Some(Language::German));
+        assert_eq!(restored.region, Some(Region::Germany));
+    } // fn
+
+} // mod
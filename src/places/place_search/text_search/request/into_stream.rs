@@ -0,0 +1,214 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places::{
+    error::Error as PlacesError,
+    place::Place,
+    place_search::text_search::{
+        request::Request as TextSearchRequest,
+        response::Response as TextSearchResponse,
+    }, // place_search::text_search
+    status::Status as PlacesStatus,
+}; // crate::places
+use futures::stream::{self, Stream};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+/// There is a short delay between when a `next_page_token` is issued, and when
+/// it will become valid. This is how long the stream waits before requesting
+/// the next page.
+const PAGE_TOKEN_DELAY: Duration = Duration::from_secs(2);
+
+/// If the `next_page_token` is still not valid after waiting, Google responds
+/// with `INVALID_REQUEST`. This is how many times the stream will wait and
+/// try again before giving up and returning the error.
+const PAGE_TOKEN_RETRIES: u8 = 5;
+
+// =============================================================================
+
+impl<'a> TextSearchRequest<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built and returns the results as an
+    /// asynchronous `Stream` of places.
+    ///
+    /// ## Description:
+    ///
+    /// A _Text Search_ returns up to 20 results per page, and up to 60 results
+    /// in total. This method transparently follows the `next_page_token` for
+    /// you: when the places from one page have been consumed, the next page is
+    /// requested. The stream ends when Google no longer returns a
+    /// `next_page_token`.
+    ///
+    /// Google's `next_page_token` does not become valid immediately. The
+    /// stream waits a short moment before requesting each subsequent page and,
+    /// if Google responds with `INVALID_REQUEST` because the token is not
+    /// ready yet, it waits and tries again a few times before giving up.
+    ///
+    /// A `ZERO_RESULTS` response ends the stream without an error. Any other
+    /// error is yielded as the stream's final item.
+    ///
+    /// When the stream ends, the request's page token is restored to what it
+    /// was before the stream started, so that executing the request again
+    /// returns the first page rather than the last one.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// use futures::StreamExt;
+    ///
+    /// let mut request = google_maps_client.text_search("pizza in New York".to_string(), 5_000);
+    /// let mut places = Box::pin(request.into_stream());
+    ///
+    /// while let Some(place) = places.next().await {
+    ///     println!("{:?}", place?.name);
+    /// }
+    /// ```
+
+    pub fn into_stream(
+        &mut self
+    ) -> impl Stream<Item = Result<Place, GoogleMapsError>> + use<'_, 'a> {
+
+        // The stream's state is made up of: the request, the page token that
+        // the request started with, the places from the current page that
+        // have not been yielded yet, and the next page to request. The next
+        // page is `Some(None)` for the first page, `Some(Some(token))` for
+        // subsequent pages, and `None` once there are no more pages:
+        let first_page = self.pagetoken.clone();
+        let state: (&mut TextSearchRequest<'a>, Option<String>, VecDeque<Place>, Option<Option<String>>) =
+            (self, first_page, VecDeque::new(), Some(None));
+
+        stream::unfold(state, |(request, first_page, mut places, mut next_page)| async move {
+            loop {
+
+                // Yield any places left over from the current page first:
+                if let Some(place) = places.pop_front() {
+                    return Some((Ok(place), (request, first_page, places, next_page)));
+                } // if
+
+                // Current page is exhausted. If there isn't a next page, the
+                // stream is finished:
+                let Some(page_token) = next_page.take() else {
+                    request.pagetoken = first_page;
+                    return None;
+                }; // let
+
+                match request.get_page(page_token).await {
+                    Ok(response) => {
+                        next_page = response.next_page_token.map(Some);
+                        places.extend(response.results);
+                    }, // Ok
+                    // Zero results is not an error, there is just nothing to
+                    // stream:
+                    Err(GoogleMapsError::Places(PlacesError::GoogleMapsService(PlacesStatus::ZeroResults, _))) => {
+                        request.pagetoken = first_page;
+                        return None;
+                    }, // Err
+                    // Yield the error and end the stream:
+                    Err(error) => return Some((Err(error), (request, first_page, places, None))),
+                } // match
+
+            } // loop
+        }) // unfold
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Requests a single page of results. If a page token is provided, waits
+    /// for the token to become valid and retries while Google reports that it
+    /// is not ready yet.
+
+    async fn get_page(
+        &mut self,
+        page_token: Option<String>,
+    ) -> Result<TextSearchResponse, GoogleMapsError> {
+
+        let mut attempts: u8 = 0;
+        let is_next_page = page_token.is_some();
+
+        if let Some(page_token) = page_token {
+            self.pagetoken = Some(page_token);
+        } // if
+
+        self.check()?;
+        self.build_query();
+        let client = self.client;
+
+        loop {
+
+            if is_next_page {
+                client.timer.sleep(PAGE_TOKEN_DELAY).await;
+            } // if
+
+            attempts += 1;
+
+            match self.get().await {
+                Err(GoogleMapsError::Places(PlacesError::GoogleMapsService(PlacesStatus::InvalidRequest, _)))
+                    if is_next_page && attempts < PAGE_TOKEN_RETRIES => {
+                        tracing::debug!("Text Search `next_page_token` is not valid yet. Trying again.");
+                    }, // Err
+                response => return response,
+            } // match
+
+        } // loop
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::test_server::{block_on, TestServer};
+    use crate::client::{GoogleMapsClient, RetryPolicy, Timer};
+    use crate::request_rate::api::Api;
+    use futures::future::BoxFuture;
+    use futures::StreamExt;
+    use std::time::Duration;
+
+    /// A `Timer` that does not wait, so that the test does not wait for page
+    /// tokens to become valid.
+    #[derive(Debug)]
+    struct NoDelay;
+
+    impl Timer for NoDelay {
+        fn sleep(&self, _duration: Duration) -> BoxFuture<'static, ()> {
+            Box::pin(async {})
+        } // fn
+    } // impl
+
+    #[test]
+    fn drained_stream_restores_the_page_token() {
+        let server = TestServer::start_sequence(&[
+            (200, &[], r#"{"html_attributions": [], "results": [{"name": "One"}], "status": "OK", "next_page_token": "page 2"}"#),
+            (200, &[], r#"{"html_attributions": [], "results": [{"name": "Two"}], "status": "OK"}"#),
+        ]); // start_sequence
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_endpoint_override(Api::Places, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none())
+            .with_timer(NoDelay);
+        let client = client.build();
+        let mut request = client.text_search(String::from("pizza"), 1_000);
+
+        let names: Vec<Option<String>> = block_on(
+            request.into_stream().map(|place| place.unwrap().name).collect()
+        ); // block_on
+        assert_eq!(names, [Some(String::from("One")), Some(String::from("Two"))]);
+
+        let targets = server.targets();
+        assert_eq!(targets.len(), 2);
+        assert!(!targets[0].contains("pagetoken"), "{}", targets[0]);
+        assert!(targets[1].contains("&pagetoken=page%202"), "{}", targets[1]);
+        assert_eq!(request.pagetoken, None);
+    } // fn
+
+} // mod
@@ -4,13 +4,18 @@
 //! structs, methods) for building your Google Maps Platform request.
 
 mod build;
+mod deserialize_with_client;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
 #[cfg(feature = "enable-reqwest")]
 mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_stream;
 mod is_open_now;
 mod new;
 mod query_url;
+mod validate;
+mod with_billing_tag;
 mod with_language;
 mod with_location;
 mod with_max_price;
@@ -22,20 +27,27 @@ mod with_type;
 // -----------------------------------------------------------------------------
 
 use crate::{client::GoogleMapsClient, types::Language, types::LatLng, types::PlaceType, types::Region};
+use serde::Serialize;
 
 // -----------------------------------------------------------------------------
 
 /// **Look at this `Request` struct for documentation on how to build your
 /// _Text Search_ query**. The methods implemented for this struct are
 /// what's used to build your request.
+///
+/// A request's parameters may be serialized, for example to save a search to
+/// disk. The client, and any query string that has already been built, are not
+/// serialized. Use `Request::deserialize_with_client` to load the parameters
+/// again.
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Request<'a> {
     // Required parameters:
     // --------------------
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
+    #[serde(skip)]
     client: &'a GoogleMapsClient,
 
     /// The text string on which to search, for example: "restaurant" or "123
@@ -152,7 +164,14 @@ pub struct Request<'a> {
     // Internal use only:
     // ------------------
 
+    /// A caller-defined tag, such as a product feature, that is recorded in the
+    /// `billing_tag` field of the request's `tracing` span. It is never sent to
+    /// Google.
+    #[serde(skip)]
+    billing_tag: Option<String>,
+
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
     query: Option<String>,
 
 } // struct
\ No newline at end of file
@@ -54,15 +54,16 @@ impl<'a> Request<'a> {
             input: query,
             radius,
             // Optional parameters:
-            language: None,
+            language: client.default_language.clone(),
             location: None,
             maxprice: None,
             minprice: None,
             opennow: None,
             pagetoken: None,
-            region: None,
+            region: client.default_region.clone(),
             place_type: None,
             // Internal use only:
+            billing_tag: None,
             query: None,
         } // struct
 
@@ -0,0 +1,38 @@
+use crate::places::{
+    error::Error,
+    place_search::text_search::request::Request,
+    MAX_RADIUS,
+}; // crate::places
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Ensures the built query is valid. This function checks the parameters
+    /// to ensure that Google Maps Places API will accept them - i.e. it will
+    /// not allow a radius over 50,000 meters.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&'a mut self) -> Result<&'a mut Request, Error> {
+        self.check()?;
+        Ok(self)
+    } // fn
+
+    /// Performs the checks for `validate` without consuming the mutable
+    /// borrow, so that `into_stream` can validate each page's request.
+
+    pub(super) fn check(&self) -> Result<(), Error> {
+
+        // The radius cannot be more than 50,000 meters:
+        if self.radius > MAX_RADIUS {
+            return Err(Error::RadiusTooLarge(self.radius));
+        } // if
+
+        Ok(())
+
+    } // fn
+
+} // impl
@@ -5,6 +5,13 @@
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "geojson")]
+mod geojson;
+mod operational;
+mod sorted_by_distance;
+
+// -----------------------------------------------------------------------------
+
 use crate::places::Place;
 use crate::places::status::Status;
 use serde::{Deserialize, Serialize};
@@ -72,4 +79,57 @@ impl std::str::FromStr for Response {
     fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
         serde_json::from_str(s)
     } // fn from_str
-} // impl FromStr
\ No newline at end of file
+} // impl FromStr
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Response {
+    /// Formats a concise, one-line summary of the Text Search response for
+    /// logging: the status, the number of places, the name of the first place,
+    /// and whether there are more pages of results. Use `{:#?}` to see the
+    /// entire response.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Text Search {}: {} place(s)", self.status, self.results.len())?;
+        if let Some(name) = self.results.first().and_then(|place| place.name.as_ref()) {
+            write!(f, ". First: {name}")?;
+        } // if
+        if self.next_page_token.is_some() {
+            write!(f, ". More results available")?;
+        } // if
+        Ok(())
+    } // fn fmt
+} // impl Display
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn display_shows_the_first_place() {
+        let response: Response = serde_json::from_value(json!({
+            "html_attributions": [],
+            "next_page_token": "CpQCAgEAAFxg8o",
+            "results": [{ "name": "Sydney Opera House" }, { "name": "Sydney Tower Eye" }],
+            "status": "OK",
+        })).unwrap(); // from_value
+        assert_eq!(
+            response.to_string(),
+            "Text Search OK: 2 place(s). First: Sydney Opera House. More results available",
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn display_without_results() {
+        let response: Response = serde_json::from_value(json!({
+            "html_attributions": [],
+            "results": [],
+            "status": "ZERO_RESULTS",
+        })).unwrap(); // from_value
+        assert_eq!(response.to_string(), format!("Text Search {}: 0 place(s)", response.status));
+    } // fn
+
+} // mod
@@ -0,0 +1,34 @@
+use crate::places::{place::Place, place_search::text_search::response::Response};
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the places in the _Text Search_ response, sorted by their
+    /// distance from the specified point, nearest first. Google's ranking of
+    /// the results is ignored.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `from` ‧ The reference point that distances are measured from. The
+    /// distance to each place's `geometry.location` is calculated using the
+    /// haversine formula.
+    ///
+    /// ## Description:
+    ///
+    /// Places that have no coordinates are skipped.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let nearest_first = search_results.sorted_by_distance(&my_location);
+    /// ```
+
+    pub fn sorted_by_distance(&self, from: &LatLng) -> Vec<&Place> {
+        crate::places::place_search::results::sorted_by_distance(&self.results, from)
+    } // fn
+
+} // impl
@@ -19,7 +19,7 @@ pub mod request;
 
 // -----------------------------------------------------------------------------
 
-const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/place/autocomplete";
+const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/place/queryautocomplete";
 const OUTPUT_FORMAT: &str = "json"; // json or xml
 
 // -----------------------------------------------------------------------------
@@ -1,5 +1,5 @@
 use crate::places::query_autocomplete::request::Request;
-use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use crate::query_string::QueryString;
 
 // -----------------------------------------------------------------------------
 
@@ -17,41 +17,35 @@ impl<'a> Request<'a> {
         // This section builds the "required parameters" portion of the query
         // string:
 
-        let mut query = format!(
-            "key={}&input={}",
-            self.client.key,
-            utf8_percent_encode(&self.input, NON_ALPHANUMERIC),
-        );
+        let mut query = QueryString::new(&self.client.key);
+        query.push_encoded("input", &self.input);
+
 
         // This section builds the "optional parameters" portion of the query
         // string:
 
         // Language key/value pair:
         if let Some(language) = &self.language {
-            query.push_str("&language=");
-            query.push_str(&String::from(language))
+            query.push("language", language.into())
         }
 
         // Location key/value pair:
         if let Some(location) = &self.location {
-            query.push_str("&location=");
-            query.push_str(&String::from(location))
+            query.push_display("location", location)
         }
 
         // Offset key/value pair:
         if let Some(offset) = &self.offset {
-            query.push_str("&offset=");
-            query.push_str(&offset.to_string())
+            query.push_display("offset", offset)
         }
 
         // Radius key/value pair:
         if let Some(radius) = &self.radius {
-            query.push_str("&radius=");
-            query.push_str(&radius.to_string())
+            query.push_display("radius", radius)
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
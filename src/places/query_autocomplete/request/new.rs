@@ -27,11 +27,12 @@ impl<'a> Request<'a> {
             client,
             input,
             // Optional parameters:
-            language: None,
+            language: client.default_language.clone(),
             location: None,
             offset: None,
             radius: None,
             // Internal use only:
+            billing_tag: None,
             query: None,
         } // struct
 
@@ -0,0 +1,30 @@
+use crate::places::place_autocomplete::error::Error;
+use crate::places::query_autocomplete::request::Request;
+use crate::places::MAX_RADIUS;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Ensures the built query is valid. This function checks the parameters
+    /// to ensure that Google Maps Places API will accept them - i.e. it will
+    /// not allow a radius over 50,000 meters.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&'a mut self) -> Result<&'a mut Request, Error> {
+
+        // The radius cannot be more than 50,000 meters:
+        if let Some(radius) = self.radius.filter(|radius| *radius > MAX_RADIUS) {
+            return Err(Error::RadiusTooLarge(radius));
+        } // if
+
+        // If we've gotten to this point, all tests passed. Return `Ok` to
+        // caller:
+        Ok(self)
+
+    } // fn
+
+} // impl
@@ -48,4 +48,28 @@ impl<'a> Request<'a> {
         self
     } // fn
 
+    /// Adds the location parameter to the Place API _Query Autocomplete_
+    /// query. Use `with_radius` to also specify the distance around the
+    /// location within which results are preferred.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `location` ‧ The point around which to retrieve place information.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_location(LatLng::try_from_dec(dec!(53.5461), dec!(-113.4938))?)
+    /// ```
+
+    pub fn with_location(
+        &'a mut self,
+        location: LatLng,
+    ) -> &'a mut Request {
+        // Set location in Request struct.
+        self.location = Some(location);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
 } // impl
\ No newline at end of file
@@ -0,0 +1,35 @@
+use crate::places::query_autocomplete::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Adds the radius parameter to the Place API _Query Autocomplete_ query.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `radius` ‧ Defines the distance (in meters) within which to return
+    /// place results. You may bias results to a specified circle by passing a
+    /// `location` and a `radius` parameter. Doing so instructs the Places
+    /// service to prefer showing results within that circle; results outside of
+    /// the defined area may still be displayed. The maximum radius is 50,000
+    /// meters. `validate` returns an error for a larger radius, rather than
+    /// letting Google clamp it.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_radius(5_000)
+    /// ```
+
+    pub fn with_radius(
+        &'a mut self,
+        radius: u32,
+    ) -> &'a mut Request {
+        // Set radius in Request struct.
+        self.radius = Some(radius);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
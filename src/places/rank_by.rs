@@ -137,7 +137,7 @@ impl RankBy {
     pub fn display(&self) -> &str {
         match self {
             RankBy::Prominence => "Prominence",
-            RankBy::Distance => "Ditance",
+            RankBy::Distance => "Distance",
         } // match
     } // fn
 } // impl
\ No newline at end of file
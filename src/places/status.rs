@@ -24,6 +24,18 @@ pub enum Status {
     #[serde(alias = "INVALID_REQUEST")]
     InvalidRequest,
     /// Indicates any of the following:
+    /// * The API key is missing or invalid.
+    /// * Billing has not been enabled on your account.
+    /// * A self-imposed usage cap has been exceeded.
+    /// * The provided method of payment is no longer valid (for example, a
+    /// credit card has expired).
+    ///
+    /// See the [Maps
+    /// FAQ](https://developers.google.com/maps/faq#over-limit-key-error) to
+    /// learn how to fix this.
+    #[serde(alias = "OVER_DAILY_LIMIT")]
+    OverDailyLimit,
+    /// Indicates any of the following:
     /// * You have exceeded the QPS limits.
     /// * Billing has not been enabled on your account.
     /// * The monthly $200 credit, or a self-imposed usage cap, has been
@@ -73,6 +85,7 @@ impl std::convert::From<&Status> for String {
         match status {
             Status::InvalidRequest => String::from("INVALID_REQUEST"),
             Status::Ok => String::from("OK"),
+            Status::OverDailyLimit => String::from("OVER_DAILY_LIMIT"),
             Status::OverQueryLimit => String::from("OVER_QUERY_LIMIT"),
             Status::RequestDenied => String::from("REQUEST_DENIED"),
             Status::UnknownError => String::from("UNKNOWN_ERROR"),
@@ -87,6 +100,7 @@ impl std::convert::From<&Status> for String {
 static STATUSES_BY_CODE: phf::Map<&'static str, Status> = phf_map! {
     "INVALID_REQUEST" => Status::InvalidRequest,
     "OK" => Status::Ok,
+    "OVER_DAILY_LIMIT" => Status::OverDailyLimit,
     "OVER_QUERY_LIMIT" => Status::OverQueryLimit,
     "REQUEST_DENIED" => Status::RequestDenied,
     "UNKNOWN_ERROR" => Status::UnknownError,
@@ -142,6 +156,7 @@ impl std::fmt::Display for Status {
         match self {
             Status::InvalidRequest => write!(f, "Invalid Request"),
             Status::Ok => write!(f, "OK"),
+            Status::OverDailyLimit => write!(f, "Over Daily Limit"),
             Status::OverQueryLimit => write!(f, "Over Query Limit"),
             Status::RequestDenied => write!(f, "Request Denied"),
             Status::UnknownError => write!(f, "Unknown Error"),
@@ -24,6 +24,9 @@ pub use crate::{
     types::error::Error as TypeError,
 }; // crate
 
+#[cfg(feature = "enable-reqwest")]
+pub use crate::client::{CircuitBreaker, CircuitState, EnvKeys, KeySource, MemoryCache, ResponseCache, RetryPolicy, RoundRobinKeys, TapEvent, Timer, TokioTimer};
+
 #[cfg(any(feature = "geocoding", feature = "places", feature = "geocoding"))]
 pub use crate::types::address_component::AddressComponent;
 #[cfg(any(feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
@@ -34,11 +37,13 @@ pub use crate::types::country::Country;
 pub use crate::types::geometry::Geometry;
 #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places", feature = "time_zone"))]
 pub use crate::types::language::Language;
-#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "elevation", feature = "geocoding", feature = "places", feature = "roads", feature = "time_zone"))]
+#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "elevation", feature = "geocoding", feature = "geolocation", feature = "places", feature = "roads", feature = "time_zone"))]
 pub use crate::types::latlng::LatLng;
 #[cfg(any(feature = "geocoding", feature = "places", feature = "geocoding"))]
 pub use crate::types::location_type::LocationType;
 #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
+pub use crate::types::place_id::PlaceId;
+#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
 pub use crate::types::place_type::PlaceType;
 #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
 pub use crate::types::region::Region;
@@ -52,6 +57,9 @@ pub use crate::types::region::Region;
 #[cfg(feature = "enable-reqwest")]
 pub use crate::request_rate::api::Api;
 
+#[cfg(feature = "enable-reqwest")]
+pub use crate::request_rate::rate_limit_status::RateLimitStatus;
+
 // -----------------------------------------------------------------------------
 
 #[cfg(any(feature = "directions", feature = "distance_matrix"))]
@@ -83,6 +91,7 @@ pub use crate::directions::{
         transit_stop::TransitStop,
         transit_time::TransitTime,
         transit_vehicle::TransitVehicle,
+        turn_instruction::TurnInstruction,
     }, // response
     travel_mode::TravelMode,
     vehicle_type::VehicleType,
@@ -97,6 +106,7 @@ pub use crate::directions::{
     response::{
         directions_distance::DirectionsDistance,
         directions_duration::DirectionsDuration,
+        distance::Distance,
     }, // response
 }; // crate::directions
 
@@ -146,6 +156,20 @@ pub use crate::geocoding::{
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "geolocation")]
+pub use crate::geolocation::{
+    error::Error as GeolocationError,
+    request::{
+        cell_tower::CellTower,
+        radio_type::RadioType,
+        Request as GeolocationRequest,
+        wifi_access_point::WiFiAccessPoint,
+    }, // request
+    response::Response as GeolocationResponse,
+}; // crate::geolocation
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "time_zone")]
 pub use crate::time_zone::{
     error::Error as TimeZoneError,
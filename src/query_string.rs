@@ -0,0 +1,209 @@
+//! Contains the `QueryString` struct, which the request modules use to build
+//! their URL query strings.
+
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use std::fmt::{Display, Write};
+
+// -----------------------------------------------------------------------------
+
+/// The initial capacity of a query string's buffer, in bytes. This is large
+/// enough for most requests, so that the buffer rarely has to grow.
+
+const INITIAL_CAPACITY: usize = 256;
+
+// -----------------------------------------------------------------------------
+//
+/// A URL query string that is written into a single buffer.
+///
+/// Values are percent-encoded directly into the buffer as they are pushed,
+/// rather than being encoded into a temporary `String` first, and lists are
+/// joined as they are encoded, rather than being collected and joined first.
+
+#[derive(Debug)]
+pub(crate) struct QueryString(String);
+
+// -----------------------------------------------------------------------------
+
+impl QueryString {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Starts a new query string with the `key` parameter.
+
+    pub(crate) fn new(key: &str) -> QueryString {
+        let mut buffer = String::with_capacity(INITIAL_CAPACITY);
+        buffer.push_str("key=");
+        buffer.push_str(key);
+        QueryString(buffer)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Appends a parameter whose value is already safe to use in a URL, such
+    /// as a language or region code.
+
+    pub(crate) fn push(&mut self, name: &str, value: &str) {
+        self.push_name(name);
+        self.0.push_str(value);
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Appends a parameter, percent-encoding its value.
+
+    pub(crate) fn push_encoded(&mut self, name: &str, value: &str) {
+        self.push_name(name);
+        self.0.extend(utf8_percent_encode(value, NON_ALPHANUMERIC));
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Appends a parameter whose value is formatted with `Display`, and is
+    /// already safe to use in a URL, such as a latitude/longitude pair.
+
+    pub(crate) fn push_display(&mut self, name: &str, value: &impl Display) {
+        self.push_name(name);
+        // Writing into a `String` cannot fail:
+        let _ = write!(self.0, "{value}");
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Appends a parameter whose value is formatted with `Display`,
+    /// percent-encoding the value as it is formatted.
+
+    pub(crate) fn push_encoded_display(&mut self, name: &str, value: &impl Display) {
+        self.push_name(name);
+        // Writing into a `String` cannot fail:
+        let _ = write!(Encoder(&mut self.0), "{value}");
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Appends a parameter whose value is a `|` pipe-separated list,
+    /// percent-encoding each item.
+
+    pub(crate) fn push_encoded_list<I, S>(&mut self, name: &str, values: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.push_prefixed_encoded_list(name, "", values);
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Appends a parameter whose value is a prefix that is already safe to use
+    /// in a URL, such as the _Directions_ API's `optimize:true|`, followed by a
+    /// `|` pipe-separated list, percent-encoding each item.
+
+    pub(crate) fn push_prefixed_encoded_list<I, S>(&mut self, name: &str, prefix: &str, values: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.push_name(name);
+        self.0.push_str(prefix);
+        for (index, value) in values.into_iter().enumerate() {
+            if index > 0 { self.0.push_str("%7C") }
+            self.0.extend(utf8_percent_encode(value.as_ref(), NON_ALPHANUMERIC));
+        } // for
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Appends a parameter that has no value, such as the _Places_ API's
+    /// `opennow`.
+
+    pub(crate) fn push_flag(&mut self, name: &str) {
+        self.0.push('&');
+        self.0.push_str(name);
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Appends the `&name=` portion of a parameter.
+
+    fn push_name(&mut self, name: &str) {
+        self.0.push('&');
+        self.0.push_str(name);
+        self.0.push('=');
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<QueryString> for String {
+    /// Returns the finished query string.
+    fn from(query: QueryString) -> String {
+        query.0
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Percent-encodes everything that is written to it into a buffer.
+
+struct Encoder<'a>(&'a mut String);
+
+impl Write for Encoder<'_> {
+    fn write_str(&mut self, string: &str) -> std::fmt::Result {
+        self.0.extend(utf8_percent_encode(string, NON_ALPHANUMERIC));
+        Ok(())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn starts_with_the_key() {
+        assert_eq!(String::from(QueryString::new("API_KEY")), "key=API_KEY");
+    } // fn
+
+    #[test]
+    fn pushes_values_as_is() {
+        let mut query = QueryString::new("API_KEY");
+        query.push("language", "fr");
+        query.push_display("latlng", &"43.6532,-79.3832");
+        assert_eq!(String::from(query), "key=API_KEY&language=fr&latlng=43.6532,-79.3832");
+    } // fn
+
+    #[test]
+    fn percent_encodes_values() {
+        let mut query = QueryString::new("API_KEY");
+        query.push_encoded("address", "Barnes & Noble, 東京");
+        query.push_encoded_display("bounds", &"1,2|3,4");
+        assert_eq!(
+            String::from(query),
+            "key=API_KEY\
+            &address=Barnes%20%26%20Noble%2C%20%E6%9D%B1%E4%BA%AC\
+            &bounds=1%2C2%7C3%2C4",
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn joins_lists_with_encoded_pipes() {
+        let mut query = QueryString::new("API_KEY");
+        query.push_encoded_list("components", ["country:CA", "postal_code:M5V 3L9"]);
+        query.push_encoded_list("result_type", Vec::<String>::new());
+        assert_eq!(
+            String::from(query),
+            "key=API_KEY&components=country%3ACA%7Cpostal%5Fcode%3AM5V%203L9&result_type=",
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn pushes_prefixed_lists_and_flags() {
+        let mut query = QueryString::new("API_KEY");
+        query.push_prefixed_encoded_list("waypoints", "optimize:true|", ["Barrie", "Orillia"]);
+        query.push_flag("opennow");
+        assert_eq!(String::from(query), "key=API_KEY&waypoints=optimize:true|Barrie%7COrillia&opennow");
+    } // fn
+
+} // mod
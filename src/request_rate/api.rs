@@ -14,13 +14,25 @@ pub enum Api {
     /// This variant is used to select settings that are observed for _all_
     /// APIs. These settings are observed in addition to the per-API settings.
     All,
+    /// Settings observed by _Directions API_ requests only. These are
+    /// independent of the `DistanceMatrix` settings.
     Directions,
+    /// Settings observed by _Distance Matrix API_ requests only. These are
+    /// independent of the `Directions` settings.
     DistanceMatrix,
+    /// Settings observed by _Elevation API_ requests.
     Elevation,
+    /// Settings observed by forward and reverse _Geocoding API_ requests.
     Geocoding,
+    /// Settings observed by _Time Zone API_ requests.
     TimeZone,
+    /// Settings observed by _Places API_ requests, including the autocomplete
+    /// services.
     Places,
+    /// Settings observed by _Roads API_ requests.
     Roads,
+    /// Settings observed by _Geolocation API_ requests.
+    Geolocation,
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -37,6 +49,7 @@ impl std::convert::From<&Api> for String {
             Api::TimeZone => String::from("Time Zone"),
             Api::Places => String::from("Places"),
             Api::Roads => String::from("Roads"),
+            Api::Geolocation => String::from("Geolocation"),
         } // match
     } // fn
 } // impl
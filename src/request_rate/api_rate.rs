@@ -4,8 +4,11 @@
 
 use crate::request_rate::target_rate::TargetRate; // use
 use stream_throttle::ThrottlePool;
+use std::collections::VecDeque;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 /// Contains the user's specified request rate and the effective current request
 /// rate.
@@ -13,6 +16,10 @@ use std::hash::{Hash, Hasher};
 pub struct ApiRate {
     pub target_rate: TargetRate,
     pub throttle_pool: Option<ThrottlePool>,
+    /// When each request within the most recent `target_rate.duration` was
+    /// let through by the rate limiter. This is only used to report the rate
+    /// limiter's status, and is shared between clones of the client.
+    pub(crate) recent_requests: Arc<Mutex<VecDeque<Instant>>>,
 } // struct
 
 impl std::default::Default for ApiRate {
@@ -21,6 +28,7 @@ impl std::default::Default for ApiRate {
         ApiRate {
             target_rate: TargetRate::default(),
             throttle_pool: None,
+            recent_requests: Arc::default(),
         } // struct
     } // fn
 } // impl
@@ -1,6 +1,7 @@
 use crate::request_rate::{
     api_rate::ApiRate,
 }; // use crate::request_rate
+use std::time::Instant;
 
 impl ApiRate {
 
@@ -19,6 +20,16 @@ impl ApiRate {
             Some(wait_pool) => wait_pool.queue().await,
             None => ()
         }
+        // Record when the request was let through, so that the rate limiter's
+        // status can be reported:
+        let now = Instant::now();
+        let mut recent_requests = self.recent_requests.lock().unwrap_or_else(|error| error.into_inner());
+        recent_requests.push_back(now);
+        while recent_requests
+            .front()
+            .is_some_and(|instant| now.duration_since(*instant) >= self.target_rate.duration) {
+            recent_requests.pop_front();
+        } // while
         /*
         match self.current_rate.first_request {
             // If this is the first request to the API, initialize the
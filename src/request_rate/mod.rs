@@ -8,6 +8,7 @@ mod duration_to_string;
 mod duration_unit;
 mod limit;
 mod rate_to_string;
+pub mod rate_limit_status;
 mod target_rate;
 mod with_rate;
 pub mod api;
@@ -0,0 +1,79 @@
+//! Contains the `RateLimitStatus` struct, a read-only snapshot of an API's
+//! rate limiter.
+
+use crate::request_rate::{api::Api, RequestRate};
+use std::time::{Duration, Instant};
+
+// -----------------------------------------------------------------------------
+//
+/// A snapshot of the rate limiter for an API: its configured rate, how many
+/// requests have been sent within the current window, and how long until the
+/// window has room for another request. Useful for exporting metrics, or for
+/// applying back-pressure to your own job queue before requests are throttled.
+///
+/// The window is a sliding window of the configured `duration`. Retrieve using
+/// the `GoogleMapsClient::rate_limit_status` method.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RateLimitStatus {
+    /// The API that this status is for.
+    pub api: Api,
+    /// The configured number of requests allowed per `duration`.
+    pub requests: u16,
+    /// The configured duration of the rate limiting window.
+    pub duration: Duration,
+    /// The number of requests sent within the last `duration`.
+    pub current_count: usize,
+    /// How long until the oldest request in the window leaves it, making room
+    /// for another request. This is zero if no requests are in the window.
+    pub resets_in: Duration,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl RateLimitStatus {
+
+    /// Returns how many more requests may be sent within the current window
+    /// before the rate limiter starts delaying requests.
+    /// ```rust
+    /// let headroom = status.remaining();
+    /// ```
+
+    pub fn remaining(&self) -> usize {
+        usize::from(self.requests).saturating_sub(self.current_count)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl RequestRate {
+
+    /// Returns a snapshot of the rate limiter for the specified API, or `None`
+    /// if no rate limit has been set for it. This does not affect rate
+    /// limiting.
+
+    pub fn status(&self, api: &Api) -> Option<RateLimitStatus> {
+        let api_rate = self.rate_map.get(api)?;
+        let duration = api_rate.target_rate.duration;
+        let now = Instant::now();
+
+        let recent_requests = api_rate.recent_requests.lock().unwrap_or_else(|error| error.into_inner());
+        let mut in_window = recent_requests
+            .iter()
+            .filter(|instant| now.duration_since(**instant) < duration);
+
+        let oldest = in_window.next();
+        let current_count = oldest.map_or(0, |_oldest| 1 + in_window.count());
+        let resets_in = oldest.map_or(Duration::ZERO, |oldest| duration.saturating_sub(now.duration_since(*oldest)));
+
+        Some(RateLimitStatus {
+            api: api.clone(),
+            requests: api_rate.target_rate.requests,
+            duration,
+            current_count,
+            resets_in,
+        }) // RateLimitStatus
+    } // fn
+
+} // impl
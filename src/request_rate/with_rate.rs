@@ -4,6 +4,7 @@ use crate::request_rate::{
     RequestRate,
     target_rate::TargetRate,
 }; // use crate::request_rate
+use std::sync::Arc;
 use std::time::Duration;
 use stream_throttle::{ThrottleRate, ThrottlePool};
 
@@ -76,6 +77,7 @@ impl RequestRate {
                 self.rate_map.insert(api.clone(), ApiRate {
                     target_rate: TargetRate { requests, duration },
                     throttle_pool,
+                    recent_requests: Arc::default(),
                 });
             }
             // If it has, set the new target request rate but preserve the
@@ -85,6 +87,7 @@ impl RequestRate {
                     // Set new target request rate:
                     target_rate: TargetRate { requests, duration },
                     throttle_pool,
+                    recent_requests: api_rate.recent_requests.clone(),
                 };
             } // ApiRate
         } // match
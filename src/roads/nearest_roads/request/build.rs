@@ -1,5 +1,5 @@
 use crate::roads::nearest_roads::request::Request;
-use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use crate::query_string::QueryString;
 
 // =============================================================================
 
@@ -19,25 +19,11 @@ impl<'a> Request<'a> {
         // This section builds the "required parameters" portion of the query
         // string:
 
-        // Convert `Vec<LatLng>` to `String`:
-        let points: String = self.points
-            .iter()
-            .map(String::from)
-            .collect::<Vec<String>>()
-            .join("|");
-
-        // URL encode path `String`:
-        let points: String =
-            utf8_percent_encode(&points, NON_ALPHANUMERIC).to_string();
-
-        // Build "required parameters" portion of the query string:
-        let query = format!(
-            "key={key}&points={points}",
-            key=self.client.key,
-        );
+        let mut query = QueryString::new(&self.client.key);
+        query.push_encoded_list("points", self.points.iter().map(String::from));
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
@@ -38,6 +38,7 @@ impl<'a> Request<'a> {
             client,
             points,
             // Internal use only:
+            billing_tag: None,
             query: None,
         } // struct
 
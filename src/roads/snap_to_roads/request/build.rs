@@ -1,5 +1,5 @@
 use crate::roads::snap_to_roads::request::Request;
-use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use crate::query_string::QueryString;
 
 // =============================================================================
 
@@ -19,34 +19,19 @@ impl<'a> Request<'a> {
         // This section builds the "required parameters" portion of the query
         // string:
 
-        // Convert `Vec<LatLng>` to `String`:
-        let path: String = self.path
-            .iter()
-            .map(String::from)
-            .collect::<Vec<String>>()
-            .join("|");
-
-        // URL encode path `String`:
-        let path: String =
-            utf8_percent_encode(&path, NON_ALPHANUMERIC).to_string();
-
-        // Build "required parameters" portion of the query string:
-        let mut query = format!(
-            "key={key}&path={path}",
-            key=self.client.key,
-        );
+        let mut query = QueryString::new(&self.client.key);
+        query.push_encoded_list("path", self.path.iter().map(String::from));
 
         // This section builds the "optional parameters" portion of the query
         // string:
 
         // Language key/value pair:
         if let Some(interpolate) = &self.interpolate {
-            query.push_str("&interpolate=");
-            query.push_str(&interpolate.to_string())
+            query.push_display("interpolate", interpolate)
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
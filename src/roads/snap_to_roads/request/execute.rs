@@ -3,6 +3,12 @@ use crate::roads::snap_to_roads::{
     request::Request as SnapToRoadsRequest,
     response::Response as SnapToRoadsResponse,
 }; // crate::roads::snap_to_roads
+use crate::roads::snapped_point::SnappedPoint;
+
+// -----------------------------------------------------------------------------
+
+/// Google's _Snap To Roads_ service accepts at most 100 points per request.
+const MAX_POINTS: usize = 100;
 
 // =============================================================================
 
@@ -18,6 +24,14 @@ impl<'a> SnapToRoadsRequest<'a> {
     /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
     /// builder pattern.
     ///
+    /// Google limits the _Snap To Roads_ `path` to 100 points. If the path is
+    /// longer than that, this method automatically splits it into several
+    /// requests and stitches the results back together into a single
+    /// response. Consecutive requests overlap by one point so that the snapped
+    /// (and optionally interpolated) path remains continuous. The
+    /// `origin_index` of each snapped point refers to its position in the
+    /// full path that was passed in, not the position within a chunk.
+    ///
     /// ## Arguments:
     ///
     /// This method accepts no arguments.
@@ -26,8 +40,254 @@ impl<'a> SnapToRoadsRequest<'a> {
         &'a mut self
     ) -> Result<SnapToRoadsResponse, GoogleMapsError> {
 
-        self.build().get().await
+        if self.path.len() <= MAX_POINTS {
+            self.build().get().await
+        } else {
+            self.execute_in_chunks().await
+        } // if
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes a request whose path is too long for a single request, by
+    /// submitting the path in chunks of `MAX_POINTS` points where the last
+    /// point of one chunk is the first point of the next.
+    ///
+    /// Each chunk is sent using a copy of the request, so that this request is
+    /// left untouched if a chunk fails or the future is dropped.
+
+    async fn execute_in_chunks(
+        &self
+    ) -> Result<SnapToRoadsResponse, GoogleMapsError> {
+
+        let path = &self.path;
+        let mut snapped_points: Vec<SnappedPoint> = Vec::new();
+        let mut warning_message: Option<String> = None;
+        let mut start = 0;
+
+        loop {
+
+            let end = (start + MAX_POINTS).min(path.len());
+            let mut request = self.clone();
+            request.path = path[start..end].to_vec();
+            let response = request.build().get().await?;
+
+            append_chunk(
+                &mut snapped_points,
+                response.snapped_points.unwrap_or_default(),
+                start,
+            ); // append_chunk
+
+            if warning_message.is_none() {
+                warning_message = response.warning_message;
+            } // if
+
+            if end == path.len() { break } // if
+            start = end - 1;
+
+        } // loop
+
+        Ok(SnapToRoadsResponse {
+            snapped_points: Some(snapped_points),
+            warning_message,
+            error: None,
+        }) // SnapToRoadsResponse
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, and returns the raw JSON response body
+    /// along with the response.
+    ///
+    /// ## Description:
+    ///
+    /// Works like `execute`, but also returns the response body exactly as
+    /// Google sent it. Fields that this crate does not model yet are dropped
+    /// from the deserialized response, but remain in the raw body. This is
+    /// useful for debugging, or for reading fields that Google has recently
+    /// added.
+    ///
+    /// Unlike `execute`, the path is never split into chunks, since a single
+    /// raw body could not describe the merged response. Paths that exceed
+    /// Google's per-request limits must be split by the caller.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let (response, body) = request.execute_with_raw().await?;
+    /// ```
+
+    pub async fn execute_with_raw(
+        &'a mut self
+    ) -> Result<(SnapToRoadsResponse, String), GoogleMapsError> {
+
+        self.build().get_with_raw().await
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Appends the snapped points of a chunk that starts at index `start` of the
+/// full path to the points snapped so far.
+///
+/// The original indices are re-numbered so that they refer to the full path
+/// rather than to the chunk. Every chunk after the first one begins with the
+/// last point of the previous chunk, so any point whose re-numbered index was
+/// already covered by the previous chunk is dropped, along with anything
+/// (i.e. interpolated points) preceding it. If Google did not snap the overlap
+/// point in either chunk, nothing is dropped.
+
+fn append_chunk(
+    snapped_points: &mut Vec<SnappedPoint>,
+    chunk_points: Vec<SnappedPoint>,
+    start: usize,
+) {
+
+    // The points of the full path up to (but excluding) this index have been
+    // covered by the previous chunks:
+    let covered = snapped_points
+        .iter()
+        .rev()
+        .find_map(|point| point.origin_index)
+        .map_or(0, |index| index + 1);
+
+    let skip = chunk_points
+        .iter()
+        .rposition(|point| point.origin_index.is_some_and(|index| index + start < covered))
+        .map_or(0, |position| position + 1);
+
+    snapped_points.extend(
+        chunk_points
+            .into_iter()
+            .skip(skip)
+            .map(|mut point| {
+                point.origin_index = point.origin_index.map(|index| index + start);
+                point
+            }) // map
+    ); // extend
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::client::test_server::{block_on, TestServer};
+    use crate::client::{GoogleMapsClient, RetryPolicy};
+    use crate::request_rate::api::Api;
+    use crate::types::LatLng;
+
+    /// Returns a client that sends _Roads API_ requests to the test server,
+    /// without retrying.
+    fn client(server: &TestServer) -> GoogleMapsClient {
+        let mut client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+        client
+            .with_endpoint_override(Api::Roads, server.url.as_str())
+            .with_retry_policy(RetryPolicy::none());
+        client.build()
+    } // fn
+
+    /// Returns a path of the specified number of points.
+    fn path(points: usize) -> Vec<LatLng> {
+        (0..points)
+            .map(|point| LatLng::try_from_f64(45.0, -75.0 + point as f64 / 1_000.0).unwrap())
+            .collect()
+    } // fn
+
+    #[test]
+    fn chunks_are_stitched_together() {
+        let server = TestServer::start(200, &[], r#"{"snappedPoints": [
+            {"location": {"latitude": 45.0, "longitude": -75.0}, "originalIndex": 0},
+            {"location": {"latitude": 45.0, "longitude": -75.0}},
+            {"location": {"latitude": 45.0, "longitude": -75.0}, "originalIndex": 1}
+        ]}"#);
+        let client = client(&server);
+        let request = client.snap_to_roads(path(150));
+
+        let response = block_on(request.execute_in_chunks()).unwrap();
+        assert_eq!(server.targets().len(), 2);
+
+        // The server answers both chunks with the same points. The second
+        // chunk starts at index 99 of the full path, which the first chunk did
+        // not snap, so none of its points are dropped:
+        let indices: Vec<Option<usize>> = response.snapped_points
+            .unwrap()
+            .iter()
+            .map(|point| point.original_index())
+            .collect();
+        assert_eq!(indices, vec![Some(0), None, Some(1), Some(99), None, Some(100)]);
+        assert_eq!(request.path.len(), 150);
+    } // fn
+
+    #[test]
+    fn failed_chunk_leaves_request_untouched() {
+        let server = TestServer::start(500, &[], "");
+        let client = client(&server);
+        let request = client.snap_to_roads(path(150));
+
+        assert!(block_on(request.execute_in_chunks()).is_err());
+        assert_eq!(server.targets().len(), 1);
+        assert_eq!(request.path, path(150));
+    } // fn
+
+    /// Returns snapped points with the specified original indices. `None` is
+    /// an interpolated point.
+    fn snapped(indices: &[Option<usize>]) -> Vec<SnappedPoint> {
+        indices
+            .iter()
+            .map(|index| SnappedPoint {
+                location: LatLng::try_from_f64(45.0, -75.0).unwrap(),
+                place_id: None,
+                origin_index: *index,
+            }) // map
+            .collect()
+    } // fn
+
+    /// Appends chunks that start at indices 0 and 2 of the full path, and
+    /// returns the merged original indices.
+    fn merge(first: &[Option<usize>], second: &[Option<usize>]) -> Vec<Option<usize>> {
+        let mut snapped_points = Vec::new();
+        append_chunk(&mut snapped_points, snapped(first), 0);
+        append_chunk(&mut snapped_points, snapped(second), 2);
+        snapped_points.iter().map(|point| point.origin_index).collect()
+    } // fn
+
+    #[test]
+    fn overlap_point_is_not_duplicated() {
+        assert_eq!(
+            merge(&[Some(0), Some(1), None, Some(2)], &[None, Some(0), None, Some(1)]),
+            vec![Some(0), Some(1), None, Some(2), None, Some(3)],
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn overlap_point_not_snapped_by_the_second_chunk() {
+        // With interpolation, the second chunk may begin with points that have
+        // no original index:
+        assert_eq!(
+            merge(&[Some(0), Some(1), None, Some(2)], &[None, None, Some(1), Some(2)]),
+            vec![Some(0), Some(1), None, Some(2), None, None, Some(3), Some(4)],
+        ); // assert_eq!
+    } // fn
 
+    #[test]
+    fn overlap_point_not_snapped_by_the_first_chunk() {
+        // The overlap point is kept from the second chunk, since the first
+        // chunk did not cover it:
+        assert_eq!(
+            merge(&[Some(0), Some(1)], &[Some(0), None, Some(1)]),
+            vec![Some(0), Some(1), Some(2), None, Some(3)],
+        ); // assert_eq!
     } // fn
 
-} // impl
\ No newline at end of file
+} // mod
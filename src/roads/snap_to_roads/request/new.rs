@@ -36,6 +36,7 @@ impl<'a> Request<'a> {
             // Optional parameters:
             interpolate: None,
             // Internal use only:
+            billing_tag: None,
             query: None,
         } // struct
 
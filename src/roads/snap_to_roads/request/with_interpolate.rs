@@ -0,0 +1,30 @@
+use crate::roads::snap_to_roads::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Whether to interpolate a path to include all points forming the full
+    /// road-geometry. This is an alias for `with_interpolation` that matches
+    /// the name of Google's `interpolate` parameter.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `interpolate` ‧ When `true`, additional interpolated points will also
+    /// be returned, resulting in a path that smoothly follows the geometry of
+    /// the road, even around corners and through tunnels. Defaults to `false`.
+    ///
+    /// ## Example:
+    ///
+    /// * Turn on interpolation:
+    /// ```rust
+    /// .with_interpolate(true)
+    /// ```
+
+    pub fn with_interpolate(&'a mut self, interpolate: bool) -> &'a mut Request {
+        self.with_interpolation(interpolate)
+    } // fn
+
+} // impl
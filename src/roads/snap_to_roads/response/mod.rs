@@ -46,4 +46,63 @@ impl std::str::FromStr for Response {
     fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
         serde_json::from_str(s)
     } // fn
-} // impl
\ No newline at end of file
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Response {
+    /// Formats a concise, one-line summary of the Snap To Roads response for
+    /// logging: the number of snapped points, or the error returned by Google.
+    /// Use `{:#?}` to see the entire response.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(error) = &self.error {
+            write!(f, "Snap To Roads {}: {}", error.status, error.message)
+        } else {
+            let snapped_points = self.snapped_points.as_ref().map_or(0, Vec::len);
+            write!(f, "Snap To Roads: {snapped_points} snapped point(s)")?;
+            if let Some(warning_message) = &self.warning_message {
+                write!(f, ". Warning: {warning_message}")?;
+            } // if
+            Ok(())
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn display_counts_the_snapped_points() {
+        let response: Response = r#"{
+            "snappedPoints": [
+                { "location": { "latitude": 60.170880, "longitude": 24.942795 }, "placeId": "ChIJNX9BrM0LkkYRIM-cQg265e8" },
+                { "location": { "latitude": 60.170879, "longitude": 24.942796 }, "placeId": "ChIJNX9BrM0LkkYRIM-cQg265e8" }
+            ],
+            "warningMessage": "Input path is too sparse."
+        }"#.parse().unwrap();
+        assert_eq!(
+            response.to_string(),
+            "Snap To Roads: 2 snapped point(s). Warning: Input path is too sparse.",
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn display_shows_the_error() {
+        let response: Response = r#"{
+            "error": {
+                "code": 400,
+                "message": "Invalid request. Invalid 'points' parameter.",
+                "status": "INVALID_ARGUMENT"
+            }
+        }"#.parse().unwrap();
+        assert_eq!(
+            response.to_string(),
+            "Snap To Roads Invalid Argument: Invalid request. Invalid 'points' parameter.",
+        ); // assert_eq!
+    } // fn
+
+} // mod
@@ -31,7 +31,27 @@ pub struct SnappedPoint {
     /// indexed from `0`, so a point with an originalIndex of `4` will be the
     /// snapped value of the 5th latitude/longitude passed to the path
     /// parameter.
+    ///
+    /// Google names this field `originalIndex`. The `original_index` method
+    /// returns it under that name.
     #[serde(alias = "originalIndex")]
     pub origin_index: Option<usize>,
 
-} // struct
\ No newline at end of file
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl SnappedPoint {
+
+    /// Returns the index of the corresponding point in the original request,
+    /// or `None` for an interpolated point. This is the `origin_index` field,
+    /// under the name that Google uses for it.
+    /// ```rust
+    /// let original_index = snapped_point.original_index();
+    /// ```
+
+    pub fn original_index(&self) -> Option<usize> {
+        self.origin_index
+    } // fn
+
+} // impl
@@ -0,0 +1,76 @@
+//! Contains a Serde deserializer that treats empty strings as `None` for
+//! optional `String` fields.
+
+use serde::{Deserialize, Deserializer};
+
+/// This function deserializes an optional `String` field, converting an empty
+/// (or whitespace-only) string into `None`. The Google Maps Platform sometimes
+/// returns `""` for a field that is semantically absent, and other times
+/// `null` or nothing at all. This way, all three are represented as `None`.
+///
+/// Fields using this deserializer must also be marked `#[serde(default)]` so
+/// that a missing field is still accepted.
+
+pub fn empty_string_as_none<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let string: Option<String> = Option::deserialize(deserializer)?;
+    Ok(string.filter(|string| !string.trim().is_empty()))
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Contact {
+        #[serde(default, deserialize_with = "empty_string_as_none")]
+        website: Option<String>,
+    } // struct
+
+    fn website(json: &str) -> Option<String> {
+        serde_json::from_str::<Contact>(json).unwrap().website
+    } // fn
+
+    #[test]
+    fn empty_null_and_missing_are_none() {
+        assert_eq!(website(r#"{ "website": "" }"#), None);
+        assert_eq!(website(r#"{ "website": "  " }"#), None);
+        assert_eq!(website(r#"{ "website": null }"#), None);
+        assert_eq!(website("{}"), None);
+    } // fn
+
+    #[test]
+    fn non_empty_string_is_kept() {
+        assert_eq!(website(r#"{ "website": "https://www.google.com/" }"#).as_deref(), Some("https://www.google.com/"));
+    } // fn
+
+    #[cfg(feature = "places")]
+    #[test]
+    fn place_fields() {
+        let empty: crate::places::Place =
+            r#"{ "formatted_phone_number": "", "website": "" }"#.parse().unwrap();
+        let null: crate::places::Place =
+            r#"{ "formatted_phone_number": null, "website": null }"#.parse().unwrap();
+        assert_eq!(empty.formatted_phone_number, None);
+        assert_eq!(empty.website, None);
+        assert_eq!(empty, null);
+    } // fn
+
+    #[cfg(feature = "geocoding")]
+    #[test]
+    fn plus_code_compound_code() {
+        use crate::geocoding::response::plus_code::PlusCode;
+        let empty: PlusCode =
+            serde_json::from_str(r#"{ "global_code": "849VCWC8+R9", "compound_code": "" }"#).unwrap();
+        let null: PlusCode =
+            serde_json::from_str(r#"{ "global_code": "849VCWC8+R9", "compound_code": null }"#).unwrap();
+        assert_eq!(empty.compound_code, None);
+        assert_eq!(empty, null);
+    } // fn
+
+} // mod
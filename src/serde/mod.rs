@@ -2,5 +2,9 @@
 
 #[cfg(any(feature = "directions", feature = "distance_matrix"))]
 pub mod duration_to_seconds;
+#[cfg(any(feature = "geocoding", feature = "places"))]
+pub mod empty_string_as_none;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub mod rounded_integer;
 #[cfg(any(feature = "directions", feature = "distance_matrix"))]
 pub mod seconds_to_duration;
\ No newline at end of file
@@ -0,0 +1,98 @@
+//! Contains Serde deserializers for integer fields that Google occasionally
+//! returns as floating-point numbers.
+
+use serde::{de::Error, Deserialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+
+/// This function deserializes a JSON number into an `i64`. Google's integer
+/// fields, such as a distance's or duration's `value`, are usually integers
+/// but are sometimes returned as a float (for example `123.0`). A float is
+/// rounded to the nearest integer.
+
+pub fn rounded_i64<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    // `serde_json::Number` is deserialized correctly whether or not
+    // `serde_json`'s `arbitrary_precision` feature is enabled:
+    let number = serde_json::Number::deserialize(deserializer)?;
+    match (number.as_i64(), number.as_f64()) {
+        (Some(integer), _) => Ok(integer),
+        (None, Some(float)) if float.is_finite()
+            && float.round() >= i64::MIN as f64
+            && float.round() < i64::MAX as f64 => Ok(float.round() as i64),
+        _ => Err(D::Error::custom(
+            format!("`{number}` can not be converted to an integer")
+        )), // _
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// This function deserializes a JSON number into a `u32`, rounding a float to
+/// the nearest integer. See `rounded_i64` for more information.
+
+pub fn rounded_u32<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let integer = rounded_i64(deserializer)?;
+    u32::try_from(integer).map_err(|_error| D::Error::custom(
+        format!("`{integer}` is out of range for an unsigned 32-bit integer")
+    )) // map_err
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::directions::response::{
+        directions_distance::DirectionsDistance,
+        directions_duration::DirectionsDuration,
+    }; // crate::directions::response
+
+    #[derive(Debug, Deserialize)]
+    struct Value {
+        #[serde(deserialize_with = "rounded_i64")]
+        signed: i64,
+        #[serde(deserialize_with = "rounded_u32")]
+        unsigned: u32,
+    } // struct
+
+    fn value(json: &str) -> Result<(i64, u32), serde_json::Error> {
+        serde_json::from_str::<Value>(json).map(|value| (value.signed, value.unsigned))
+    } // fn
+
+    #[test]
+    fn integers_and_floats() {
+        assert_eq!(value(r#"{ "signed": -123, "unsigned": 123 }"#).unwrap(), (-123, 123));
+        assert_eq!(value(r#"{ "signed": -123.0, "unsigned": 123.0 }"#).unwrap(), (-123, 123));
+        // Floats are rounded to the nearest integer:
+        assert_eq!(value(r#"{ "signed": 2.5, "unsigned": 122.6 }"#).unwrap(), (3, 123));
+        assert_eq!(value(r#"{ "signed": -2.4, "unsigned": 0.4 }"#).unwrap(), (-2, 0));
+    } // fn
+
+    #[test]
+    fn out_of_range_values() {
+        assert!(value(r#"{ "signed": 1e300, "unsigned": 0 }"#).is_err());
+        assert!(value(r#"{ "signed": 0, "unsigned": -1 }"#).is_err());
+        assert!(value(r#"{ "signed": 0, "unsigned": 4294967296.0 }"#).is_err());
+        assert!(value(r#"{ "signed": "1", "unsigned": 0 }"#).is_err());
+    } // fn
+
+    #[test]
+    fn distance_and_duration_values() {
+        for json in [r#"{ "text": "9.5 km", "value": 9500 }"#, r#"{ "text": "9.5 km", "value": 9500.0 }"#] {
+            let distance: DirectionsDistance = serde_json::from_str(json).unwrap();
+            assert_eq!(distance.value.as_meters(), 9_500);
+        } // for
+        for json in [r#"{ "text": "2 mins", "value": 123 }"#, r#"{ "text": "2 mins", "value": 123.0 }"#] {
+            let duration: DirectionsDuration = serde_json::from_str(json).unwrap();
+            assert_eq!(duration.value.num_seconds(), 123);
+        } // for
+    } // fn
+
+} // mod
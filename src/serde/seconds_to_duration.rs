@@ -2,7 +2,8 @@
 //! in `String` format into a `time::Duration` struct.
 
 use chrono::Duration;
-use serde::{Deserialize, Deserializer};
+use crate::serde::rounded_integer::rounded_i64;
+use serde::Deserializer;
 
 /// This trait converts a quantity of seconds in `String` format into a
 /// `time::Duration` struct. The Google Maps Platform returns duration fields in
@@ -12,9 +13,10 @@ pub fn seconds_to_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error
 where
     D: Deserializer<'de>,
 {
-    // Deserialize the field (from a `String`) into an `i64`. This is what the
-    // `time::Duration::seconds()` method expects:
-    let seconds: i64 = Deserialize::deserialize(deserializer)?;
+    // Deserialize the field into an `i64`. This is what the
+    // `time::Duration::seconds()` method expects. Google occasionally returns
+    // the seconds as a float, which is rounded:
+    let seconds: i64 = rounded_i64(deserializer)?;
     // This handy-dandy method converts from the seconds count in `i64` format
     // into a `Duration` struct:
     Ok(Duration::seconds(seconds))
@@ -3,6 +3,7 @@
 // -----------------------------------------------------------------------------
 
 use crate::time_zone::response::status::Status;
+use crate::types::LatLng;
 use miette::Diagnostic;
 use thiserror::Error;
 
@@ -22,6 +23,11 @@ pub enum Error {
     /// status code. See `google_maps\src\time_zone\response\status.rs` for more
     /// information.
     InvalidStatusCode(String),
+    /// Google Maps Time Zone API server reported that the daily usage limit
+    /// has been exceeded, or that billing is not set up for the API key. This
+    /// error is never retried, as retrying will not help until the quota is
+    /// reset or the billing and quota settings are fixed.
+    OverDailyLimit(Option<String>),
     /// The query string must be built before the request may be sent to the
     /// Google Maps Time Zone API server.
     QueryNotBuilt,
@@ -34,6 +40,16 @@ pub enum Error {
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
     SerdeJson(serde_json::error::Error),
+    /// Google Maps Time Zone API server did not return a time zone for the
+    /// location passed to `GoogleMapsClient::current_offset`. This usually
+    /// means that the location is at sea.
+    TimeZoneNotFound(LatLng),
+    /// The HTTP request to the Google Maps Time Zone API server timed out. See
+    /// `GoogleMapsClient::with_timeout` and
+    /// `GoogleMapsClient::with_connect_timeout`. This error is eligible for
+    /// retries.
+    #[cfg(feature = "enable-reqwest")]
+    Timeout(reqwest::Error),
 } // enum
 
 impl std::fmt::Display for Error {
@@ -80,6 +96,15 @@ impl std::fmt::Display for Error {
                 Valid codes are `INVALID_REQUEST`, `OK`, `OVER_DAILY_LIMIT`, \
                 `OVER_QUERY_LIMIT`, `REQUEST_DENIED`, `UNKNOWN_ERROR`, and \
                 `ZERO_RESULTS`."),
+            Error::OverDailyLimit(error_message) => match error_message {
+                Some(error_message) => write!(f, "Google Maps Time Zone API service: \
+                    Over daily limit. {error_message} \
+                    Check the billing and quota settings for your API key in the Google Cloud Console."),
+                None => write!(f, "Google Maps Time Zone API service: \
+                    Over daily limit. \
+                    Usage cap has been exceeded, API key is invalid, billing has not been enabled, or method of payment is no longer valid. \
+                    Check the billing and quota settings for your API key in the Google Cloud Console."),
+            }, // match
             Error::QueryNotBuilt => write!(f, "Google Maps Time Zone API client library: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
                 Ensure the build() method is called before run()."),
@@ -88,6 +113,12 @@ impl std::fmt::Display for Error {
             #[cfg(feature = "enable-reqwest")]
             Error::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
             Error::SerdeJson(error) => write!(f, "Google Maps Time Zone API client in the Serde JSON library: {error}"),
+            Error::TimeZoneNotFound(location) => write!(f, "Google Maps Time Zone API client: \
+                No time zone was returned for the `{location}` location, so its UTC offset is not available. \
+                This may occur if the location is at sea."),
+            #[cfg(feature = "enable-reqwest")]
+            Error::Timeout(error) => write!(f, "Google Maps Time Zone API client: \
+                The request timed out: {error}"),
         } // match
     } // fn
 } // impl
@@ -115,4 +146,25 @@ impl From<serde_json::error::Error> for Error {
     fn from(error: serde_json::error::Error) -> Error {
         Error::SerdeJson(error)
     } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::client::ApiError for Error {
+    /// Wraps an unsuccessful HTTP status in a Google Maps Time Zone API
+    /// error type. This function is required by the shared `get` method.
+    fn http_unsuccessful(status: String) -> Error {
+        Error::HttpUnsuccessful(status)
+    } // fn
+    /// Wraps a HTTP client error message in a Google Maps Time Zone API
+    /// error type. This function is required by the shared `get` method.
+    fn reqwest_message(message: String) -> Error {
+        Error::ReqwestMessage(message)
+    } // fn
+    /// Wraps a HTTP client timeout in a Google Maps Time Zone API
+    /// error type. This function is required by the shared `get` method.
+    fn timeout(error: reqwest::Error) -> Error {
+        Error::Timeout(error)
+    } // fn
 } // impl
\ No newline at end of file
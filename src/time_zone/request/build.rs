@@ -1,3 +1,4 @@
+use crate::query_string::QueryString;
 use crate::time_zone::request::Request;
 
 impl<'a> Request<'a> {
@@ -14,24 +15,20 @@ impl<'a> Request<'a> {
         // This section builds the "required parameters" portion of the query
         // string:
 
-        let mut query = format!(
-            "key={}&location={}&timestamp={}",
-            self.client.key,
-            String::from(&self.location),
-            self.timestamp.timestamp(),
-        );
+        let mut query = QueryString::new(&self.client.key);
+        query.push_display("location", &self.location);
+        query.push_display("timestamp", &self.timestamp.timestamp());
 
         // This section builds the "optional parameters" portion of the query
         // string:
 
         // Language key/value pair:
         if let Some(language) = &self.language {
-            query.push_str("&language=");
-            query.push_str(&String::from(language))
+            query.push("language", language.into())
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
@@ -49,8 +49,9 @@ impl<'a> Request<'a> {
             location,
             timestamp,
             // Optional parameters:
-            language: None,
+            language: client.default_language.clone(),
             // Internal use only:
+            billing_tag: None,
             query: None,
         } // struct
 
@@ -86,8 +87,9 @@ impl<'a> Request<'a> {
             location: LatLng::try_from(coordinate)?,
             timestamp,
             // Optional parameters:
-            language: None,
+            language: client.default_language.clone(),
             // Internal use only:
+            billing_tag: None,
             query: None,
         }) // struct
 
@@ -123,8 +125,9 @@ impl<'a> Request<'a> {
             location: LatLng::try_from(point)?,
             timestamp,
             // Optional parameters:
-            language: None,
+            language: client.default_language.clone(),
             // Internal use only:
+            billing_tag: None,
             query: None,
         }) // struct
 
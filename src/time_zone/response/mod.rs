@@ -4,6 +4,7 @@
 
 pub mod status;
 
+use chrono::FixedOffset;
 use chrono_tz::Tz;
 use crate::time_zone::response::status::Status;
 use serde::{Deserialize, Serialize};
@@ -27,7 +28,7 @@ pub struct Response {
     /// the time zone is not in Daylight Savings Time during the specified
     /// `time`.
     #[serde(alias = "dstOffset")]
-    pub dst_offset: Option<i16>,
+    pub dst_offset: Option<i32>,
 
     /// More detailed information about the reasons behind the given status
     /// code, if other than `OK`.
@@ -40,7 +41,7 @@ pub struct Response {
     /// The offset from UTC (in seconds) for the given location. This does not
     /// take into effect daylight savings.
     #[serde(alias = "rawOffset")]
-    pub raw_offset: Option<i16>,
+    pub raw_offset: Option<i32>,
 
     /// The status of the response.
     pub status: Status,
@@ -70,4 +71,85 @@ impl std::str::FromStr for Response {
     fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
         serde_json::from_str(s)
     }
-}
\ No newline at end of file
+}
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the total offset from UTC for the requested location at the
+    /// requested time. This is the sum of the `raw_offset` and the
+    /// `dst_offset` fields, as a `chrono::FixedOffset` that may be used with
+    /// `DateTime::with_timezone`.
+    ///
+    /// Returns `None` if the status is not `OK`, if either offset is missing,
+    /// or if the sum is not a valid offset.
+    /// ```rust
+    /// let local_time = Utc::now().with_timezone(&time_zone.total_offset()?);
+    /// ```
+    pub fn total_offset(&self) -> Option<FixedOffset> {
+        if self.status != Status::Ok { return None } // if
+        let raw_offset = self.raw_offset?;
+        let dst_offset = self.dst_offset?;
+        FixedOffset::east_opt(raw_offset.checked_add(dst_offset)?)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Response {
+    /// Formats a concise, one-line summary of the Time Zone response for
+    /// logging: the status, the time zone, and the total offset from UTC. Use
+    /// `{:#?}` to see the entire response.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Time Zone {}", self.status)?;
+        if let Some(time_zone_id) = &self.time_zone_id {
+            write!(f, ": {}", time_zone_id.name())?;
+        } // if
+        if let Some(time_zone_name) = &self.time_zone_name {
+            write!(f, " ({time_zone_name})")?;
+        } // if
+        if let Some(raw_offset) = self.raw_offset {
+            let offset = raw_offset + self.dst_offset.unwrap_or_default();
+            let sign = if offset < 0 { '-' } else { '+' };
+            let minutes = offset.abs() / 60;
+            write!(f, ", UTC{sign}{:02}:{:02}", minutes / 60, minutes % 60)?;
+        } // if
+        Ok(())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn display_shows_the_total_offset() {
+        let response: Response = r#"{
+            "dstOffset": 3600,
+            "rawOffset": -28800,
+            "status": "OK",
+            "timeZoneId": "America/Los_Angeles",
+            "timeZoneName": "Pacific Daylight Time"
+        }"#.parse().unwrap();
+        assert_eq!(
+            response.to_string(),
+            "Time Zone OK: America/Los_Angeles (Pacific Daylight Time), UTC-07:00",
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn display_shows_partial_hour_offsets() {
+        let response: Response = r#"{
+            "dstOffset": 0,
+            "rawOffset": 19800,
+            "status": "OK",
+            "timeZoneId": "Asia/Calcutta",
+            "timeZoneName": "India Standard Time"
+        }"#.parse().unwrap();
+        assert!(response.to_string().ends_with("(India Standard Time), UTC+05:30"), "{response}");
+    } // fn
+
+} // mod
@@ -0,0 +1,88 @@
+//! Provides conversions between the `Bounds` type and CSV records, for bulk
+//! import and export of bounding boxes. These functions do not depend on any
+//! CSV library: they accept and return the fields of a single record.
+//!
+//! Records are in `southwest latitude,southwest longitude,northeast
+//! latitude,northeast longitude` column order. This is the `LatLng` column
+//! order, applied to the south-west corner and then the north-east corner.
+
+use crate::types::error::Error as TypeError;
+use crate::types::{Bounds, LatLng};
+
+// -----------------------------------------------------------------------------
+
+impl Bounds {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Gets a `Bounds` struct from the fields of a CSV record.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `record` ‧ Exactly four fields: the south-west corner's latitude and
+    /// longitude, then the north-east corner's latitude and longitude.
+    /// Surrounding whitespace is ignored.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let bounds = Bounds::from_csv_record(&["53.39", "-113.71", "53.71", "-113.27"])?;
+    /// ```
+
+    pub fn from_csv_record(record: &[&str]) -> Result<Bounds, TypeError> {
+        if record.len() != 4 {
+            return Err(TypeError::InvalidCsvRecord(record.join(",")));
+        } // if
+        Ok(Bounds {
+            southwest: LatLng::from_csv_record(&record[..2])?,
+            northeast: LatLng::from_csv_record(&record[2..])?,
+        }) // Bounds
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Converts a `Bounds` struct into the fields of a CSV record: the
+    /// south-west corner's latitude and longitude, then the north-east
+    /// corner's latitude and longitude.
+
+    pub fn to_csv_record(&self) -> [String; 4] {
+        let [southwest_lat, southwest_lng] = self.southwest.to_csv_record();
+        let [northeast_lat, northeast_lng] = self.northeast.to_csv_record();
+        [southwest_lat, southwest_lng, northeast_lat, northeast_lng]
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let record = ["53.39", "-113.71", "53.71", "-113.27"];
+        let bounds = Bounds::from_csv_record(&record).unwrap();
+        assert_eq!(bounds.southwest, LatLng::from_csv_record(&record[..2]).unwrap());
+        assert_eq!(bounds.northeast, LatLng::from_csv_record(&record[2..]).unwrap());
+        assert_eq!(bounds.to_csv_record(), record.map(String::from));
+    } // fn
+
+    #[test]
+    fn invalid_records() {
+        assert!(matches!(
+            Bounds::from_csv_record(&["53.39", "-113.71", "53.71"]),
+            Err(TypeError::InvalidCsvRecord(_)),
+        )); // assert!
+        assert!(matches!(
+            Bounds::from_csv_record(&["53.39", "-113.71", "53.71", "east"]),
+            Err(TypeError::InvalidCsvRecord(_)),
+        )); // assert!
+        assert!(matches!(
+            Bounds::from_csv_record(&["-91", "-113.71", "53.71", "-113.27"]),
+            Err(TypeError::InvalidLatitude(..)),
+        )); // assert!
+    } // fn
+
+} // mod
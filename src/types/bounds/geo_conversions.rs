@@ -2,7 +2,8 @@
 //! [geo](https://crates.io/crates/geo) crate.
 
 use crate::{Bounds, LatLng};
-use geo_types::geometry::{Coord, Polygon, Rect};
+use crate::types::error::Error as TypeError;
+use geo_types::geometry::{Coord, MultiPolygon, Polygon, Rect};
 
 // -----------------------------------------------------------------------------
 
@@ -18,8 +19,8 @@ impl TryFrom<&Rect> for Bounds {
         let min_coordinate: Coord = rect.min();
         let max_coordinate: Coord = rect.max();
 
-        let southwest: LatLng = LatLng::try_from_f64(max_coordinate.y, min_coordinate.x)?;
-        let northeast: LatLng = LatLng::try_from_f64(min_coordinate.y, max_coordinate.x)?;
+        let southwest: LatLng = LatLng::try_from_f64(min_coordinate.y, min_coordinate.x)?;
+        let northeast: LatLng = LatLng::try_from_f64(max_coordinate.y, max_coordinate.x)?;
 
         Ok(Bounds { southwest, northeast })
 
@@ -36,12 +37,20 @@ impl TryFrom<&Bounds> for Rect {
 
     /// Attempts to convert a `google_maps::Bounds` struct to a
     /// `geo_types::geometry::Rect` struct.
+    ///
+    /// A `Rect` cannot represent a bounding box that crosses the antimeridian
+    /// (±180° longitude) without spanning the rest of the globe instead, so
+    /// an `AntimeridianBounds` error is returned for such a bounding box.
+    /// Convert it to a `MultiPolygon` instead.
     fn try_from(bounds: &Bounds) -> Result<Self, Self::Error> {
 
-        let c1: Coord = Coord::try_from(&bounds.southwest)?;
-        let c2: Coord = Coord::try_from(&bounds.northeast)?;
+        if bounds.southwest.lng > bounds.northeast.lng {
+            Err(TypeError::AntimeridianBounds(bounds.to_string()))?
+        } // if
 
-        Ok(Rect::new(c1, c2))
+        let coordinate = |lat_lng: &LatLng| Coord { x: lat_lng.lng_f64(), y: lat_lng.lat_f64() };
+
+        Ok(Rect::new(coordinate(&bounds.southwest), coordinate(&bounds.northeast)))
 
     } // fn
 
@@ -49,17 +58,154 @@ impl TryFrom<&Bounds> for Rect {
 
 // -----------------------------------------------------------------------------
 
+impl TryFrom<Bounds> for Rect {
+
+    // Error definitions are contained in the `google_maps\src\error.rs` module.
+    type Error = crate::error::Error;
+
+    /// Attempts to convert a `google_maps::Bounds` struct to a
+    /// `geo_types::geometry::Rect` struct. See the `TryFrom<&Bounds>`
+    /// implementation for details.
+    fn try_from(bounds: Bounds) -> Result<Self, Self::Error> {
+        Rect::try_from(&bounds)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl TryFrom<&Bounds> for Polygon {
 
     // Error definitions are contained in the `google_maps\src\error.rs` module.
     type Error = crate::error::Error;
 
+    /// Attempts to convert a `google_maps::Bounds` struct, such as a geocoding
+    /// result's `viewport`, to a `geo_types::geometry::Polygon` struct. The
+    /// polygon's exterior ring is the rectangle's four corners, closed by
+    /// repeating the first corner.
+    ///
+    /// A bounding box whose south-west longitude is greater than its
+    /// north-east longitude crosses the antimeridian (±180° longitude). A
+    /// single polygon cannot represent it without spanning the rest of the
+    /// globe instead, so an `AntimeridianBounds` error is returned. Convert
+    /// such a bounding box to a `MultiPolygon` instead.
+    fn try_from(bounds: &Bounds) -> Result<Self, Self::Error> {
+        Ok(Rect::try_from(bounds)?.to_polygon())
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl TryFrom<&Bounds> for MultiPolygon {
+
+    // Error definitions are contained in the `google_maps\src\error.rs` module.
+    type Error = crate::error::Error;
+
     /// Attempts to convert a `google_maps::Bounds` struct to a
-    /// `geo_types::geometry::Polygon` struct.
+    /// `geo_types::geometry::MultiPolygon` struct.
+    ///
+    /// A bounding box that crosses the antimeridian (±180° longitude) is split
+    /// into two polygons: one running east from the south-west corner to
+    /// 180°, and one running east from -180° to the north-east corner. Any
+    /// other bounding box becomes a single polygon.
     fn try_from(bounds: &Bounds) -> Result<Self, Self::Error> {
 
-        Ok(Rect::try_from(bounds)?.to_polygon())
+        if bounds.southwest.lng <= bounds.northeast.lng {
+            return Ok(MultiPolygon::new(vec![Polygon::try_from(bounds)?]));
+        } // if
+
+        let southwest: Coord = Coord::try_from(&bounds.southwest)?;
+        let northeast: Coord = Coord::try_from(&bounds.northeast)?;
+
+        let west = Rect::new(southwest, Coord { x: 180.0, y: northeast.y });
+        let east = Rect::new(Coord { x: -180.0, y: southwest.y }, northeast);
+
+        Ok(MultiPolygon::new(vec![west.to_polygon(), east.to_polygon()]))
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn bounds(south: f64, west: f64, north: f64, east: f64) -> Bounds {
+        Bounds {
+            southwest: LatLng::try_from_f64(south, west).unwrap(),
+            northeast: LatLng::try_from_f64(north, east).unwrap(),
+        } // Bounds
+    } // fn
+
+    /// Returns the corners of a polygon's exterior ring as `(x, y)` pairs.
+    fn ring(polygon: &Polygon) -> Vec<(f64, f64)> {
+        polygon.exterior().coords().map(|coord| (coord.x, coord.y)).collect()
+    } // fn
+
+    #[test]
+    fn viewport_to_closed_polygon() {
+        let polygon = Polygon::try_from(&bounds(43.5, -79.5, 43.75, -79.25)).unwrap();
+        let ring = ring(&polygon);
+        assert_eq!(ring.len(), 5);
+        assert_eq!(ring.first(), ring.last());
+        for corner in [(-79.5, 43.5), (-79.25, 43.5), (-79.25, 43.75), (-79.5, 43.75)] {
+            assert!(ring.contains(&corner), "{corner:?} is missing from {ring:?}");
+        } // for
+        assert!(polygon.interiors().is_empty());
+    } // fn
+
+    #[test]
+    fn antimeridian_viewport_to_rect_or_polygon_fails() {
+        let fiji = bounds(-21.0, 177.0, -12.0, -178.0);
+        assert!(matches!(
+            Rect::try_from(&fiji),
+            Err(crate::error::Error::Type(TypeError::AntimeridianBounds(_))),
+        ));
+        assert!(Rect::try_from(fiji.clone()).is_err());
+        assert!(Polygon::try_from(&fiji).is_err());
+    } // fn
+
+    #[test]
+    fn antimeridian_viewport_to_split_multi_polygon() {
+        let fiji = bounds(-21.0, 177.0, -12.0, -178.0);
+        let multi_polygon = MultiPolygon::try_from(&fiji).unwrap();
+        assert_eq!(multi_polygon.0.len(), 2);
+        let west = ring(&multi_polygon.0[0]);
+        let east = ring(&multi_polygon.0[1]);
+        for (ring, corners) in [
+            (&west, [(177.0, -21.0), (180.0, -21.0), (180.0, -12.0), (177.0, -12.0)]),
+            (&east, [(-180.0, -21.0), (-178.0, -21.0), (-178.0, -12.0), (-180.0, -12.0)]),
+        ] {
+            assert_eq!(ring.len(), 5);
+            assert_eq!(ring.first(), ring.last());
+            for corner in corners {
+                assert!(ring.contains(&corner), "{corner:?} is missing from {ring:?}");
+            } // for
+        } // for
+    } // fn
+
+    #[test]
+    fn viewport_to_single_multi_polygon() {
+        let multi_polygon = MultiPolygon::try_from(&bounds(43.5, -79.5, 43.75, -79.25)).unwrap();
+        assert_eq!(multi_polygon.0.len(), 1);
+        assert_eq!(ring(&multi_polygon.0[0]).len(), 5);
+    } // fn
 
+    #[test]
+    fn rect_round_trips() {
+        let viewport = bounds(43.5, -79.5, 43.75, -79.25);
+        let rect = Rect::try_from(&viewport).unwrap();
+        assert_eq!(rect.min(), Coord { x: -79.5, y: 43.5 });
+        assert_eq!(rect.max(), Coord { x: -79.25, y: 43.75 });
+        let round_tripped = Bounds::try_from(&rect).unwrap();
+        assert_eq!(round_tripped.southwest.lat, dec!(43.5));
+        assert_eq!(round_tripped.northeast.lng, dec!(-79.25));
     } // fn
 
-} // impl
\ No newline at end of file
+} // mod
@@ -2,6 +2,8 @@
 //! specify a selection or bounding box over a geographic area using two
 //! latitude & longitude pairs.
 
+#[cfg(feature = "csv")]
+mod csv;
 #[cfg(feature = "geo")]
 mod geo_conversions;
 
@@ -10,6 +12,8 @@ mod geo_conversions;
 use crate::types::error::Error as GoogleMapsError;
 use crate::types::error::Error as TypeError;
 use crate::types::latlng::LatLng;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
@@ -74,4 +78,269 @@ impl std::str::FromStr for Bounds {
             Ok(Bounds { southwest, northeast })
         } // if
     } // fn
-} // impl
\ No newline at end of file
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Bounds {
+    /// Returns whether the specified point falls inside of the bounding box.
+    /// Points on the edges of the bounding box are considered to be inside.
+    ///
+    /// A bounding box whose south-west longitude is greater than its
+    /// north-east longitude is taken to cross the antimeridian (±180°
+    /// longitude).
+    /// ```rust
+    /// let is_inside = bounds.contains(&geocoding.geometry.location);
+    /// ```
+    pub fn contains(&self, point: &LatLng) -> bool {
+        let contains_lat =
+            point.lat >= self.southwest.lat && point.lat <= self.northeast.lat;
+        let contains_lng = if self.southwest.lng <= self.northeast.lng {
+            point.lng >= self.southwest.lng && point.lng <= self.northeast.lng
+        } else {
+            // The bounding box crosses the antimeridian:
+            point.lng >= self.southwest.lng || point.lng <= self.northeast.lng
+        }; // if
+        contains_lat && contains_lng
+    } // fn
+
+    /// Returns the point at the middle of the bounding box. This correctly
+    /// handles a bounding box that crosses the antimeridian (±180° longitude).
+    /// ```rust
+    /// let center = bounds.center();
+    /// ```
+    pub fn center(&self) -> LatLng {
+        let lat = (self.southwest.lat + self.northeast.lat) / dec!(2);
+        let lng = if self.southwest.lng <= self.northeast.lng {
+            (self.southwest.lng + self.northeast.lng) / dec!(2)
+        } else {
+            // The bounding box crosses the antimeridian. Measure the width
+            // eastward from the south-west corner, then wrap the result back
+            // into the -180° to 180° range:
+            let lng = (self.southwest.lng + self.northeast.lng + dec!(360)) / dec!(2);
+            if lng > dec!(180) { lng - dec!(360) } else { lng }
+        }; // if
+        LatLng { lat, lng }
+    } // fn
+
+    /// Returns the bounding box covered by both this bounding box and another
+    /// bounding box, or `None` if they do not overlap. Boxes that only touch
+    /// along an edge are considered to overlap.
+    ///
+    /// A bounding box whose south-west longitude is greater than its
+    /// north-east longitude is taken to cross the antimeridian (±180°
+    /// longitude), and the result may cross it too. Two very wide boxes can
+    /// overlap in two separate areas, for example on either side of the
+    /// globe. A single `Bounds` cannot describe that, so the smallest box
+    /// covering both areas is returned instead. This errs on the side of
+    /// including too much rather than too little.
+    /// ```rust
+    /// let overlap = viewport.intersection(&search_area);
+    /// ```
+    pub fn intersection(&self, other: &Bounds) -> Option<Bounds> {
+        let south = self.southwest.lat.max(other.southwest.lat);
+        let north = self.northeast.lat.min(other.northeast.lat);
+        if south > north {
+            return None;
+        } // if
+
+        // Compare the longitude ranges with the other box shifted a full turn
+        // east and west, so that ranges meeting across the antimeridian are
+        // found:
+        let (west, east) = self.unwrapped_lngs();
+        let overlaps: Vec<(Decimal, Decimal)> = other
+            .shifted_lngs()
+            .into_iter()
+            .map(|(other_west, other_east)| (west.max(other_west), east.min(other_east)))
+            .filter(|(west, east)| west <= east)
+            .collect();
+
+        let west = overlaps.iter().map(|(west, _east)| *west).min()?;
+        let east = overlaps.iter().map(|(_west, east)| *east).max()?;
+
+        Some(Bounds::from_unwrapped(south, west, north, east))
+    } // fn
+
+    /// Returns the smallest bounding box that covers both this bounding box
+    /// and another bounding box.
+    ///
+    /// A bounding box whose south-west longitude is greater than its
+    /// north-east longitude is taken to cross the antimeridian (±180°
+    /// longitude). The union crosses the antimeridian whenever that gives a
+    /// narrower box. If the two boxes together span every longitude, the union
+    /// runs from -180° to 180°.
+    /// ```rust
+    /// let combined_viewport = first.union(&second);
+    /// ```
+    pub fn union(&self, other: &Bounds) -> Bounds {
+        let south = self.southwest.lat.min(other.southwest.lat);
+        let north = self.northeast.lat.max(other.northeast.lat);
+
+        // Compare the longitude ranges with the other box shifted a full turn
+        // east and west, and keep whichever covers both boxes most narrowly:
+        let (west, east) = self.unwrapped_lngs();
+        let (west, east) = other
+            .shifted_lngs()
+            .into_iter()
+            .map(|(other_west, other_east)| (west.min(other_west), east.max(other_east)))
+            .min_by_key(|(west, east)| *east - *west)
+            .unwrap_or((west, east));
+
+        Bounds::from_unwrapped(south, west, north, east)
+    } // fn
+
+    /// Returns the western and eastern longitudes of the bounding box such
+    /// that the eastern longitude is never less than the western longitude.
+    /// For a box that crosses the antimeridian, the eastern longitude is
+    /// greater than 180°.
+    fn unwrapped_lngs(&self) -> (Decimal, Decimal) {
+        let west = self.southwest.lng;
+        let east = self.northeast.lng;
+        if west <= east { (west, east) } else { (west, east + dec!(360)) }
+    } // fn
+
+    /// Returns the unwrapped longitude range of the bounding box, and the
+    /// same range shifted one full turn west and one full turn east.
+    fn shifted_lngs(&self) -> [(Decimal, Decimal); 3] {
+        let (west, east) = self.unwrapped_lngs();
+        [
+            (west - dec!(360), east - dec!(360)),
+            (west, east),
+            (west + dec!(360), east + dec!(360)),
+        ]
+    } // fn
+
+    /// Builds a bounding box from an unwrapped longitude range, wrapping the
+    /// longitudes back into the -180° to 180° range.
+    fn from_unwrapped(south: Decimal, west: Decimal, north: Decimal, east: Decimal) -> Bounds {
+        let (west, east) = if east - west >= dec!(360) {
+            (dec!(-180), dec!(180))
+        } else {
+            (wrap_lng(west), wrap_lng(east))
+        }; // if
+        Bounds {
+            southwest: LatLng { lat: south, lng: west },
+            northeast: LatLng { lat: north, lng: east },
+        } // Bounds
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Wraps a longitude into the -180° to 180° range.
+fn wrap_lng(lng: Decimal) -> Decimal {
+    let mut lng = lng;
+    while lng > dec!(180) { lng -= dec!(360); }
+    while lng < dec!(-180) { lng += dec!(360); }
+    lng
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn lat_lng(lat: Decimal, lng: Decimal) -> LatLng {
+        LatLng::try_from_dec(lat, lng).unwrap()
+    } // fn
+
+    fn bounds(south: Decimal, west: Decimal, north: Decimal, east: Decimal) -> Bounds {
+        Bounds { southwest: lat_lng(south, west), northeast: lat_lng(north, east) }
+    } // fn
+
+    #[test]
+    fn normal_box() {
+        let toronto = bounds(dec!(43.58), dec!(-79.64), dec!(43.86), dec!(-79.12));
+        // Inside, on an edge, and on a corner:
+        assert!(toronto.contains(&lat_lng(dec!(43.65), dec!(-79.38))));
+        assert!(toronto.contains(&lat_lng(dec!(43.58), dec!(-79.38))));
+        assert!(toronto.contains(&lat_lng(dec!(43.86), dec!(-79.12))));
+        // Outside to the north, and to the east:
+        assert!(!toronto.contains(&lat_lng(dec!(44.00), dec!(-79.38))));
+        assert!(!toronto.contains(&lat_lng(dec!(43.65), dec!(-79.00))));
+        assert_eq!(toronto.center(), lat_lng(dec!(43.72), dec!(-79.38)));
+    } // fn
+
+    #[test]
+    fn antimeridian_crossing_box() {
+        // Fiji straddles ±180° longitude:
+        let fiji = bounds(dec!(-21), dec!(177), dec!(-12), dec!(-178));
+        // Inside, on either side of the antimeridian:
+        assert!(fiji.contains(&lat_lng(dec!(-17), dec!(178))));
+        assert!(fiji.contains(&lat_lng(dec!(-17), dec!(-179))));
+        assert!(fiji.contains(&lat_lng(dec!(-17), dec!(180))));
+        // Outside, between the box's east and west edges:
+        assert!(!fiji.contains(&lat_lng(dec!(-17), dec!(0))));
+        assert!(!fiji.contains(&lat_lng(dec!(-17), dec!(176))));
+        // The box is 5° wide, so its center is 2.5° east of 177°, which is
+        // 179.5°. A naive average would return -0.5°, on the other side of
+        // the globe:
+        assert_eq!(fiji.center(), lat_lng(dec!(-16.5), dec!(179.5)));
+        // A center past 180° wraps around to the western hemisphere:
+        let wide = bounds(dec!(0), dec!(170), dec!(10), dec!(-160));
+        assert_eq!(wide.center(), lat_lng(dec!(5), dec!(-175)));
+    } // fn
+
+    #[test]
+    fn degenerate_box() {
+        let point = bounds(dec!(51.5), dec!(-0.12), dec!(51.5), dec!(-0.12));
+        assert!(point.contains(&lat_lng(dec!(51.5), dec!(-0.12))));
+        assert!(!point.contains(&lat_lng(dec!(51.5), dec!(-0.13))));
+        assert!(!point.contains(&lat_lng(dec!(51.6), dec!(-0.12))));
+        assert_eq!(point.center(), lat_lng(dec!(51.5), dec!(-0.12)));
+    } // fn
+
+    #[test]
+    fn overlapping_boxes() {
+        let first = bounds(dec!(0), dec!(0), dec!(10), dec!(10));
+        let second = bounds(dec!(5), dec!(5), dec!(15), dec!(15));
+        assert_eq!(first.intersection(&second), Some(bounds(dec!(5), dec!(5), dec!(10), dec!(10))));
+        assert_eq!(first.union(&second), bounds(dec!(0), dec!(0), dec!(15), dec!(15)));
+        // Boxes that only touch along an edge overlap along that edge:
+        let above = bounds(dec!(10), dec!(0), dec!(20), dec!(10));
+        assert_eq!(first.intersection(&above), Some(bounds(dec!(10), dec!(0), dec!(10), dec!(10))));
+    } // fn
+
+    #[test]
+    fn disjoint_boxes() {
+        let first = bounds(dec!(0), dec!(0), dec!(10), dec!(10));
+        // Apart in latitude, and apart in longitude only:
+        let north_east = bounds(dec!(20), dec!(20), dec!(30), dec!(30));
+        let east = bounds(dec!(0), dec!(20), dec!(10), dec!(30));
+        assert_eq!(first.intersection(&north_east), None);
+        assert_eq!(first.intersection(&east), None);
+        assert_eq!(first.union(&north_east), bounds(dec!(0), dec!(0), dec!(30), dec!(30)));
+    } // fn
+
+    #[test]
+    fn contained_box() {
+        let outer = bounds(dec!(0), dec!(0), dec!(10), dec!(10));
+        let inner = bounds(dec!(2), dec!(2), dec!(4), dec!(4));
+        assert_eq!(outer.intersection(&inner), Some(inner.clone()));
+        assert_eq!(inner.intersection(&outer), Some(inner.clone()));
+        assert_eq!(outer.union(&inner), outer);
+    } // fn
+
+    #[test]
+    fn antimeridian_intersection_and_union() {
+        let fiji = bounds(dec!(-21), dec!(177), dec!(-12), dec!(-178));
+        // Another box that crosses the antimeridian:
+        let crossing = bounds(dec!(-20), dec!(179), dec!(-10), dec!(-170));
+        assert_eq!(fiji.intersection(&crossing), Some(bounds(dec!(-20), dec!(179), dec!(-12), dec!(-178))));
+        assert_eq!(fiji.union(&crossing), bounds(dec!(-21), dec!(177), dec!(-10), dec!(-170)));
+        // A box just east of the antimeridian:
+        let east = bounds(dec!(-20), dec!(-179.5), dec!(-15), dec!(-170));
+        assert_eq!(fiji.intersection(&east), Some(bounds(dec!(-20), dec!(-179.5), dec!(-15), dec!(-178))));
+        // A box on the other side of the globe:
+        let africa = bounds(dec!(-20), dec!(10), dec!(-15), dec!(20));
+        assert_eq!(fiji.intersection(&africa), None);
+        // The narrowest union of boxes either side of the antimeridian crosses
+        // it:
+        let west = bounds(dec!(0), dec!(170), dec!(10), dec!(175));
+        let east = bounds(dec!(0), dec!(-175), dec!(10), dec!(-170));
+        assert_eq!(west.union(&east), bounds(dec!(0), dec!(170), dec!(10), dec!(-170)));
+    } // fn
+
+} // mod
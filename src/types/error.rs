@@ -39,10 +39,22 @@ pub enum Error {
     /// API client library attempted to convert a bounds string that is invalid.
     InvalidBoundsString(String),
 
+    /// API client library attempted to convert a bounding box that crosses the
+    /// antimeridian into a single polygon.
+    AntimeridianBounds(String),
+
+    /// API client library attempted to convert a CSV record that did not
+    /// contain the expected number of valid coordinates.
+    InvalidCsvRecord(String),
+
     /// API client library attempted to parse a string that contained an invalid
     /// country code.
     InvalidCountryCode(String),
 
+    /// API client library attempted to parse a string that contained an invalid
+    /// place ID.
+    InvalidPlaceId(String),
+
     /// API client library attempted to parse a string that contained an invalid
     /// place type code.
     InvalidPlaceTypeCode(String),
@@ -97,12 +109,34 @@ impl std::fmt::Display for Error {
                 "Google Maps Platform API client: \
                 `{value}` is an invalid `Bounds` string."
             ),
+            Error::AntimeridianBounds(bounds) => write!(
+                f,
+                "Google Maps Platform API client: \
+                the `{bounds}` bounding box crosses the antimeridian (±180° longitude), \
+                so it cannot be represented by a single polygon. \
+                Convert it to a `MultiPolygon` instead."
+            ),
+            Error::InvalidCsvRecord(record) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{record}` is an invalid CSV record. \
+                A `LatLng` record must contain a latitude and a longitude, \
+                and a `Bounds` record must contain the south-west corner's \
+                latitude and longitude followed by the north-east corner's."
+            ),
             Error::InvalidCountryCode(country_code) => write!(f,
                 "Google Maps Geocoding API client: \
                 `{country_code}` is not a valid ISO 3166-1 Alpha-2 country code. \
                 Note that the country code must be in uppercase. \
                 For a list of country codes see \
                 https://en.wikipedia.org/wiki/List_of_ISO_3166_country_codes"),
+            Error::InvalidPlaceId(place_id) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{place_id}` is not a valid place ID. \
+                A place ID may only contain letters, digits, hyphens and \
+                underscores."
+            ),
             Error::InvalidPlaceTypeCode(place_type_code) => write!(
                 f,
                 "Google Maps Platform API client: \
@@ -0,0 +1,100 @@
+//! Typed [GeoJSON](https://geojson.org/) objects that geocoding and place
+//! search results are converted into. Serializing them with `serde` produces
+//! standard GeoJSON, ready for map front ends such as Leaflet.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+// -----------------------------------------------------------------------------
+//
+/// A GeoJSON `Point` geometry. Note that GeoJSON orders a position's
+/// coordinates as longitude, then latitude.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "Point")]
+pub struct Point {
+    /// The point's position, as `[longitude, latitude]`.
+    pub coordinates: [f64; 2],
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// A GeoJSON `Feature`: a geometry and a set of properties that describe it.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "Feature")]
+pub struct Feature {
+    /// The feature's location, or `None` if it has no location. A feature
+    /// without a location is serialized with a `null` geometry.
+    pub geometry: Option<Point>,
+    /// The feature's properties, such as its formatted address and place ID.
+    pub properties: Map<String, Value>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// A GeoJSON `FeatureCollection`. It may be collected from an iterator of
+/// `Feature`s.
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "FeatureCollection")]
+pub struct FeatureCollection {
+    /// The features in the collection.
+    pub features: Vec<Feature>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::iter::FromIterator<Feature> for FeatureCollection {
+    /// Collects features into a `FeatureCollection`, in order.
+    fn from_iter<I: IntoIterator<Item = Feature>>(features: I) -> Self {
+        FeatureCollection { features: features.into_iter().collect() }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn serializes_to_geojson() {
+        let feature_collection: FeatureCollection = vec![
+            Feature {
+                geometry: Some(Point { coordinates: [-79.3832, 43.6532] }),
+                properties: json!({ "name": "Toronto" }).as_object().unwrap().clone(),
+            }, // Feature
+            Feature { geometry: None, properties: Map::new() },
+        ].into_iter().collect();
+
+        assert_eq!(
+            serde_json::to_value(&feature_collection).unwrap(),
+            json!({
+                "type": "FeatureCollection",
+                "features": [
+                    {
+                        "type": "Feature",
+                        "geometry": { "type": "Point", "coordinates": [-79.3832, 43.6532] },
+                        "properties": { "name": "Toronto" },
+                    },
+                    { "type": "Feature", "geometry": null, "properties": {} },
+                ],
+            }), // json!
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn deserializes_from_geojson() {
+        let json = json!({
+            "type": "Feature",
+            "geometry": { "type": "Point", "coordinates": [2.3522, 48.8566] },
+            "properties": {},
+        }); // json!
+        let feature: Feature = serde_json::from_value(json).unwrap();
+        assert_eq!(feature.geometry, Some(Point { coordinates: [2.3522, 48.8566] }));
+    } // fn
+
+} // mod
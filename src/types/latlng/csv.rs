@@ -0,0 +1,103 @@
+//! Provides conversions between the `LatLng` type and CSV records, for bulk
+//! import and export of coordinates. These functions do not depend on any CSV
+//! library: they accept and return the fields of a single record, as produced
+//! or consumed by the `csv` crate's `StringRecord` or any other CSV reader.
+//!
+//! Records are in `latitude,longitude` column order.
+
+use crate::types::error::Error as TypeError;
+use crate::types::LatLng;
+use crate::types::latlng::latlng_from_dec;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Gets a `LatLng` struct from the fields of a CSV record.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `record` ‧ Exactly two fields: the latitude, then the longitude.
+    /// Surrounding whitespace is ignored.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let latlng = LatLng::from_csv_record(&["53.5461", "-113.4938"])?;
+    /// ```
+
+    pub fn from_csv_record(record: &[&str]) -> Result<LatLng, TypeError> {
+
+        let [lat, lng] = record else {
+            return Err(TypeError::InvalidCsvRecord(record.join(",")));
+        }; // let
+
+        let lat = Decimal::from_str(lat.trim())
+            .map_err(|_| TypeError::InvalidCsvRecord(record.join(",")))?;
+        let lng = Decimal::from_str(lng.trim())
+            .map_err(|_| TypeError::InvalidCsvRecord(record.join(",")))?;
+
+        latlng_from_dec(lat, lng)
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Converts a `LatLng` struct into the fields of a CSV record: the
+    /// latitude, then the longitude.
+
+    pub fn to_csv_record(&self) -> [String; 2] {
+        [self.lat.normalize().to_string(), self.lng.normalize().to_string()]
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for record in [["53.5461", "-113.4938"], ["-33.8688", "151.2093"], ["0", "0"], ["90", "-180"]] {
+            let latlng = LatLng::from_csv_record(&record).unwrap();
+            assert_eq!(latlng.to_csv_record(), record.map(String::from));
+        } // for
+    } // fn
+
+    #[test]
+    fn whitespace_and_trailing_zeros() {
+        let latlng = LatLng::from_csv_record(&[" 53.54610 ", "\t-113.4938"]).unwrap();
+        assert_eq!(latlng.to_csv_record(), ["53.5461", "-113.4938"]);
+    } // fn
+
+    #[test]
+    fn invalid_records() {
+        // Too few fields, too many fields, no fields, and unparsable fields:
+        let records: [&[&str]; 5] = [
+            &["53.5461"],
+            &["53.5461", "-113.4938", "0"],
+            &[],
+            &["north", "-113.4938"],
+            &["53.5461", ""],
+        ]; // records
+        for record in records {
+            assert!(
+                matches!(
+                    LatLng::from_csv_record(record),
+                    Err(TypeError::InvalidCsvRecord(ref fields)) if *fields == record.join(","),
+                ),
+                "{record:?} was accepted",
+            ); // assert!
+        } // for
+        assert!(matches!(LatLng::from_csv_record(&["91", "0"]), Err(TypeError::InvalidLatitude(..))));
+        assert!(matches!(LatLng::from_csv_record(&["0", "181"]), Err(TypeError::InvalidLongitude(..))));
+    } // fn
+
+} // mod
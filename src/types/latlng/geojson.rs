@@ -0,0 +1,17 @@
+//! Provides a [GeoJSON](https://geojson.org/) conversion for the `LatLng`
+//! type.
+
+use crate::types::{geojson::Point, LatLng};
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Converts a `LatLng` struct into a GeoJSON `Point` geometry. Note that
+    /// GeoJSON orders coordinates as longitude, then latitude.
+    /// ```rust
+    /// let point = lat_lng.to_geojson_point();
+    /// ```
+    pub fn to_geojson_point(&self) -> Point {
+        Point { coordinates: [self.lng_f64(), self.lat_f64()] }
+    } // fn
+} // impl
@@ -0,0 +1,94 @@
+//! Provides a great-circle distance calculation for the `LatLng` type using
+//! the [haversine formula](https://en.wikipedia.org/wiki/Haversine_formula).
+
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+
+/// The mean radius of the Earth in meters, as defined by the International
+/// Union of Geodesy and Geophysics (IUGG).
+const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Returns the great-circle distance, in meters, between this point and
+    /// another point. The calculation uses the haversine formula and treats the
+    /// Earth as a sphere, so the result may differ from road distances and
+    /// from ellipsoidal distances by up to about 0.5%.
+    ///
+    /// This is useful for a quick "how far apart are these two points" check
+    /// without making a request to the _Distance Matrix API_.
+    /// ```rust
+    /// let meters = toronto.haversine_distance_meters(&montreal);
+    /// ```
+    pub fn haversine_distance_meters(&self, other: &LatLng) -> f64 {
+        let lat_1 = self.lat_f64().to_radians();
+        let lng_1 = self.lng_f64().to_radians();
+        let lat_2 = other.lat_f64().to_radians();
+        let lng_2 = other.lng_f64().to_radians();
+
+        let half_delta_lat = (lat_2 - lat_1) / 2.0;
+        let half_delta_lng = (lng_2 - lng_1) / 2.0;
+
+        let a = half_delta_lat.sin().powi(2)
+            + lat_1.cos() * lat_2.cos() * half_delta_lng.sin().powi(2);
+
+        2.0 * EARTH_RADIUS_METERS * a.sqrt().min(1.0).asin()
+    } // fn
+
+    /// Returns `true` if this point and another point are within `tolerance_m`
+    /// meters of each other, as measured by `haversine_distance_meters`.
+    ///
+    /// The precision of coordinates returned by Google varies, so exact
+    /// `Decimal` equality is often too strict when checking whether two
+    /// coordinates refer to the "same place." This is useful for de-duplication
+    /// and for assertions in tests.
+    /// ```rust
+    /// let same_place = pickup.approx_eq(&dropoff, 25.0);
+    /// ```
+    pub fn approx_eq(&self, other: &LatLng, tolerance_m: f64) -> bool {
+        self.haversine_distance_meters(other) <= tolerance_m
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn latlng(lat: f64, lng: f64) -> LatLng {
+        LatLng::try_from_f64(lat, lng).unwrap()
+    } // fn
+
+    #[test]
+    fn haversine_distance_between_cities() {
+        let toronto = latlng(43.6532, -79.3832);
+        let montreal = latlng(45.5017, -73.5673);
+        let meters = toronto.haversine_distance_meters(&montreal);
+        assert!((meters - 504_000.0).abs() < 2_000.0, "{meters}");
+        assert_eq!(meters, montreal.haversine_distance_meters(&toronto));
+    } // fn
+
+    #[test]
+    fn approx_eq_within_tolerance() {
+        // 0.0001° of latitude is about 11 meters:
+        let pickup = latlng(43.6532, -79.3832);
+        let dropoff = latlng(43.6533, -79.3832);
+        assert!(pickup.approx_eq(&dropoff, 25.0));
+        assert!(pickup.approx_eq(&pickup, 0.0));
+        // Points either side of the antimeridian are close together:
+        assert!(latlng(0.0, 179.9999).approx_eq(&latlng(0.0, -179.9999), 25.0));
+    } // fn
+
+    #[test]
+    fn approx_eq_beyond_tolerance() {
+        let pickup = latlng(43.6532, -79.3832);
+        let dropoff = latlng(43.6533, -79.3832);
+        assert!(!pickup.approx_eq(&dropoff, 5.0));
+        assert!(!latlng(43.6532, -79.3832).approx_eq(&latlng(45.5017, -73.5673), 1_000.0));
+    } // fn
+
+} // mod
@@ -2,15 +2,20 @@
 //! latitude & longitude coorindate system is used to specify a position or
 //! location on the Earth's surface.
 
+#[cfg(feature = "csv")]
+mod csv;
 #[cfg(feature = "geo")]
 mod geo;
+#[cfg(feature = "geojson")]
+mod geojson;
+mod haversine;
 
 // -----------------------------------------------------------------------------
 
 use crate::error::Error as GoogleMapsError;
 use crate::types::error::Error as TypeError;
 use rust_decimal::Decimal;
-use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::cmp::{Ord, Ordering};
@@ -51,19 +56,31 @@ impl LatLng {
         latitude: Decimal,
         longitude: Decimal,
     ) -> Result<LatLng, GoogleMapsError> {
+        Ok(latlng_from_dec(latitude, longitude)?)
+    } // fn
+} // impl
 
-        if latitude < dec!(-90.0) || latitude > dec!(90.0) {
-            Err(TypeError::InvalidLatitude(latitude, longitude))?
-        } // if
+// -----------------------------------------------------------------------------
 
-        if longitude < dec!(-180.0) || longitude > dec!(180.0) {
-            Err(TypeError::InvalidLongitude(latitude, longitude))?
-        } // if
+/// Validates a latitude & longitude pair. This is `LatLng::try_from_dec`,
+/// returning the type error rather than the crate error.
 
-        Ok(LatLng { lat: latitude, lng: longitude })
+pub(in crate::types) fn latlng_from_dec(
+    latitude: Decimal,
+    longitude: Decimal,
+) -> Result<LatLng, TypeError> {
 
-    } // fn
-} // impl
+    if latitude < dec!(-90.0) || latitude > dec!(90.0) {
+        return Err(TypeError::InvalidLatitude(latitude, longitude));
+    } // if
+
+    if longitude < dec!(-180.0) || longitude > dec!(180.0) {
+        return Err(TypeError::InvalidLongitude(latitude, longitude));
+    } // if
+
+    Ok(LatLng { lat: latitude, lng: longitude })
+
+} // fn
 
 // -----------------------------------------------------------------------------
 
@@ -137,24 +154,26 @@ impl LatLng {
 
 impl std::str::FromStr for LatLng {
 
-    // Error definitions are contained in the
-    // `google_maps\src\geocoding\error.rs` module.
-    type Err = GoogleMapsError;
+    // Error definitions are contained in the `google_maps\src\types\error.rs`
+    // module.
+    type Err = TypeError;
 
     /// Attempts to get a `LatLng` struct from a borrowed `&str` that contains a
-    /// comma-delimited latitude & longitude pair.
+    /// comma-delimited latitude & longitude pair, such as
+    /// `51.503364,-0.127625`. This is the format produced by `Display`, so a
+    /// `LatLng` may be written to and read back from a string.
     fn from_str(str: &str) -> Result<Self, Self::Err> {
 
         let coordinates: Vec<&str> = str.trim().split(',').collect();
 
         if coordinates.len() != 2 {
-            Err(TypeError::InvalidLatLongString(str.to_owned()))?
+            Err(TypeError::InvalidLatLongString(str.to_owned()))
         } else {
             let lat = Decimal::from_str(coordinates[0].trim());
             let lat = lat.map_err(|_| TypeError::InvalidLatLongString(str.to_owned()))?;
             let lon = Decimal::from_str(coordinates[1].trim());
             let lon = lon.map_err(|_| TypeError::InvalidLatLongString(str.to_owned()))?;
-            LatLng::try_from_dec(lat, lon)
+            latlng_from_dec(lat, lon)
         } // if
 
     } // fn
@@ -167,7 +186,7 @@ impl TryFrom<&str> for LatLng {
     type Error = GoogleMapsError;
     /// Attempts to get a `LatLng` struct from a borrowed `&str` that contains a
     /// comma-delimited latitude & longitude pair.
-    fn try_from(str: &str) -> Result<Self, Self::Error> { str.parse() }
+    fn try_from(str: &str) -> Result<Self, Self::Error> { Ok(str.parse()?) }
 } // impl
 
 // -----------------------------------------------------------------------------
@@ -176,7 +195,7 @@ impl TryFrom<&String> for LatLng {
     type Error = GoogleMapsError;
     /// Attempts to get a `LatLng` struct from a borrowed `&String` that
     /// contains a comma-delimited latitude & longitude pair.
-    fn try_from(string: &String) -> Result<Self, Self::Error> { string.parse() }
+    fn try_from(string: &String) -> Result<Self, Self::Error> { Ok(string.parse()?) }
 } // impl
 
 // -----------------------------------------------------------------------------
@@ -185,7 +204,7 @@ impl TryFrom<String> for LatLng {
     type Error = GoogleMapsError;
     /// Attempts to get a `LatLng` struct from an owned `String` that contains a
     /// comma-delimited latitude & longitude pair.
-    fn try_from(string: String) -> Result<Self, Self::Error> { string.parse() }
+    fn try_from(string: String) -> Result<Self, Self::Error> { Ok(string.parse()?) }
 } // impl
 
 // -----------------------------------------------------------------------------
@@ -194,11 +213,7 @@ impl std::convert::From<&LatLng> for String {
     /// Converts a borrowed `&LatLng` struct to a `String` that contains a
     /// latitude/longitude pair.
     fn from(lat_lng: &LatLng) -> Self {
-        format!(
-            "{latitude},{longitude}",
-            latitude=lat_lng.lat.normalize(),
-            longitude=lat_lng.lng.normalize(),
-        ) // format!
+        lat_lng.to_string()
     } // fn
 } // impl
 
@@ -214,9 +229,15 @@ impl std::convert::From<LatLng> for String {
 
 impl std::fmt::Display for LatLng {
     /// Converts a `LatLng` struct to a string that contains a
-    /// latitude/longitude pair.
+    /// latitude/longitude pair in the format that Google accepts, such as
+    /// `51.503364,-0.127625`. The string may be parsed back using `FromStr`.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", String::from(self))
+        write!(
+            f,
+            "{latitude},{longitude}",
+            latitude=self.lat.normalize(),
+            longitude=self.lng.normalize(),
+        ) // write!
     } // fn
 } // impl
 
@@ -282,4 +303,17 @@ impl LatLng {
     /// Returns a tuple containing 1. the latitude (y) coordinate, and then 2.
     /// the longitude (x) coordinate, in that order.
     pub fn coordinates(&self) -> (&Decimal, &Decimal) { (&self.lat, &self.lng) }
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+// A valid `LatLng` is always within ±180°, which an `f64` can represent, so
+// these conversions cannot fail. They are used wherever a coordinate is needed
+// for floating-point math or for another crate's geometry types.
+
+impl LatLng {
+    /// Returns the latitude as an `f64`.
+    pub(crate) fn lat_f64(&self) -> f64 { self.lat.to_f64().unwrap_or_default() }
+    /// Returns the longitude as an `f64`.
+    pub(crate) fn lng_f64(&self) -> f64 { self.lng.to_f64().unwrap_or_default() }
 } // impl
\ No newline at end of file
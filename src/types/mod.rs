@@ -7,15 +7,21 @@ pub(super) mod bounds;
 #[cfg(any(feature = "autocomplete", feature = "directions", feature = "geocoding"))]
 pub(super) mod country;
 pub(super) mod error;
+#[cfg(feature = "geojson")]
+pub mod geojson;
 #[cfg(any(feature = "geocoding", feature = "places", feature = "geocoding"))]
 pub(super) mod geometry;
 #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places", feature = "time_zone"))]
 pub(super) mod language;
-#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "elevation", feature = "geocoding", feature = "places", feature = "roads", feature = "time_zone"))]
+#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "elevation", feature = "geocoding", feature = "geolocation", feature = "places", feature = "roads", feature = "time_zone"))]
 pub(super) mod latlng;
+#[cfg(any(feature = "directions", feature = "distance_matrix", feature = "elevation", feature = "roads"))]
+pub mod polyline;
 #[cfg(any(feature = "geocoding", feature = "places", feature = "geocoding"))]
 pub(super) mod location_type;
 #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
+pub(super) mod place_id;
+#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
 pub(super) mod place_type;
 #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
 pub(super) mod region;
@@ -33,11 +39,13 @@ pub use crate::types::error::Error;
 pub use crate::types::geometry::Geometry;
 #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places", feature = "time_zone"))]
 pub use crate::types::language::Language;
-#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "elevation", feature = "geocoding", feature = "places", feature = "roads", feature = "time_zone"))]
+#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "elevation", feature = "geocoding", feature = "geolocation", feature = "places", feature = "roads", feature = "time_zone"))]
 pub use crate::types::latlng::LatLng;
 #[cfg(any(feature = "geocoding", feature = "places", feature = "geocoding"))]
 pub use crate::types::location_type::LocationType;
 #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
+pub use crate::types::place_id::PlaceId;
+#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
 pub use crate::types::place_type::PlaceType;
 #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places"))]
 pub use crate::types::region::Region;
\ No newline at end of file
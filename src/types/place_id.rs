@@ -0,0 +1,163 @@
+//! Contains the `PlaceId` struct and its associated traits. It is used to
+//! validate a place ID before it is sent to Google Maps.
+
+use crate::types::error::Error as TypeError;
+use crate::error::Error as GoogleMapsError;
+use percent_encoding::percent_decode_str;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A [place ID](https://developers.google.com/maps/documentation/places/web-service/place-id)
+/// is a textual identifier that uniquely identifies a place in the Google
+/// Places database and on Google Maps.
+///
+/// Place IDs are made up of letters, digits, hyphens and underscores. A
+/// `PlaceId` can only be created using `PlaceId::parse`, which checks this
+/// before the ID is sent to Google. A `PlaceId` may be used anywhere a place ID
+/// string is accepted.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PlaceId(String);
+
+// -----------------------------------------------------------------------------
+
+impl PlaceId {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Parses and normalizes a place ID.
+    ///
+    /// ## Description:
+    ///
+    /// Leading and trailing whitespace is removed, and a percent-encoded place
+    /// ID (for example, one copied from a URL) is decoded. The result must be
+    /// non-empty and contain only letters, digits, hyphens and underscores.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `place_id` ‧ The place ID to parse.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let place_id = PlaceId::parse(" ChIJN1t_tDeuEmsRUsoyG83frY4 ")?;
+    /// assert_eq!(place_id.as_str(), "ChIJN1t_tDeuEmsRUsoyG83frY4");
+    /// ```
+
+    pub fn parse(place_id: &str) -> Result<PlaceId, TypeError> {
+        let decoded = percent_decode_str(place_id.trim())
+            .decode_utf8()
+            .map_err(|_error| TypeError::InvalidPlaceId(place_id.to_string()))?;
+        let decoded = decoded.trim();
+        if decoded.is_empty()
+            || !decoded.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(TypeError::InvalidPlaceId(place_id.to_string()));
+        } // if
+        Ok(PlaceId(decoded.to_string()))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the place ID as a string slice.
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for PlaceId {
+    /// Formats a `PlaceId` struct into a string that may be sent to Google.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl AsRef<str> for PlaceId {
+    /// Returns the place ID as a string slice.
+    fn as_ref(&self) -> &str {
+        &self.0
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<PlaceId> for String {
+    /// Converts a `PlaceId` struct into a `String` that may be sent to Google.
+    fn from(place_id: PlaceId) -> String {
+        place_id.0
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::TryFrom<String> for PlaceId {
+    // Error definitions are contained in the `google_maps\src\types\error.rs` module.
+    type Error = TypeError;
+    /// Gets a `PlaceId` struct from a `String` that contains a place ID.
+    fn try_from(place_id: String) -> Result<Self, Self::Error> {
+        PlaceId::parse(&place_id)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for PlaceId {
+    // Error definitions are contained in the `google_maps\src\error.rs` module.
+    type Err = GoogleMapsError;
+    /// Gets a `PlaceId` struct from a `String` that contains a place ID.
+    fn from_str(place_id: &str) -> Result<Self, Self::Err> {
+        Ok(PlaceId::parse(place_id)?)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn valid_place_ids() {
+        for place_id in [
+            "ChIJN1t_tDeuEmsRUsoyG83frY4",
+            "GhIJQWDl0CIeQUARxks3icF8U8A",
+            "EicxMyBNYXJrZXQgU3QsIFdpbG1pbmd0b24sIE5DIDI4NDAxLCBVU0EiGhIYChQKEgnRTo6ixx-qiRHo_bbmkCm7ZRAN",
+        ] {
+            assert_eq!(PlaceId::parse(place_id).unwrap().as_str(), place_id);
+        } // for
+    } // fn
+
+    #[test]
+    fn whitespace_padded_and_percent_encoded_place_ids() {
+        let expected = "ChIJN1t_tDeuEmsRUsoyG83frY4";
+        assert_eq!(PlaceId::parse("  ChIJN1t_tDeuEmsRUsoyG83frY4\n").unwrap().as_str(), expected);
+        assert_eq!(PlaceId::parse("ChIJN1t%5FtDeuEmsRUsoyG83frY4").unwrap().as_str(), expected);
+        assert_eq!(PlaceId::parse("%20ChIJN1t_tDeuEmsRUsoyG83frY4%20").unwrap().as_str(), expected);
+    } // fn
+
+    #[test]
+    fn invalid_place_ids() {
+        for place_id in ["", "   ", "ChIJN1t tDeuEmsR", "ChIJN1t/tDeuEmsR", "ChIJN1t%2FtDeuEmsR", "%FF", "東京"] {
+            assert!(
+                matches!(PlaceId::parse(place_id), Err(TypeError::InvalidPlaceId(ref input)) if input == place_id),
+                "`{place_id}` was accepted",
+            ); // assert!
+        } // for
+    } // fn
+
+    #[test]
+    fn serde_validates_place_ids() {
+        let place_id: PlaceId = serde_json::from_str(r#"" ChIJN1t_tDeuEmsRUsoyG83frY4 ""#).unwrap();
+        assert_eq!(serde_json::to_string(&place_id).unwrap(), r#""ChIJN1t_tDeuEmsRUsoyG83frY4""#);
+        assert!(serde_json::from_str::<PlaceId>(r#""not a place id""#).is_err());
+    } // fn
+
+} // mod
@@ -603,7 +603,7 @@ impl std::str::FromStr for PlaceType {
 impl PlaceType {
     /// Formats a `PlaceType` enum into a string that is presentable to the end
     /// user.
-    pub fn display(&self) -> &str {
+    pub fn display(&self) -> &'static str {
         match self {
             PlaceType::Accounting => "Accounting",
             PlaceType::Airport => "Airport",
@@ -753,6 +753,21 @@ impl PlaceType {
 
 // -----------------------------------------------------------------------------
 
+impl PlaceType {
+    /// Returns an English, human-readable label for the place type that is
+    /// suitable for showing in a user interface. For example,
+    /// `PlaceType::GasStation` is labelled `Gas Station`, and `PlaceType::Atm`
+    /// is labelled `ATM`.
+    ///
+    /// Every `PlaceType` variant has a label, so there is no fallback case.
+    /// This is the same text that is returned by the `display` method.
+    pub fn label_en(&self) -> &'static str {
+        self.display()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl PlaceType {
     /// A helper function that converts a `Vec<PlaceType>` (i.e. an array of
     /// PlaceType enum) to a `String` that contains a comma-delimited list of
@@ -766,4 +781,43 @@ impl PlaceType {
             .collect::<Vec<String>>()
             .join(",")
     } // fn
-} // impl
\ No newline at end of file
+} // impl
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn english_labels() {
+        for (place_type, label) in [
+            (PlaceType::Restaurant, "Restaurant"),
+            (PlaceType::GasStation, "Gas Station"),
+            (PlaceType::Atm, "ATM"),
+            (PlaceType::PostalCode, "Postal Code"),
+            (PlaceType::StreetAddress, "Street Address"),
+        ] {
+            assert_eq!(place_type.label_en(), label);
+        } // for
+    } // fn
+
+    #[test]
+    fn every_place_type_has_a_label() {
+        for place_type in PLACE_TYPES_BY_CODE.values() {
+            assert!(!place_type.label_en().trim().is_empty(), "{place_type:?} has no label");
+        } // for
+    } // fn
+
+    #[test]
+    fn unknown_place_types_are_rejected() {
+        // An unknown code never becomes a `PlaceType`, so there is no
+        // unlabelled variant to fall back from:
+        assert!(matches!(
+            PlaceType::try_from("space_elevator"),
+            Err(GoogleMapsError::Type(TypeError::InvalidPlaceTypeCode(code))) if code == "space_elevator",
+        ));
+        assert!(serde_json::from_str::<PlaceType>(r#""space_elevator""#).is_err());
+    } // fn
+
+} // mod
@@ -0,0 +1,191 @@
+//! Encodes and decodes paths using Google's
+//! [Encoded Polyline Algorithm](https://developers.google.com/maps/documentation/utilities/polylinealgorithm).
+//! Encoded polylines are returned by the _Directions API_ and may be sent to
+//! the _Elevation API_, where they are much shorter than a list of
+//! coordinates.
+
+use crate::types::LatLng;
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+
+// -----------------------------------------------------------------------------
+//
+/// Encodes a path into a polyline string.
+///
+/// Coordinates are encoded with five decimal places of precision (about one
+/// meter), as Google does. Any further decimal places are rounded.
+///
+/// ## Arguments:
+///
+/// * `points` ‧ The coordinates of the path, in order.
+///
+/// ## Example:
+///
+/// ```rust
+/// let polyline = google_maps::types::polyline::encode(&[
+///     LatLng::try_from_dec(dec!(38.5), dec!(-120.2))?,
+///     LatLng::try_from_dec(dec!(40.7), dec!(-120.95))?,
+///     LatLng::try_from_dec(dec!(43.252), dec!(-126.453))?,
+/// ]);
+/// assert_eq!(polyline, "_p~iF~ps|U_ulLnnqC_mqNvxq`@");
+/// ```
+
+pub fn encode(points: &[LatLng]) -> String {
+
+    let mut encoded = String::new();
+    let (mut previous_lat, mut previous_lng) = (0_i64, 0_i64);
+
+    for point in points {
+        let (lat, lng) = (to_e5(point.lat), to_e5(point.lng));
+        encode_delta(lat - previous_lat, &mut encoded);
+        encode_delta(lng - previous_lng, &mut encoded);
+        (previous_lat, previous_lng) = (lat, lng);
+    } // for
+
+    encoded
+
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes a polyline string into a path. Returns `None` if the string is
+/// malformed.
+///
+/// Coordinates are encoded with five decimal places, so they are decoded
+/// exactly into `Decimal` values.
+///
+/// ## Arguments:
+///
+/// * `encoded` ‧ The encoded polyline, for example a step's `polyline.points`.
+///
+/// ## Example:
+///
+/// ```rust
+/// let path = google_maps::types::polyline::decode("_p~iF~ps|U_ulLnnqC_mqNvxq`@");
+/// assert_eq!(path.map(|path| path.len()), Some(3));
+/// ```
+
+pub fn decode(encoded: &str) -> Option<Vec<LatLng>> {
+
+    let mut bytes = encoded.bytes().peekable();
+    let mut path = Vec::new();
+    let (mut lat, mut lng) = (0_i64, 0_i64);
+
+    while bytes.peek().is_some() {
+        lat += decode_delta(&mut bytes)?;
+        lng += decode_delta(&mut bytes)?;
+        path.push(LatLng::try_from_dec(Decimal::new(lat, 5), Decimal::new(lng, 5)).ok()?);
+    } // while
+
+    Some(path)
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Converts a coordinate into an integer number of 0.00001° units.
+
+fn to_e5(coordinate: Decimal) -> i64 {
+    (coordinate * Decimal::new(100_000, 0))
+        .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+        .to_i64()
+        .unwrap_or_default()
+} // fn
+
+/// Appends a value to an encoded polyline: the difference between a coordinate
+/// and the previous one, in units of 0.00001°. The value is written in 5-bit
+/// chunks, and a chunk with its `0x20` bit set is followed by another.
+
+fn encode_delta(delta: i64, encoded: &mut String) {
+    let mut value = if delta < 0 { !(delta << 1) } else { delta << 1 };
+    while value >= 0x20 {
+        encoded.push(char::from(((0x20 | (value & 0x1f)) + 63) as u8));
+        value >>= 5;
+    } // while
+    encoded.push(char::from((value + 63) as u8));
+} // fn
+
+/// Reads the next value of an encoded polyline. This is the inverse of
+/// `encode_delta`.
+
+fn decode_delta(bytes: &mut impl Iterator<Item = u8>) -> Option<i64> {
+
+    let mut value = 0_i64;
+    let mut shift = 0;
+
+    loop {
+        let chunk = bytes.next()?.checked_sub(63).filter(|chunk| *chunk < 64)?;
+        value |= i64::from(chunk & 0x1f) << shift;
+        if chunk < 0x20 { break; }
+        shift += 5;
+        if shift > 30 { return None; }
+    } // loop
+
+    Some(if value & 1 == 1 { !(value >> 1) } else { value >> 1 })
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    /// Google's reference example, from the _Encoded Polyline Algorithm
+    /// Format_ documentation.
+    const REFERENCE: &str = "_p~iF~ps|U_ulLnnqC_mqNvxq`@";
+
+    fn reference_path() -> Vec<LatLng> {
+        vec![
+            LatLng::try_from_dec(dec!(38.5), dec!(-120.2)).unwrap(),
+            LatLng::try_from_dec(dec!(40.7), dec!(-120.95)).unwrap(),
+            LatLng::try_from_dec(dec!(43.252), dec!(-126.453)).unwrap(),
+        ] // vec!
+    } // fn
+
+    #[test]
+    fn encodes_reference_example() {
+        assert_eq!(encode(&reference_path()), REFERENCE);
+    } // fn
+
+    #[test]
+    fn decodes_reference_example() {
+        assert_eq!(decode(REFERENCE), Some(reference_path()));
+    } // fn
+
+    #[test]
+    fn encodes_reference_coordinate() {
+        // The documentation's worked example encodes -179.9832104 as
+        // `` `~oia@ ``. A latitude of zero encodes as `?`:
+        let point = LatLng::try_from_dec(dec!(0), dec!(-179.9832104)).unwrap();
+        assert_eq!(encode(&[point]), "?`~oia@");
+        assert_eq!(
+            decode("?`~oia@"),
+            Some(vec![LatLng::try_from_dec(dec!(0), dec!(-179.98321)).unwrap()]),
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn round_trips() {
+        let path = vec![
+            LatLng::try_from_dec(dec!(-90), dec!(180)).unwrap(),
+            LatLng::try_from_dec(dec!(90), dec!(-180)).unwrap(),
+            LatLng::try_from_dec(dec!(0.00001), dec!(-0.00001)).unwrap(),
+            LatLng::try_from_dec(dec!(45.42153), dec!(-75.69719)).unwrap(),
+        ]; // vec!
+        assert_eq!(decode(&encode(&path)), Some(path));
+        assert_eq!(encode(&[]), "");
+        assert_eq!(decode(""), Some(Vec::new()));
+    } // fn
+
+    #[test]
+    fn rejects_malformed_polylines() {
+        // Truncated in the middle of a value, missing a longitude, and with a
+        // character outside of the encoding's range:
+        assert_eq!(decode("_p~iF~ps|U_ulLnnqC_mqNvxq"), None);
+        assert_eq!(decode("_p~iF"), None);
+        assert_eq!(decode("_p~iF ps|U"), None);
+    } // fn
+
+} // mod
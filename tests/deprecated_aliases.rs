@@ -0,0 +1,114 @@
+//! Checks that the deprecated and alias builder methods still compile, and
+//! that they build exactly the same query string as the methods that replace
+//! them.
+
+#![cfg(all(
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "geocoding",
+    feature = "places",
+))]
+#![allow(deprecated)]
+
+use google_maps::places::RankBy;
+use google_maps::prelude::*;
+
+// -----------------------------------------------------------------------------
+
+fn toronto() -> LatLng {
+    LatLng::try_from_f64(43.6532, -79.3832).unwrap()
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn directions_with_region_bias() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    let request = || client.directions(
+        Location::Address(String::from("Toledo")),
+        Location::Address(String::from("Madrid")),
+    ); // directions
+    assert_eq!(
+        request().with_region_bias(Region::Spain).query_url().unwrap(),
+        request().with_region(Region::Spain).query_url().unwrap(),
+    ); // assert_eq!
+} // fn
+
+#[test]
+fn distance_matrix_with_region_bias() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    let request = || client.distance_matrix(
+        vec![Waypoint::Address(String::from("Toledo"))],
+        vec![Waypoint::Address(String::from("Madrid"))],
+    ); // distance_matrix
+    assert_eq!(
+        request().with_region_bias(Region::Spain).query_url().unwrap(),
+        request().with_region(Region::Spain).query_url().unwrap(),
+    ); // assert_eq!
+} // fn
+
+#[test]
+fn geocoding_with_region_bias() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    assert_eq!(
+        client.geocoding().with_address("Toledo").with_region_bias(Region::Spain).query_url().unwrap(),
+        client.geocoding().with_address("Toledo").with_region(Region::Spain).query_url().unwrap(),
+    ); // assert_eq!
+} // fn
+
+#[test]
+fn place_autocomplete_aliases() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    let request = || client.place_autocomplete(String::from("pizza"));
+    assert_eq!(
+        request().with_region_bias(Region::Canada).query_url(),
+        request().with_region(Region::Canada).query_url(),
+    ); // assert_eq!
+    assert_eq!(
+        request().with_sessiontoken(String::from("session")).query_url(),
+        request().with_session_token(String::from("session")).query_url(),
+    ); // assert_eq!
+} // fn
+
+#[test]
+fn place_details_aliases() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    let request = || client.place_details("ChIJN1t_tDeuEmsRUsoyG83frY4");
+    assert_eq!(
+        request().with_region_bias(Region::Australia).query_url(),
+        request().with_region(Region::Australia).query_url(),
+    ); // assert_eq!
+    assert_eq!(
+        request().with_sessiontoken(String::from("session")).query_url(),
+        request().with_session_token(String::from("session")).query_url(),
+    ); // assert_eq!
+} // fn
+
+#[test]
+fn nearby_search_aliases() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    let request = || client.nearby_search(toronto(), 1_000);
+    assert_eq!(
+        request().with_pagetoken(String::from("page")).query_url(),
+        request().with_page_token(String::from("page")).query_url(),
+    ); // assert_eq!
+    assert_eq!(
+        request().with_rankby(RankBy::Distance).query_url(),
+        request().with_rank_by(RankBy::Distance).query_url(),
+    ); // assert_eq!
+} // fn
+
+#[test]
+fn text_search_aliases() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    let request = || client.text_search(String::from("pizza"), 1_000);
+    assert_eq!(
+        request().with_region_bias(Region::Canada).query_url(),
+        request().with_region(Region::Canada).query_url(),
+    ); // assert_eq!
+    assert_eq!(
+        request().with_pagetoken(String::from("page")).query_url(),
+        request().with_page_token(String::from("page")).query_url(),
+    ); // assert_eq!
+} // fn
@@ -0,0 +1,175 @@
+//! Checks that free-text input is percent-encoded correctly in the query
+//! strings built for the _Geocoding_, _Directions_, _Distance Matrix_,
+//! _Elevation_, and _Places_ requests. Each input must be encoded so that only
+//! alphanumeric characters and `%` escapes appear in the query string, and
+//! must decode back to exactly the input that was passed in.
+
+#![cfg(all(feature = "autocomplete", feature = "geocoding", feature = "places"))]
+
+use google_maps::prelude::*;
+use percent_encoding::percent_decode_str;
+
+// -----------------------------------------------------------------------------
+
+/// Inputs in several scripts, with emoji, and with characters that have a
+/// special meaning in a query string.
+const INPUTS: &[&str] = &[
+    "شارع الملك فهد، الرياض",
+    "ถนนสุขุมวิท กรุงเทพมหานคร",
+    "東京都渋谷区道玄坂１丁目",
+    "🍣 sushi near 🗼",
+    "Barnes & Noble + Starbucks",
+    "a+b=c&d=e?f#g%20h",
+];
+
+// -----------------------------------------------------------------------------
+
+/// Returns the raw value of the named parameter in a URL's query string.
+fn parameter<'a>(url: &'a str, name: &str) -> &'a str {
+    let (_path, query) = url.split_once('?').expect("URL has no query string");
+    query
+        .split('&')
+        .find_map(|parameter| parameter.strip_prefix(name)?.strip_prefix('='))
+        .unwrap_or_else(|| panic!("`{name}` is missing from `{url}`"))
+} // fn
+
+/// Asserts that a raw query string value is fully percent-encoded, and that it
+/// decodes to the expected input.
+fn assert_round_trips(raw: &str, input: &str) {
+    assert!(
+        raw.chars().all(|character| character.is_ascii_alphanumeric() || character == '%'),
+        "`{raw}` is not fully percent-encoded",
+    ); // assert!
+    assert_eq!(percent_decode_str(raw).decode_utf8().unwrap(), input);
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn geocoding_address() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    for input in INPUTS {
+        let url = client.geocoding().with_address(input).query_url().unwrap();
+        assert_round_trips(parameter(&url, "address"), input);
+    } // for
+} // fn
+
+#[test]
+fn geocoding_place_id() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    for input in INPUTS {
+        let url = client.geocoding().with_place_id(input).query_url().unwrap();
+        assert_round_trips(parameter(&url, "place_id"), input);
+    } // for
+} // fn
+
+#[test]
+fn text_search_query() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    for input in INPUTS {
+        let url = client.text_search(input.to_string(), 1_000).query_url();
+        assert_round_trips(parameter(&url, "query"), input);
+    } // for
+} // fn
+
+#[test]
+fn text_search_page_token() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    for input in INPUTS {
+        let url = client
+            .text_search(String::from("pizza"), 1_000)
+            .with_page_token(input.to_string())
+            .query_url();
+        assert_round_trips(parameter(&url, "pagetoken"), input);
+    } // for
+} // fn
+
+#[test]
+fn place_autocomplete_input() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    for input in INPUTS {
+        let url = client.place_autocomplete(input.to_string()).query_url();
+        assert_round_trips(parameter(&url, "input"), input);
+    } // for
+} // fn
+
+#[test]
+fn place_autocomplete_session_token() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    for input in INPUTS {
+        let url = client
+            .place_autocomplete(String::from("pizza"))
+            .with_session_token(input.to_string())
+            .query_url();
+        assert_round_trips(parameter(&url, "sessiontoken"), input);
+    } // for
+} // fn
+
+#[test]
+fn place_details_session_token() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    for input in INPUTS {
+        let url = client
+            .place_details("ChIJN1t_tDeuEmsRUsoyG83frY4")
+            .with_session_token(input.to_string())
+            .query_url();
+        assert_round_trips(parameter(&url, "sessiontoken"), input);
+    } // for
+} // fn
+
+#[test]
+fn nearby_search_keyword() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    let location = LatLng::try_from_f64(43.6532, -79.3832).unwrap();
+    for input in INPUTS {
+        let url = client
+            .nearby_search(location.clone(), 1_000)
+            .with_keyword(input.to_string())
+            .query_url();
+        assert_round_trips(parameter(&url, "keyword"), input);
+    } // for
+} // fn
+
+#[cfg(feature = "directions")]
+#[test]
+fn directions_addresses() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    for input in INPUTS {
+        let url = client
+            .directions(Location::Address(input.to_string()), Location::Address(input.to_string()))
+            .with_waypoints(&[Waypoint::Address(input.to_string())])
+            .query_url()
+            .unwrap();
+        assert_round_trips(parameter(&url, "origin"), input);
+        assert_round_trips(parameter(&url, "destination"), input);
+        assert_round_trips(parameter(&url, "waypoints"), input);
+    } // for
+} // fn
+
+#[cfg(feature = "distance_matrix")]
+#[test]
+fn distance_matrix_addresses() {
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    for input in INPUTS {
+        let url = client
+            .distance_matrix(vec![Waypoint::Address(input.to_string())], vec![Waypoint::Address(input.to_string())])
+            .query_url()
+            .unwrap();
+        assert_round_trips(parameter(&url, "origins"), input);
+        assert_round_trips(parameter(&url, "destinations"), input);
+    } // for
+} // fn
+
+#[cfg(feature = "elevation")]
+#[test]
+fn elevation_encoded_polyline() {
+    // Encoded polylines may contain characters such as `?`, `@`, `\` and `|`:
+    let polyline = r"gfo}EtohhU?@\|~oia@";
+    let client = GoogleMapsClient::new("YOUR_API_KEY_HERE");
+    let url = client
+        .elevation()
+        .for_sampled_path_request(ElevationLocations::Polyline(polyline.to_string()), 10)
+        .query_url()
+        .unwrap();
+    assert_round_trips(parameter(&url, "path"), &format!("enc:{polyline}"));
+} // fn